@@ -19,7 +19,7 @@ macro_rules! impl_maybe_from_u128 {
         }
         impl MaybeFromU128 for $non_zero_type {
             fn maybe_from_u128(value: u128) -> Option<Self> {
-                if value <= (Self::MAX.get() as u128) {
+                if value <= (<$type>::MAX as u128) {
                     return Self::new(value as $type);
                 }
                 None
@@ -100,17 +100,110 @@ macro_rules! create_comparison_bench {
     };
 }
 
+/// Compares the octal digit count against the formula that divides the base-2 logarithm by three.
+#[allow(clippy::incompatible_msrv)]
+fn octal_division(criterion: &mut Criterion) {
+    let inputs = radix_boundaries!(u128, 8).collect::<Vec<_>>();
+    let mut group = criterion.benchmark_group("octal_division");
+    for input in &inputs {
+        group.bench_with_input(
+            BenchmarkId::new("ilog2 / 3", input.count_octal_digits()),
+            input,
+            |b, &n| b.iter(move || 1 + black_box(n).checked_ilog2().unwrap_or_default() / 3),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("count_octal_digits", input.count_octal_digits()),
+            input,
+            |b, &n| b.iter(move || black_box(n).count_octal_digits()),
+        );
+    }
+    group.finish();
+}
+
 create_comparison_bench!(count_bits);
 create_comparison_bench!(count_octal_digits);
 create_comparison_bench!(count_digits);
 create_comparison_bench!(count_hex_digits);
 
+/// Compares the decimal digit count of a [u64] against its base-10 logarithm.
+#[allow(clippy::incompatible_msrv)]
+fn decimal_u64(criterion: &mut Criterion) {
+    let inputs = radix_boundaries!(u64, 10).collect::<Vec<_>>();
+    let mut group = criterion.benchmark_group("decimal_u64");
+    for input in &inputs {
+        group.bench_with_input(
+            BenchmarkId::new("checked_ilog10", input.count_digits()),
+            input,
+            |b, &n| b.iter(move || 1 + black_box(n).checked_ilog10().unwrap_or_default() as usize),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("count_digits", input.count_digits()),
+            input,
+            |b, &n| b.iter(move || black_box(n).count_digits()),
+        );
+    }
+    group.finish();
+}
+
+/// Compares the decimal digit count against the length of the integer when it is formatted,
+/// either with [itoa] or with [format!].
+fn formatted_length_group<T>(criterion: &mut Criterion, name: &str, inputs: &[T])
+where
+    T: CountDigits + itoa::Integer + std::fmt::Display,
+{
+    let mut group = criterion.benchmark_group(name);
+    for &input in inputs {
+        group.bench_with_input(
+            BenchmarkId::new("count_digits", input.count_digits()),
+            &input,
+            |b, &n| b.iter(move || black_box(n).count_digits()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("itoa", input.count_digits()),
+            &input,
+            |b, &n| b.iter(move || itoa::Buffer::new().format(black_box(n)).len()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("format!", input.count_digits()),
+            &input,
+            |b, &n| b.iter(move || format!("{}", black_box(n)).len()),
+        );
+    }
+    group.finish();
+}
+
+fn formatted_length(criterion: &mut Criterion) {
+    formatted_length_group(
+        criterion,
+        "formatted_length_u32",
+        &radix_boundaries!(u32, 10).collect::<Vec<_>>(),
+    );
+    formatted_length_group(
+        criterion,
+        "formatted_length_i64",
+        &radix_boundaries!(i64, 10).collect::<Vec<_>>(),
+    );
+    formatted_length_group(
+        criterion,
+        "formatted_length_u64",
+        &radix_boundaries!(u64, 10).collect::<Vec<_>>(),
+    );
+    formatted_length_group(
+        criterion,
+        "formatted_length_u128",
+        &radix_boundaries!(u128, 10).collect::<Vec<_>>(),
+    );
+}
+
 criterion_group!(
     benchmarks,
     count_bits,
     count_octal_digits,
     count_digits,
-    count_hex_digits
+    count_hex_digits,
+    octal_division,
+    decimal_u64,
+    formatted_length
 );
 
 criterion_main!(benchmarks);
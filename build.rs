@@ -0,0 +1,126 @@
+use std::env;
+use std::ffi::OsString;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Uses the integer logarithm functions that were stabilized in Rust 1.67.
+const ILOG_PROBE: &str = r#"
+#![no_std]
+pub fn probe(n: u32, m: core::num::NonZeroU32) -> [Option<u32>; 5] {
+    [
+        n.checked_ilog(3),
+        n.checked_ilog2(),
+        n.checked_ilog10(),
+        Some(m.ilog2()),
+        Some(m.ilog10()),
+    ]
+}
+"#;
+
+/// The error codes with which a compiler rejects an API that it does not provide:
+/// a method that does not exist, or one that is not yet stable.
+const MISSING_API_ERRORS: [&str; 2] = ["E0599", "E0658"];
+
+/// The outcome of compiling a probe.
+enum Probe {
+    /// The probe compiled.
+    Compiles,
+    /// The probe failed to compile because the compiler does not provide an API that it uses.
+    MissingApi,
+    /// The probe could not determine whether the compiler provides the API.
+    Inconclusive(String),
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    match probe(ILOG_PROBE) {
+        Probe::Compiles => {}
+        Probe::MissingApi => println!("cargo:rustc-cfg=count_digits_no_ilog"),
+        Probe::Inconclusive(reason) => println!(
+            "cargo:warning=count-digits could not detect the integer logarithm functions ({reason}), \
+             so it assumes that the compiler provides them"
+        ),
+    }
+}
+
+/// Compiles the given source code as a library with the current compiler, the same wrapper,
+/// the same target, and the same flags as the crate itself.
+///
+/// A probe that cannot be compiled for any reason other than a missing API, such as a compiler that
+/// cannot be invoked or a sysroot that the probe cannot use, is inconclusive, so that an unusual build
+/// environment does not silently select the fallback implementations.
+fn probe(source: &str) -> Probe {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let out_dir = match env::var_os("OUT_DIR") {
+        Some(out_dir) => out_dir,
+        None => return Probe::Inconclusive("OUT_DIR is not set".into()),
+    };
+
+    let wrappers = ["RUSTC_WRAPPER", "RUSTC_WORKSPACE_WRAPPER"]
+        .iter()
+        .filter_map(env::var_os)
+        .filter(|wrapper| !wrapper.is_empty())
+        .collect::<Vec<OsString>>();
+    let mut command = match wrappers.split_first() {
+        Some((wrapper, rest)) => {
+            let mut command = Command::new(wrapper);
+            command.args(rest).arg(rustc);
+            command
+        }
+        None => Command::new(rustc),
+    };
+    command
+        .args(["--crate-name", "count_digits_probe"])
+        .args(["--crate-type", "lib"])
+        .args(["--emit", "metadata"])
+        .args(["--cap-lints", "allow"])
+        .arg("--out-dir")
+        .arg(out_dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+
+    if let Some(target) = env::var_os("TARGET") {
+        command.arg("--target").arg(target);
+    }
+
+    // The flags are separated by the ASCII unit separator, and include any --sysroot that applies to the crate.
+    if let Ok(rustflags) = env::var("CARGO_ENCODED_RUSTFLAGS") {
+        command.args(rustflags.split('\x1f').filter(|flag| !flag.is_empty()));
+    }
+    command.arg("-");
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(error) => {
+            return Probe::Inconclusive(format!("the compiler could not be invoked: {error}"))
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        if let Err(error) = stdin.write_all(source.as_bytes()) {
+            let _ = child.kill();
+            return Probe::Inconclusive(format!("the probe could not be written: {error}"));
+        }
+    }
+
+    let output = match child.wait_with_output() {
+        Ok(output) => output,
+        Err(error) => return Probe::Inconclusive(format!("the compiler did not finish: {error}")),
+    };
+    if output.status.success() {
+        return Probe::Compiles;
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if MISSING_API_ERRORS.iter().any(|code| stderr.contains(code)) {
+        Probe::MissingApi
+    } else {
+        let error = stderr
+            .lines()
+            .find(|line| line.starts_with("error"))
+            .unwrap_or("the compiler failed without an error message");
+        Probe::Inconclusive(error.into())
+    }
+}
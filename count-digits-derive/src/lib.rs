@@ -0,0 +1,132 @@
+//! A derive macro for the [CountDigits](https://docs.rs/count-digits/latest/count_digits/trait.CountDigits.html)
+//! trait of the [count-digits](https://docs.rs/count-digits) crate.
+//!
+//! This crate is re-exported by `count-digits` with its `derive` feature, which is the intended way to use it.
+//!
+//! ```toml
+//! [dependencies]
+//! count-digits = { version = "0.6.0", features = ["derive"] }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Index, Member};
+
+/// Derives [CountDigits](https://docs.rs/count-digits/latest/count_digits/trait.CountDigits.html)
+/// for a struct with a single field whose type implements `CountDigits`.
+///
+/// Every function forwards to the field, and the [Radix](https://docs.rs/count-digits/latest/count_digits/trait.CountDigits.html#associatedtype.Radix)
+/// is the radix of the field. Both tuple structs and structs with a named field are supported,
+/// including generic structs. `CountDigits` requires [Copy], so the struct must implement [Copy] as well.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::CountDigits;
+///
+/// #[derive(Clone, Copy, CountDigits)]
+/// struct UserId(u64);
+///
+/// #[derive(Clone, Copy, CountDigits)]
+/// struct Offset {
+///     bytes: i32,
+/// }
+///
+/// assert_eq!(4, UserId(1337).count_digits());
+/// assert_eq!(3, UserId(1337).count_digits_radix(16));
+/// assert_eq!(3, Offset { bytes: -255 }.count_digits());
+/// assert_eq!(8, Offset { bytes: -1 }.count_hex_digits());
+/// ```
+///
+/// Structs without exactly one field, enums, and unions are rejected.
+///
+/// ```compile_fail
+/// use count_digits::CountDigits;
+///
+/// #[derive(Clone, Copy, CountDigits)]
+/// struct Point(u32, u32);
+/// ```
+///
+/// ```compile_fail
+/// use count_digits::CountDigits;
+///
+/// #[derive(Clone, Copy, CountDigits)]
+/// struct Unit;
+/// ```
+///
+/// ```compile_fail
+/// use count_digits::CountDigits;
+///
+/// #[derive(Clone, Copy, CountDigits)]
+/// enum Either {
+///     Left(u8),
+///     Right(u8),
+/// }
+/// ```
+///
+/// The field must implement `CountDigits`.
+///
+/// ```compile_fail
+/// use count_digits::CountDigits;
+///
+/// #[derive(Clone, Copy, CountDigits)]
+/// struct Ratio(f64);
+/// ```
+#[proc_macro_derive(CountDigits)]
+pub fn derive_count_digits(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Returns the forwarding implementation of `CountDigits` for the given struct.
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(Error::new_spanned(
+                data.enum_token,
+                "CountDigits can only be derived for a struct with exactly one field",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "CountDigits can only be derived for a struct with exactly one field",
+            ))
+        }
+    };
+
+    let field = match fields {
+        Fields::Named(named) if named.named.len() == 1 => &named.named[0],
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => &unnamed.unnamed[0],
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "CountDigits can only be derived for a struct with exactly one field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+    let inner = &field.ty;
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let where_predicates = where_clause
+        .map(|clause| &clause.predicates)
+        .into_iter()
+        .flatten();
+
+    Ok(quote! {
+        ::count_digits::__derive_count_digits! {
+            impl [#impl_generics] for #name #type_generics,
+            field #member: #inner,
+            where [#(#where_predicates,)*]
+        }
+    })
+}
@@ -0,0 +1,94 @@
+use core::num::NonZeroU32;
+use count_digits::CountDigits;
+
+#[derive(Clone, Copy, CountDigits)]
+struct UserId(u64);
+
+#[derive(Clone, Copy, CountDigits)]
+struct Offset {
+    bytes: i32,
+}
+
+#[derive(Clone, Copy, CountDigits)]
+struct Wrapper<T>(T)
+where
+    T: Copy;
+
+#[derive(Clone, Copy, CountDigits)]
+struct Nested(UserId);
+
+#[test]
+fn tuple_struct_forwards_to_the_field() {
+    for n in [0, 1, 9, 10, 1337, u32::MAX as u64, u64::MAX] {
+        let id = UserId(n);
+        assert_eq!(id.count_bits(), n.count_bits());
+        assert_eq!(id.count_octal_digits(), n.count_octal_digits());
+        assert_eq!(id.count_digits(), n.count_digits());
+        assert_eq!(id.count_hex_digits(), n.count_hex_digits());
+        for radix in 2..=36 {
+            assert_eq!(id.count_digits_radix(radix), n.count_digits_radix(radix));
+            assert_eq!(
+                id.checked_count_digits_radix(radix),
+                n.checked_count_digits_radix(radix)
+            );
+            assert!(id.digits_radix_le(radix).eq(n.digits_radix_le(radix)));
+        }
+        assert_eq!(id.checked_count_digits_radix(1), None);
+        assert_eq!(id.count_digits_of_sum(UserId(n)), n.count_digits_of_sum(n));
+        assert_eq!(
+            id.digit_diff_count_radix(UserId(7), 10),
+            n.digit_diff_count_radix(7, 10)
+        );
+        assert_eq!(id.digit_width_delta(UserId(7)), n.digit_width_delta(7));
+    }
+    assert_eq!(UserId::max_digit_value(16), 15);
+    // The derived width is the width of the field, not of the Debug output of the struct.
+    assert_eq!(UserId(1337).debug_width(), 4);
+    assert_eq!(Offset { bytes: -1337 }.debug_width(), 5);
+}
+
+#[test]
+fn named_struct_forwards_to_the_field() {
+    for bytes in [i32::MIN, -1000, -1, 0, 1, 999, i32::MAX] {
+        let offset = Offset { bytes };
+        assert_eq!(offset.count_digits(), bytes.count_digits());
+        assert_eq!(
+            offset.bit_pattern_as_unsigned(),
+            bytes.bit_pattern_as_unsigned()
+        );
+        assert_eq!(offset.count_digits_radix(2), bytes.count_digits_radix(2));
+        assert_eq!(
+            offset.count_digits_radix_abs(16),
+            bytes.count_digits_radix_abs(16)
+        );
+        assert_eq!(
+            offset.grouped_width_with_sign(3),
+            bytes.grouped_width_with_sign(3)
+        );
+        assert_eq!(
+            offset.count_balanced_ternary_digits(),
+            bytes.count_balanced_ternary_digits()
+        );
+
+        let (mut derived, mut direct) = ([0_u8; 40], [0_u8; 40]);
+        assert_eq!(
+            offset.write_ascii_digits_radix(10, false, &mut derived),
+            bytes.write_ascii_digits_radix(10, false, &mut direct)
+        );
+        assert_eq!(derived, direct);
+    }
+}
+
+#[test]
+fn generic_and_nested_structs_forward_to_the_field() {
+    let non_zero = NonZeroU32::new(100_000).unwrap();
+    assert_eq!(Wrapper(non_zero).count_digits(), 6);
+    assert_eq!(Wrapper(-128_i8).count_digits_radix(2), 8);
+    assert_eq!(Wrapper(Some(255_u8)).count_hex_digits(), 2);
+    assert_eq!(Wrapper(None::<u8>).count_digits(), 0);
+    assert_eq!(Nested(UserId(12345)).count_digits(), 5);
+    assert_eq!(
+        Nested(UserId(12345)).common_prefix_len_radix(Nested(UserId(12399)), 10),
+        3
+    );
+}
@@ -0,0 +1,6 @@
+#[test]
+#[ignore = "the expected compiler errors are generated by Rust 1.95, so the ui job in CI runs these tests"]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
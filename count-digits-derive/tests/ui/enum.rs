@@ -0,0 +1,9 @@
+use count_digits::CountDigits;
+
+#[derive(Clone, Copy, CountDigits)]
+enum Either {
+    Left(u8),
+    Right(u8),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use count_digits::CountDigits;
+
+#[derive(Clone, Copy, CountDigits)]
+struct Ratio(f64);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use count_digits::CountDigits;
+
+#[derive(Clone, Copy, CountDigits)]
+struct Point(u32, u32);
+
+fn main() {}
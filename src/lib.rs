@@ -24,6 +24,14 @@
 //!
 //! It is [implemented](https://docs.rs/count-digits/latest/count_digits/trait.CountDigits.html#foreign-impls)
 //! for all primitive integer types and all non-zero integer types.
+//! With the `ethnum` feature, it is also implemented for the 256-bit integer types
+//! [U256](https://docs.rs/ethnum/latest/ethnum/struct.U256.html) and
+//! [I256](https://docs.rs/ethnum/latest/ethnum/struct.I256.html) of the [ethnum](https://docs.rs/ethnum) crate.
+//! With the `derive` feature, `#[derive(CountDigits)]` implements it for a struct with a single
+//! integer field, such as `struct UserId(u64)`, by forwarding every function to the field.
+//!
+//! The minimum supported Rust version is 1.64.
+//! With the `ethnum` feature, Rust 1.64 also requires ethnum 1.5.2 or earlier.
 //!
 //! ```rust
 //! pub trait CountDigits: Copy + Sized {
@@ -143,7 +151,7 @@
 //! must be greater than or equal to 2.
 //!
 //! * [count_digits_radix()](https://docs.rs/count-digits/latest/count_digits/trait.CountDigits.html#tymethod.count_digits_radix)
-//! will [panic](https://doc.rust-lang.org/stable/core/macro.panic.html) if given an invalid radix.
+//!   will [panic](https://doc.rust-lang.org/stable/core/macro.panic.html) if given an invalid radix.
 //!
 //! ```rust
 //! # use count_digits::CountDigits;
@@ -154,7 +162,7 @@
 //! ```
 //!
 //! * [checked_count_digits_radix()](https://docs.rs/count-digits/latest/count_digits/trait.CountDigits.html#tymethod.checked_count_digits_radix)
-//! will return [None](https://doc.rust-lang.org/stable/core/option/enum.Option.html#variant.None) if given an invalid radix.
+//!   will return [None](https://doc.rust-lang.org/stable/core/option/enum.Option.html#variant.None) if given an invalid radix.
 //!
 //! ```rust
 //! # use count_digits::CountDigits;
@@ -232,8 +240,31 @@
 //! * [count_digits()](https://nordzilla.github.io/count-digits/count_digits/report/index.html)
 //! * [count_hex_digits()](https://nordzilla.github.io/count-digits/count_hex_digits/report/index.html)
 
+use core::cmp::{Ordering, Reverse};
+use core::fmt;
 use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::{Div, DivAssign, Rem};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "derive")]
+/// Derives [CountDigits] for a struct with a single field that implements [CountDigits].
+///
+/// Requires the `derive` feature. See [count_digits_derive::CountDigits](https://docs.rs/count-digits-derive).
+pub use count_digits_derive::CountDigits;
+
+use ilog::{IntegerLogarithm, NonZeroIntegerLogarithm};
 
 /// A [no-std](https://docs.rust-embedded.org/book/intro/no-std.html) trait to determine
 /// lengths of integers in various number bases.
@@ -698,13 +729,29 @@ pub trait CountDigits: Copy + Sized {
     ///
     /// For all other radix values, counts digits according to the
     /// <a href="https://en.wikipedia.org/wiki/Two%27s_complement">twos-complement</a> representation.
+    ///
+    /// The minimum value of a signed type cannot be negated, but its twos-complement representation
+    /// is the same as its magnitude, so it has the same count of digits as its magnitude in every radix.
     /// </div>
     ///
+    /// A non-negative integer has a single digit exactly when it is less than the radix,
+    /// so `radix - 1` has one digit, while `radix` and `radix + 1` have two.
+    ///
+    /// The count is at most the count of bits in the integer's type, so it always fits in a [usize],
+    /// even where [usize] is 16 bits wide, and never needs to saturate.
+    ///
     /// # Examples
     ///
     /// ```rust
     /// use count_digits::CountDigits;
     ///
+    /// assert_eq!(i128::MIN.count_digits_radix(7_u128), i128::MIN.unsigned_abs().count_digits_radix(7));
+    /// assert_eq!(i128::MIN.count_digits_radix(7_u128), 46);
+    ///
+    /// assert_eq!(1, 15_u8.count_digits_radix(16));
+    /// assert_eq!(2, 16_u8.count_digits_radix(16));
+    /// assert_eq!(2, 17_u8.count_digits_radix(16));
+    ///
     /// for n in 0..100 {
     ///   assert!(std::panic::catch_unwind(|| n.count_digits_radix(0_u32)).is_err());
     ///   assert!(std::panic::catch_unwind(|| n.count_digits_radix(1_u32)).is_err());
@@ -745,903 +792,12361 @@ pub trait CountDigits: Copy + Sized {
     /// }
     /// ```
     fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize>;
-}
-
-macro_rules! impl_count_digits {
-    (
-        primitive_type = $primitive_type:ty,
-        non_zero_type = $non_zero_type:ty,
-        radix_type = $radix_type:ty,
-        min_value_bits = $min_value_bits:expr,
-        min_value_octal_digits = $min_value_octal_digits:expr,
-        min_value_hex_digits = $min_value_hex_digits:expr $(,)?
-    ) => {
-        impl CountDigits for $primitive_type {
-            type Radix = $radix_type;
 
-            #[inline(always)]
-            /// Returns the count of bits in an integer.
-            fn count_bits(self) -> u32 {
-                if self.is_negative() {
-                    $min_value_bits
-                } else {
-                    1 + self.checked_ilog2().unwrap_or_default()
-                }
-            }
+    #[inline(always)]
+    /// Returns how many more decimal digits this integer has than the other integer.
+    ///
+    /// The result is negative if the other integer has more decimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(02, 1000.digit_width_delta(10));
+    /// assert_eq!(-2, 10.digit_width_delta(1000));
+    /// assert_eq!(00, 99.digit_width_delta(-10));
+    /// ```
+    fn digit_width_delta(self, other: Self) -> isize {
+        self.count_digits() as isize - other.count_digits() as isize
+    }
 
-            #[inline(always)]
-            /// Returns the count of octal digits in an integer.
-            fn count_octal_digits(self) -> u32 {
-                if self.is_negative() {
-                    $min_value_octal_digits
-                } else {
-                    1 + self.checked_ilog2().unwrap_or_default() / 3
-                }
-            }
+    #[inline(always)]
+    /// Returns how many more digits this integer has than the other integer
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// The result is negative if the other integer has more digits.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(03, 0b1000_u32.digit_width_delta_radix(0b1, 2));
+    /// assert_eq!(-1, 0x0F_u32.digit_width_delta_radix(0xF0, 16));
+    /// assert_eq!(00, 0o77_u32.digit_width_delta_radix(0o10, 8));
+    /// ```
+    fn digit_width_delta_radix(self, other: Self, radix: Self::Radix) -> isize
+    where
+        Self::Radix: Copy,
+    {
+        self.count_digits_radix(radix) as isize - other.count_digits_radix(radix) as isize
+    }
 
-            #[inline(always)]
-            /// Returns the count of hexadecimal digits in an integer.
-            fn count_hex_digits(self) -> u32 {
-                if self.is_negative() {
-                    $min_value_hex_digits
-                } else {
-                    1 + self.checked_ilog2().unwrap_or_default() / 4
-                }
-            }
+    /// Returns the count of trits in the [balanced ternary](https://en.wikipedia.org/wiki/Balanced_ternary)
+    /// representation of an integer.
+    ///
+    /// Balanced ternary uses the digits -1, 0, and 1, so negative integers are represented
+    /// natively and an integer has the same count as its negated value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 0_i32.count_balanced_ternary_digits()); // 0
+    /// assert_eq!(1, 1_i32.count_balanced_ternary_digits()); // 1
+    /// assert_eq!(1, (-1_i32).count_balanced_ternary_digits()); // T
+    /// assert_eq!(2, 2_i32.count_balanced_ternary_digits()); // 1T
+    /// assert_eq!(2, 4_i32.count_balanced_ternary_digits()); // 11
+    /// assert_eq!(3, 5_i32.count_balanced_ternary_digits()); // 1TT
+    /// assert_eq!(3, (-5_i32).count_balanced_ternary_digits()); // T11
+    /// ```
+    fn count_balanced_ternary_digits(self) -> u32;
 
-            #[inline(always)]
-            /// Returns the count of decimal digits in an integer.
-            fn count_digits(self) -> usize {
-                1 + self.abs_diff(0).checked_ilog10().unwrap_or_default() as usize
-            }
+    #[inline(always)]
+    /// Returns the count of bits in the [Gray code](https://en.wikipedia.org/wiki/Gray_code)
+    /// representation of an integer.
+    ///
+    /// The Gray code of an integer `n` is `n ^ (n >> 1)`, which never changes the position
+    /// of the most-significant set bit, so this is always equal to [count_bits()](CountDigits::count_bits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// for n in 0..1000_u32 {
+    ///     assert_eq!(n.count_gray_code_bits(), n.count_bits());
+    ///     assert_eq!(n.count_gray_code_bits(), (n ^ (n >> 1)).count_bits());
+    /// }
+    ///
+    /// assert_eq!(8, (-1_i8).count_gray_code_bits());
+    /// ```
+    fn count_gray_code_bits(self) -> u32 {
+        self.count_bits()
+    }
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// [Panics](panic) if the provided radix is 0 or 1.
-            ///
-            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
-            fn count_digits_radix(self, radix: Self::Radix) -> usize {
-                match radix {
-                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
-                    02 => self.count_bits() as usize,
-                    08 => self.count_octal_digits() as usize,
-                    10 => self.count_digits(),
-                    16 => self.count_hex_digits() as usize,
-                    __ => {
-                        if self.is_negative() {
-                            1 + <$primitive_type>::MIN.abs_diff(0).ilog(radix) as usize
-                        } else {
-                            1 + self.abs_diff(0).checked_ilog(radix).unwrap_or_default() as usize
-                        }
-                    }
-                }
-            }
+    #[inline(always)]
+    /// Returns the count of bits in an integer as a [NonZeroU32].
+    ///
+    /// Every integer, including zero, has at least one digit, so the result of
+    /// [count_bits()](CountDigits::count_bits) is never zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    /// use core::num::NonZeroU32;
+    ///
+    /// assert_eq!(NonZeroU32::new(1).unwrap(), 0_u32.count_bits_nonzero());
+    /// assert_eq!(NonZeroU32::new(4).unwrap(), 0b1011_u8.count_bits_nonzero());
+    /// assert_eq!(0b1011_u8.count_bits(), 0b1011_u8.count_bits_nonzero().get());
+    /// ```
+    fn count_bits_nonzero(self) -> NonZeroU32 {
+        ONE_DIGIT.saturating_add(self.count_bits().saturating_sub(1))
+    }
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// Returns [None] if the provided radix is 0 or 1.
-            ///
-            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
-            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
-                match radix {
-                    0 | 1 => None,
-                    radix => Some(self.count_digits_radix(radix)),
-                }
-            }
-        }
+    #[inline(always)]
+    /// Returns the count of octal digits in an integer as a [NonZeroU32].
+    ///
+    /// Every integer, including zero, has at least one digit, so the result of
+    /// [count_octal_digits()](CountDigits::count_octal_digits) is never zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    /// use core::num::NonZeroU32;
+    ///
+    /// assert_eq!(NonZeroU32::new(1).unwrap(), 0_u32.count_octal_digits_nonzero());
+    /// assert_eq!(NonZeroU32::new(4).unwrap(), 0o1777_u16.count_octal_digits_nonzero());
+    /// assert_eq!(0o1777_u16.count_octal_digits(), 0o1777_u16.count_octal_digits_nonzero().get());
+    /// ```
+    fn count_octal_digits_nonzero(self) -> NonZeroU32 {
+        ONE_DIGIT.saturating_add(self.count_octal_digits().saturating_sub(1))
+    }
 
-        impl CountDigits for $non_zero_type {
-            type Radix = $radix_type;
+    #[inline(always)]
+    /// Returns the count of hexadecimal digits in an integer as a [NonZeroU32].
+    ///
+    /// Every integer, including zero, has at least one digit, so the result of
+    /// [count_hex_digits()](CountDigits::count_hex_digits) is never zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    /// use core::num::NonZeroU32;
+    ///
+    /// assert_eq!(NonZeroU32::new(1).unwrap(), 0_u32.count_hex_digits_nonzero());
+    /// assert_eq!(NonZeroU32::new(4).unwrap(), 0xF00D_u32.count_hex_digits_nonzero());
+    /// assert_eq!(0xF00D_u32.count_hex_digits(), 0xF00D_u32.count_hex_digits_nonzero().get());
+    /// ```
+    fn count_hex_digits_nonzero(self) -> NonZeroU32 {
+        ONE_DIGIT.saturating_add(self.count_hex_digits().saturating_sub(1))
+    }
 
-            #[inline(always)]
-            /// Returns the count of bits in an integer.
-            fn count_bits(self) -> u32 {
-                if self.is_negative() {
-                    $min_value_bits
-                } else {
-                    1 + self.get().ilog2()
-                }
-            }
+    /// Returns the bits of an integer reinterpreted as the corresponding unsigned primitive type.
+    ///
+    /// This is the value whose digits are counted when a negative integer is interpreted with a radix
+    /// other than 10, according to the
+    /// [twos-complement](https://en.wikipedia.org/wiki/Two%27s_complement) representation.
+    ///
+    /// For unsigned integers, this is the value itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    /// # use core::num::{NonZeroI8, NonZeroU8};
+    ///
+    /// assert_eq!(255_u8, (-1_i8).bit_pattern_as_unsigned());
+    /// assert_eq!(128_u8, i8::MIN.bit_pattern_as_unsigned());
+    /// assert_eq!(127_u8, i8::MAX.bit_pattern_as_unsigned());
+    /// assert_eq!(255_u8, NonZeroI8::new(-1).unwrap().bit_pattern_as_unsigned());
+    /// assert_eq!(255_u8, u8::MAX.bit_pattern_as_unsigned());
+    /// assert_eq!(255_u8, NonZeroU8::MAX.bit_pattern_as_unsigned());
+    ///
+    /// assert_eq!(
+    ///     (-1_i8).count_hex_digits(),
+    ///     (-1_i8).bit_pattern_as_unsigned().count_hex_digits(),
+    /// );
+    /// ```
+    fn bit_pattern_as_unsigned(self) -> Self::Radix;
 
-            #[inline(always)]
-            /// Returns the count of octal digits in an integer.
-            fn count_octal_digits(self) -> u32 {
-                if self.is_negative() {
-                    $min_value_octal_digits
-                } else {
-                    1 + self.get().ilog2() / 3
-                }
-            }
+    /// Returns the count of bits in the bitwise complement of an integer.
+    ///
+    /// This is `(!self).count_bits()`, which is also available for the [NonZero](core::num) types,
+    /// whose complement is computed on the inner value. The complement of a non-zero integer with every
+    /// bit set is zero, which has one bit, as with [count_bits()](CountDigits::count_bits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    /// # use core::num::{NonZeroI8, NonZeroU8};
+    ///
+    /// assert_eq!(4, 0b1111_0000_u8.count_bits_of_complement());
+    /// assert_eq!(8, 0_u8.count_bits_of_complement());
+    /// assert_eq!(8, 0_i8.count_bits_of_complement());
+    /// assert_eq!(7, 0b1000_0000_u8.count_bits_of_complement());
+    ///
+    /// // The complement of a non-zero integer can be zero.
+    /// assert_eq!(1, NonZeroU8::MAX.count_bits_of_complement());
+    /// assert_eq!(1, NonZeroI8::new(-1).unwrap().count_bits_of_complement());
+    /// ```
+    fn count_bits_of_complement(self) -> u32;
 
-            #[inline(always)]
-            /// Returns the count of hexadecimal digits in an integer.
-            fn count_hex_digits(self) -> u32 {
-                if self.is_negative() {
-                    $min_value_hex_digits
-                } else {
-                    1 + self.get().ilog2() / 4
-                }
-            }
+    /// Returns the minimum count of bits that can hold an integer in
+    /// [twos-complement](https://en.wikipedia.org/wiki/Two%27s_complement) representation,
+    /// such as for inferring the width of a signed register.
+    ///
+    /// This is the smallest `w` such that `-2^(w-1) <= n < 2^(w-1)`, which is one sign bit plus
+    /// the count of significant bits in `n` for non-negative integers, or in `!n` for negative integers,
+    /// where zero has no significant bits. Unlike [count_bits()](CountDigits::count_bits), a negative
+    /// integer does not take the full width of its type, and a non-negative integer needs room for its sign,
+    /// so unsigned integers need one bit more than [count_bits()](CountDigits::count_bits) unless they are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 0_i32.min_twos_complement_bits());
+    /// assert_eq!(1, (-1_i32).min_twos_complement_bits());
+    /// assert_eq!(2, 1_i32.min_twos_complement_bits());
+    /// assert_eq!(8, 127_i32.min_twos_complement_bits());
+    /// assert_eq!(9, 128_i32.min_twos_complement_bits());
+    /// assert_eq!(8, (-128_i32).min_twos_complement_bits());
+    /// assert_eq!(9, (-129_i32).min_twos_complement_bits());
+    /// assert_eq!(9, 255_u8.min_twos_complement_bits());
+    /// ```
+    fn min_twos_complement_bits(self) -> u32;
 
-            #[inline(always)]
-            /// Returns the count of decimal digits in an integer.
-            fn count_digits(self) -> usize {
-                1 + self.get().abs_diff(0).ilog10() as usize
-            }
+    /// Returns an iterator over the count of decimal digits of each byte of an integer's
+    /// little-endian representation, such as for aligning the columns of a per-byte table.
+    ///
+    /// Each byte of the [twos-complement](https://en.wikipedia.org/wiki/Two%27s_complement) bit pattern
+    /// yields the [count_digits()](CountDigits::count_digits) of that byte, from 1 to 3,
+    /// in the order of the bytes returned by `to_le_bytes()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(0x01_0A_64_FF_u32.per_byte_decimal_widths().eq([3, 3, 2, 1]));
+    /// assert!(0_u16.per_byte_decimal_widths().eq([1, 1]));
+    /// assert!((-1_i16).per_byte_decimal_widths().eq([3, 3]));
+    /// ```
+    fn per_byte_decimal_widths(self) -> PerByteDecimalWidths;
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// [Panics](panic) if the provided radix is 0 or 1.
-            ///
-            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
-            fn count_digits_radix(self, radix: Self::Radix) -> usize {
-                match radix {
-                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
-                    02 => self.count_bits() as usize,
-                    08 => self.count_octal_digits() as usize,
-                    10 => self.count_digits(),
-                    16 => self.count_hex_digits() as usize,
-                    __ => {
-                        if self.is_negative() {
-                            1 + <$primitive_type>::MIN.abs_diff(0).ilog(radix) as usize
-                        } else {
-                            1 + self.get().abs_diff(0).ilog(radix) as usize
-                        }
-                    }
-                }
-            }
+    /// Returns the count of digits in the bitwise complement of an integer as interpreted with the given
+    /// [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// This is `(!self).count_digits_radix(radix)`, which is also available for the [NonZero](core::num) types,
+    /// whose complement is computed on the inner value. The complement of a non-zero integer with every
+    /// bit set is zero, which has one digit. The complement of a signed integer is `-self - 1`, which is
+    /// counted as in [count_digits_radix()](CountDigits::count_digits_radix).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    /// # use core::num::NonZeroU16;
+    ///
+    /// assert_eq!(2, 0xFF00_u16.count_digits_radix_of_complement(16));
+    /// assert_eq!(5, 0_u16.count_digits_radix_of_complement(10));
+    /// assert_eq!(3, 99_i8.count_digits_radix_of_complement(10));
+    /// assert_eq!(1, NonZeroU16::MAX.count_digits_radix_of_complement(10));
+    /// ```
+    fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize;
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// Returns [None] if the provided radix is 0 or 1.
-            ///
-            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
-            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
-                match radix {
-                    0 | 1 => None,
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// where the radix is passed by reference.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// See [count_digits_radix()](CountDigits::count_digits_radix) for passing the radix by value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// struct Config {
+    ///     radix: u32,
+    /// }
+    ///
+    /// let config = Config { radix: 7 };
+    /// let numbers = [6_u32, 7, 48, 49];
+    ///
+    /// assert_eq!(
+    ///     [1, 2, 2, 3],
+    ///     numbers.map(|n| n.count_digits_radix_ref(&config.radix)),
+    /// );
+    /// assert!(numbers
+    ///     .iter()
+    ///     .all(|n| n.count_digits_radix_ref(&config.radix) == n.count_digits_radix(config.radix)));
+    /// ```
+    fn count_digits_radix_ref(self, radix: &Self::Radix) -> usize
+    where
+        Self::Radix: Copy,
+    {
+        self.count_digits_radix(*radix)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Returns the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// without any prefix.
+    ///
+    /// Digits greater than 9 are rendered as the letters `a` through `z`, or as `A` through `Z` if `uppercase` is true.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 36.
+    ///
+    /// <div class="warning" style="text-align: left;">
+    /// For radix 10, negative, signed integers are rendered with a leading negative sign.
+    ///
+    /// For all other radix values, renders digits according to the
+    /// <a href="https://en.wikipedia.org/wiki/Two%27s_complement">twos-complement</a> representation,
+    /// so the length of the string is equal to the count returned by [count_digits_radix()](CountDigits::count_digits_radix).
+    /// </div>
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!("f00d", 0xF00D_u32.to_string_radix(16, false));
+    /// assert_eq!("F00D", 0xF00D_u32.to_string_radix(16, true));
+    /// assert_eq!("-1337", (-1337_i32).to_string_radix(10, false));
+    /// assert_eq!("11111111", (-1_i8).to_string_radix(2, false));
+    /// assert_eq!("100110", (-1_i8).to_string_radix(3, false));
+    ///
+    /// for radix in (2..=36).filter(|&radix| radix != 10) {
+    ///     let string = (-1337_i32).to_string_radix(radix, false);
+    ///     assert_eq!(string.len(), (-1337_i32).count_digits_radix(radix));
+    /// }
+    /// ```
+    fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String;
+
+    /// Writes the ASCII digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+    /// to the start of the buffer, without any prefix.
+    ///
+    /// Returns the count of bytes written, or the count of bytes required if the buffer is too short,
+    /// in which case the buffer is left unchanged. The digits are the same as those rendered by
+    /// [to_string_radix()](CountDigits::to_string_radix), but this does not require the `alloc` feature.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 36.
+    ///
+    /// <div class="warning" style="text-align: left;">
+    /// For radix 10, negative, signed integers are written with a leading negative sign, so the length
+    /// is one more than the count returned by [count_digits()](CountDigits::count_digits).
+    ///
+    /// For all other radix values, writes digits according to the
+    /// <a href="https://en.wikipedia.org/wiki/Two%27s_complement">twos-complement</a> representation,
+    /// so the length is equal to the count returned by [count_digits_radix()](CountDigits::count_digits_radix).
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// let mut buf = [0_u8; 8];
+    ///
+    /// assert_eq!(Ok(4), 0xF00D_u32.write_ascii_digits_radix(16, false, &mut buf));
+    /// assert_eq!(b"f00d", &buf[..4]);
+    ///
+    /// assert_eq!(Ok(4), 0xF00D_u32.write_ascii_digits_radix(16, true, &mut buf));
+    /// assert_eq!(b"F00D", &buf[..4]);
+    ///
+    /// assert_eq!(Ok(5), (-1337_i32).write_ascii_digits_radix(10, false, &mut buf));
+    /// assert_eq!(b"-1337", &buf[..5]);
+    ///
+    /// assert_eq!(Err(32), (-1_i32).write_ascii_digits_radix(2, false, &mut buf));
+    /// ```
+    fn write_ascii_digits_radix(
+        self,
+        radix: Self::Radix,
+        uppercase: bool,
+        buf: &mut [u8],
+    ) -> Result<usize, usize>;
+
+    /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// from the least-significant digit to the most-significant digit.
+    ///
+    /// The digits are computed by repeated division, so the count of digits is never computed up front.
+    /// Zero yields a single 0 digit. Reversing the iterator's digits gives the digits rendered by
+    /// [write_ascii_digits_radix()](CountDigits::write_ascii_digits_radix), as values rather than ASCII.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 256.
+    ///
+    /// <div class="warning" style="text-align: left;">
+    /// For radix 10, negative, signed integers yield the digits of their magnitude, without a sign.
+    ///
+    /// For all other radix values, yields digits according to the
+    /// <a href="https://en.wikipedia.org/wiki/Two%27s_complement">twos-complement</a> representation,
+    /// so the count of digits is equal to the count returned by [count_digits_radix()](CountDigits::count_digits_radix).
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(1234_u32.digits_radix_le(10).eq([4, 3, 2, 1]));
+    /// assert!(0xF00D_u32.digits_radix_le(16).eq([0xD, 0x0, 0x0, 0xF]));
+    /// assert!((-1337_i32).digits_radix_le(10).eq([7, 3, 3, 1]));
+    /// assert!((-1_i8).digits_radix_le(2).eq([1; 8]));
+    /// assert!(0_u8.digits_radix_le(10).eq([0]));
+    /// ```
+    fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe;
+
+    #[inline(always)]
+    /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// from the most-significant digit to the least-significant digit, paired with the
+    /// [positional weight](https://en.wikipedia.org/wiki/Positional_notation) of each digit.
+    ///
+    /// The weight of a digit is `radix.pow(position)`, so the sum of each digit multiplied by its weight
+    /// is the magnitude whose digits are yielded by [digits_radix_le()](CountDigits::digits_radix_le).
+    /// The digits are those digits in the opposite order, so zero yields exactly one `(0, 1)` pair, and negative,
+    /// signed integers yield the digits of their magnitude in radix 10, and of their
+    /// [twos-complement](https://en.wikipedia.org/wiki/Two%27s_complement) representation in every other radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 256,
+    /// or if the magnitude does not fit in a [u128], which can only happen with 256-bit integers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(1234_u32.weighted_digits_radix(10).eq([(1, 1000), (2, 100), (3, 10), (4, 1)]));
+    /// assert!(0xF00D_u32.weighted_digits_radix(16).eq([(0xF, 0x1000), (0x0, 0x100), (0x0, 0x10), (0xD, 0x1)]));
+    /// assert!((-1337_i32).weighted_digits_radix(10).eq([(1, 1000), (3, 100), (3, 10), (7, 1)]));
+    /// assert!(0_u8.weighted_digits_radix(10).eq([(0, 1)]));
+    ///
+    /// let sum: u128 = 1234_u32
+    ///     .weighted_digits_radix(7)
+    ///     .map(|(digit, weight)| digit as u128 * weight)
+    ///     .sum();
+    /// assert_eq!(sum, 1234);
+    /// ```
+    fn weighted_digits_radix(self, radix: Self::Radix) -> WeightedDigitsRadix {
+        WeightedDigitsRadix::of_digits(self.digits_radix_le(radix))
+    }
+
+    #[inline(always)]
+    /// Returns the count of leading digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+    /// that are equal to its most-significant digit.
+    ///
+    /// The digits are those yielded by [digits_radix_le()](CountDigits::digits_radix_le), so the count is at least 1,
+    /// and the run is counted from the end of the little-endian digits, which supports integers of every width.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 256.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(2, 7789_u32.leading_repeat_count_radix(10));
+    /// assert_eq!(3, 333_u32.leading_repeat_count_radix(10));
+    /// assert_eq!(1, 1234_u32.leading_repeat_count_radix(10));
+    /// assert_eq!(1, 0_u32.leading_repeat_count_radix(10));
+    /// assert_eq!(3, 0xFFF0_u32.leading_repeat_count_radix(16));
+    /// assert_eq!(8, (-1_i8).leading_repeat_count_radix(2));
+    /// ```
+    fn leading_repeat_count_radix(self, radix: Self::Radix) -> u32 {
+        self.digits_radix_le(radix)
+            .fold((None, 0), |(last, run), digit| {
+                if last == Some(digit) {
+                    (last, run + 1)
+                } else {
+                    (Some(digit), 1)
+                }
+            })
+            .1
+    }
+
+    /// Returns the largest value of a single digit in the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// which is one less than the radix.
+    ///
+    /// This is the largest index into an alphabet of digit symbols for the radix, such as the
+    /// digits yielded by [digits_radix_le()](CountDigits::digits_radix_le).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1, or greater than 256.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, u32::max_digit_value(2));
+    /// assert_eq!(9, u32::max_digit_value(10));
+    /// assert_eq!(15, i64::max_digit_value(16));
+    /// assert_eq!(255, u16::max_digit_value(256));
+    /// ```
+    fn max_digit_value(radix: Self::Radix) -> u8;
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// rounded up to the next multiple of `align`.
+    ///
+    /// An `align` of 0 or 1 leaves the count unchanged. The rounded count saturates at [usize::MAX]
+    /// rather than overflowing.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 0xF_u32.count_digits_radix_aligned(16, 0));
+    /// assert_eq!(2, 0xF_u32.count_digits_radix_aligned(16, 2));
+    /// assert_eq!(4, 0xFFF_u32.count_digits_radix_aligned(16, 2));
+    /// assert_eq!(8, 0b1011_u32.count_digits_radix_aligned(2, 8));
+    /// assert_eq!(16, 0b1_0000_0000_u32.count_digits_radix_aligned(2, 8));
+    /// ```
+    fn count_digits_radix_aligned(self, radix: Self::Radix, align: u32) -> usize {
+        let count = self.count_digits_radix(radix);
+        match usize::try_from(align).unwrap_or(usize::MAX) {
+            0 => count,
+            align => match count % align {
+                0 => count,
+                remainder => count.saturating_add(align - remainder),
+            },
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of groups of `group_size` digits needed to hold the digits of an integer
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// This is the count of digits divided by the group size, rounded up, such as the count of
+    /// base-1000 units in a decimal integer for a `group_size` of 3. A `group_size` of 0
+    /// returns the count of digits unchanged.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(3, 1_234_567_u32.count_digit_groups(3, 10));
+    /// assert_eq!(2, 123_456_u32.count_digit_groups(3, 10));
+    /// assert_eq!(1, 0_u32.count_digit_groups(3, 10));
+    /// assert_eq!(7, 1_234_567_u32.count_digit_groups(0, 10));
+    /// assert_eq!(2, 0x1_0000_u32.count_digit_groups(4, 16));
+    /// ```
+    fn count_digit_groups(self, group_size: u32, radix: Self::Radix) -> usize {
+        let count = self.count_digits_radix(radix);
+        match group_size as usize {
+            0 => count,
+            group_size => count / group_size + (count % group_size != 0) as usize,
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the tier of an integer's magnitude in groups of three decimal digits,
+    /// such as for choosing the suffix in `1.2K` or `3.4M`.
+    ///
+    /// This is `(count_digits() - 1) / 3`: 0 for no suffix, 1 for thousands, 2 for millions, and so on.
+    /// See [magnitude_tier_radix()](CountDigits::magnitude_tier_radix) for other radices and group sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(0, 999_u32.magnitude_tier());
+    /// assert_eq!(1, 1_000_u32.magnitude_tier());
+    /// assert_eq!(1, 999_999_u32.magnitude_tier());
+    /// assert_eq!(2, 1_000_000_u32.magnitude_tier());
+    /// assert_eq!(2, (-1_000_000_i32).magnitude_tier());
+    /// assert_eq!(0, 0_u32.magnitude_tier());
+    /// ```
+    fn magnitude_tier(self) -> u32 {
+        (self.count_digits().saturating_sub(1) / 3) as u32
+    }
+
+    #[inline(always)]
+    /// Returns the tier of an integer's magnitude in groups of `group` digits
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// This is `(count_digits_radix(radix) - 1) / group`, such as the tier of a binary prefix
+    /// for a radix of 2 and a `group` of 10. A `group` of 0 returns 0.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 1_000_u32.magnitude_tier_radix(10, 3));
+    /// assert_eq!(0, 1023_u32.magnitude_tier_radix(2, 10));
+    /// assert_eq!(1, 1024_u32.magnitude_tier_radix(2, 10));
+    /// assert_eq!(2, (1_u32 << 20).magnitude_tier_radix(2, 10));
+    /// assert_eq!(1, 0x1_0000_u32.magnitude_tier_radix(16, 4));
+    /// assert_eq!(0, u32::MAX.magnitude_tier_radix(10, 0));
+    /// ```
+    fn magnitude_tier_radix(self, radix: Self::Radix, group: u32) -> u32 {
+        match group as usize {
+            0 => 0,
+            group => (self.count_digits_radix(radix).saturating_sub(1) / group) as u32,
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// after multiplying it by `radix^shift`.
+    ///
+    /// Multiplying by a power of the radix appends `shift` zeros to the digits, so this is
+    /// `count_digits_radix(radix) + shift` without computing the product, which might overflow.
+    /// Zero is still zero after the multiplication, so it always has one digit.
+    /// The count saturates at [usize::MAX] rather than overflowing.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(5, 123_u8.count_digits_radix_shifted(10, 2));
+    /// assert_eq!(3, 123_u8.count_digits_radix_shifted(10, 0));
+    /// assert_eq!(1, 0_u8.count_digits_radix_shifted(10, 2));
+    /// assert_eq!(0x1000_u32.count_hex_digits() as usize, 1_u32.count_digits_radix_shifted(16, 3));
+    /// ```
+    fn count_digits_radix_shifted(self, radix: Self::Radix, shift: u32) -> usize
+    where
+        Self::Radix: PartialEq + From<u8>,
+    {
+        let count = self.count_digits_radix(radix);
+        if self.bit_pattern_as_unsigned() == 0.into() {
+            return count;
+        }
+        count.saturating_add(usize::try_from(shift).unwrap_or(usize::MAX))
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// after removing its `drop` least-significant digits.
+    ///
+    /// Removing digits divides the integer by `radix^drop`, so this is
+    /// `count_digits_radix(radix) - drop`, without computing the quotient. Removing every digit
+    /// leaves zero, which still has one digit.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(3, 12345_u32.count_digits_radix_truncated(10, 2));
+    /// assert_eq!(5, 12345_u32.count_digits_radix_truncated(10, 0));
+    /// assert_eq!(1, 12345_u32.count_digits_radix_truncated(10, 5));
+    /// assert_eq!(1, 12345_u32.count_digits_radix_truncated(10, 9));
+    /// assert_eq!(2, 0xFFF_u32.count_digits_radix_truncated(16, 1));
+    /// ```
+    fn count_digits_radix_truncated(self, radix: Self::Radix, drop: u32) -> usize {
+        match self.count_digits_radix(radix) {
+            0 => 0,
+            count => count.saturating_sub(drop as usize).max(1),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in an integer, counting zero as having no digits.
+    ///
+    /// This differs from [count_digits()](CountDigits::count_digits), which counts zero as a single digit,
+    /// and allows an empty representation of zero to be distinguished from a single zero digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(0, 0_i32.count_digits_nonempty());
+    /// assert_eq!(1, 0_i32.count_digits());
+    ///
+    /// assert_eq!(1, 7_i32.count_digits_nonempty());
+    /// assert_eq!(3, (-100_i32).count_digits_nonempty());
+    /// ```
+    fn count_digits_nonempty(self) -> usize
+    where
+        Self::Radix: From<u8>,
+    {
+        self.count_digits_radix_nonempty(10.into())
+    }
+
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// counting zero as having no digits.
+    ///
+    /// This differs from [count_digits_radix()](CountDigits::count_digits_radix), which counts zero as a single digit.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(0, 0_u32.count_digits_radix_nonempty(16));
+    /// assert_eq!(1, 0_u32.count_digits_radix(16));
+    ///
+    /// assert_eq!(2, 0xFF_u32.count_digits_radix_nonempty(16));
+    /// assert_eq!(8, 0xFF_u32.count_digits_radix_nonempty(2));
+    /// ```
+    fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize;
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with one of the common bases.
+    ///
+    /// Each [CommonBase] calls the function for that base:
+    /// [count_bits()](CountDigits::count_bits), [count_octal_digits()](CountDigits::count_octal_digits),
+    /// [count_digits()](CountDigits::count_digits), or [count_hex_digits()](CountDigits::count_hex_digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::{CommonBase, CountDigits};
+    ///
+    /// assert_eq!(8, 255_u8.count_digits_base(CommonBase::Binary));
+    /// assert_eq!(3, 255_u8.count_digits_base(CommonBase::Octal));
+    /// assert_eq!(3, 255_u8.count_digits_base(CommonBase::Decimal));
+    /// assert_eq!(2, 255_u8.count_digits_base(CommonBase::Hexadecimal));
+    ///
+    /// assert_eq!(3, (-1_i8).count_digits_base(CommonBase::Octal));
+    /// assert_eq!(1, (-1_i8).count_digits_base(CommonBase::Decimal));
+    /// ```
+    fn count_digits_base(self, base: CommonBase) -> usize {
+        match base {
+            CommonBase::Binary => self.count_bits() as usize,
+            CommonBase::Octal => self.count_octal_digits() as usize,
+            CommonBase::Decimal => self.count_digits(),
+            CommonBase::Hexadecimal => self.count_hex_digits() as usize,
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in an integer, counting zero according to the given [ZeroPolicy].
+    ///
+    /// [ZeroPolicy::OneDigit] gives the same count as [count_digits()](CountDigits::count_digits), and
+    /// [ZeroPolicy::ZeroDigits] gives the same count as [count_digits_nonempty()](CountDigits::count_digits_nonempty).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::{CountDigits, ZeroPolicy};
+    ///
+    /// assert_eq!(1, 0_i32.count_digits_with_zero_policy(ZeroPolicy::OneDigit));
+    /// assert_eq!(0, 0_i32.count_digits_with_zero_policy(ZeroPolicy::ZeroDigits));
+    ///
+    /// assert_eq!(3, (-100_i32).count_digits_with_zero_policy(ZeroPolicy::OneDigit));
+    /// assert_eq!(3, (-100_i32).count_digits_with_zero_policy(ZeroPolicy::ZeroDigits));
+    /// ```
+    fn count_digits_with_zero_policy(self, policy: ZeroPolicy) -> usize
+    where
+        Self::Radix: From<u8>,
+    {
+        match policy {
+            ZeroPolicy::OneDigit => self.count_digits(),
+            ZeroPolicy::ZeroDigits => self.count_digits_nonempty(),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// counting zero according to the given [ZeroPolicy].
+    ///
+    /// [ZeroPolicy::OneDigit] gives the same count as [count_digits_radix()](CountDigits::count_digits_radix), and
+    /// [ZeroPolicy::ZeroDigits] gives the same count as [count_digits_radix_nonempty()](CountDigits::count_digits_radix_nonempty).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::{CountDigits, ZeroPolicy};
+    ///
+    /// assert_eq!(1, 0_u32.count_digits_radix_with_zero_policy(16, ZeroPolicy::OneDigit));
+    /// assert_eq!(0, 0_u32.count_digits_radix_with_zero_policy(16, ZeroPolicy::ZeroDigits));
+    ///
+    /// assert_eq!(2, 0xFF_u32.count_digits_radix_with_zero_policy(16, ZeroPolicy::ZeroDigits));
+    /// ```
+    fn count_digits_radix_with_zero_policy(self, radix: Self::Radix, policy: ZeroPolicy) -> usize {
+        match policy {
+            ZeroPolicy::OneDigit => self.count_digits_radix(radix),
+            ZeroPolicy::ZeroDigits => self.count_digits_radix_nonempty(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in an integer as a [u32].
+    ///
+    /// This is the same count as [count_digits()](CountDigits::count_digits), which always fits in a [u32],
+    /// for symmetry with [count_bits()](CountDigits::count_bits), [count_octal_digits()](CountDigits::count_octal_digits),
+    /// and [count_hex_digits()](CountDigits::count_hex_digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// let n = 0xF00D_u32;
+    /// let widths: [u32; 4] = [
+    ///     n.count_bits(),
+    ///     n.count_octal_digits(),
+    ///     n.count_decimal_digits_u32(),
+    ///     n.count_hex_digits(),
+    /// ];
+    ///
+    /// assert_eq!([16, 6, 5, 4], widths);
+    /// assert_eq!(39, u128::MAX.count_decimal_digits_u32());
+    /// ```
+    fn count_decimal_digits_u32(self) -> u32 {
+        self.count_digits() as u32
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// as a [u32].
+    ///
+    /// This is the same count as [count_digits_radix()](CountDigits::count_digits_radix), which is at most 128
+    /// and always fits in a [u32], so counts in every radix can be collected as the same type as
+    /// [count_bits()](CountDigits::count_bits), [count_octal_digits()](CountDigits::count_octal_digits),
+    /// and [count_hex_digits()](CountDigits::count_hex_digits).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// let n = 0xF00D_u32;
+    /// let widths = [2, 3, 10, 16].map(|radix| n.count_digits_radix_u32(radix));
+    ///
+    /// assert_eq!([n.count_bits(), 11, 5, n.count_hex_digits()], widths);
+    /// assert_eq!(128, (-1_i128).count_digits_radix_u32(2));
+    /// ```
+    fn count_digits_radix_u32(self, radix: Self::Radix) -> u32 {
+        self.count_digits_radix(radix) as u32
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// saturating at [usize::MAX] if the count does not fit in a [usize].
+    ///
+    /// Every supported radix is at least 2, so the count is at most the count of bits in the integer's type
+    /// and always fits, even when [usize] is 16 bits wide: this is the same count as
+    /// [count_digits_radix()](CountDigits::count_digits_radix). The saturating contract is the one that
+    /// any count which could exceed [usize::MAX] will follow, rather than wrapping or panicking.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(4, 0xF00D_u32.count_digits_saturating_radix(16));
+    /// assert_eq!(128, u128::MAX.count_digits_saturating_radix(2));
+    /// assert_eq!(128, (-1_i128).count_digits_saturating_radix(2));
+    /// ```
+    fn count_digits_saturating_radix(self, radix: Self::Radix) -> usize {
+        self.count_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Returns true if an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+    /// has at most the given count of digits.
+    ///
+    /// This is the same as comparing the count returned by [count_digits_radix()](CountDigits::count_digits_radix),
+    /// so negative, signed integers in radices other than 10 are measured by their
+    /// [twos-complement](https://en.wikipedia.org/wiki/Two%27s_complement) representation.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(0xFFFF_u32.fits_in_digits_radix(4, 16));
+    /// assert!(!0x10000_u32.fits_in_digits_radix(4, 16));
+    ///
+    /// assert!((-9999_i32).fits_in_digits_radix(4, 10));
+    /// assert!(!(-1_i32).fits_in_digits_radix(4, 16));
+    /// ```
+    fn fits_in_digits_radix(self, digits: u32, radix: Self::Radix) -> bool {
+        self.count_digits_radix(radix) <= digits as usize
+    }
+
+    #[inline(always)]
+    /// Returns true if an integer has exactly one decimal digit.
+    ///
+    /// Negative, signed integers are measured by their magnitude, as with [count_digits()](CountDigits::count_digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(0_u32.is_single_digit());
+    /// assert!(9_u32.is_single_digit());
+    /// assert!(!10_u32.is_single_digit());
+    /// assert!((-9_i32).is_single_digit());
+    /// ```
+    fn is_single_digit(self) -> bool {
+        self.count_digits() == 1
+    }
+
+    #[inline(always)]
+    /// Returns true if an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+    /// has exactly one digit.
+    ///
+    /// This is the same as comparing the count returned by [count_digits_radix()](CountDigits::count_digits_radix),
+    /// so negative, signed integers in radices other than 10 are measured by their
+    /// [twos-complement](https://en.wikipedia.org/wiki/Two%27s_complement) representation.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(0xF_u32.is_single_digit_radix(16));
+    /// assert!(!0x10_u32.is_single_digit_radix(16));
+    /// assert!(1_u32.is_single_digit_radix(2));
+    ///
+    /// assert!((-9_i8).is_single_digit_radix(10));
+    /// assert!(!(-1_i8).is_single_digit_radix(2));
+    /// ```
+    fn is_single_digit_radix(self, radix: Self::Radix) -> bool {
+        self.count_digits_radix(radix) == 1
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with a [radix](https://en.wikipedia.org/wiki/Radix)
+    /// that is given as a [usize].
+    ///
+    /// Returns [None] if the provided radix is 0 or 1, or if it does not fit in the [Radix](CountDigits::Radix) type.
+    ///
+    /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(Some(3), 100_i8.count_digits_radix_usize(10));
+    /// assert_eq!(Some(2), (-1_i8).count_digits_radix_usize(16));
+    /// assert_eq!(None, 100_i8.count_digits_radix_usize(1));
+    /// assert_eq!(None, 100_i8.count_digits_radix_usize(300));
+    /// assert_eq!(Some(1), 100_i16.count_digits_radix_usize(300));
+    /// ```
+    fn count_digits_radix_usize(self, radix: usize) -> Option<usize>
+    where
+        Self::Radix: TryFrom<usize>,
+    {
+        Self::Radix::try_from(radix)
+            .ok()
+            .and_then(|radix| self.checked_count_digits_radix(radix))
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with a [radix](https://en.wikipedia.org/wiki/Radix)
+    /// that is given as a [u32].
+    ///
+    /// Returns a [RadixError] that describes why the radix is invalid if it is 0 or 1,
+    /// or if it does not fit in the [Radix](CountDigits::Radix) type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::{CountDigits, RadixError};
+    ///
+    /// assert_eq!(Ok(3), 100_i8.try_count_digits_radix_u32(10));
+    /// assert_eq!(Err(RadixError::Zero), 100_i8.try_count_digits_radix_u32(0));
+    /// assert_eq!(Err(RadixError::One), 100_i8.try_count_digits_radix_u32(1));
+    /// assert_eq!(Err(RadixError::TooLargeForType), 100_i8.try_count_digits_radix_u32(256));
+    /// assert_eq!(Ok(1), 100_i16.try_count_digits_radix_u32(256));
+    /// ```
+    fn try_count_digits_radix_u32(self, radix: u32) -> Result<usize, RadixError>
+    where
+        Self::Radix: TryFrom<u32>,
+    {
+        match radix {
+            0 => Err(RadixError::Zero),
+            1 => Err(RadixError::One),
+            radix => Self::Radix::try_from(radix)
+                .map(|radix| self.count_digits_radix(radix))
+                .map_err(|_| RadixError::TooLargeForType),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the counts of decimal digits before and after the decimal point of an integer
+    /// that is interpreted as a fixed-point number whose last `scale` digits are fractional.
+    ///
+    /// The fractional part always has `scale` digits. An integer with no more than `scale` digits
+    /// has an integer part of `0`, which is one digit, and its fractional part is padded with leading zeros.
+    /// As with [count_digits()](CountDigits::count_digits), the negative sign is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// // 123.45
+    /// assert_eq!((3, 2), 12345_u32.count_digits_fixed_point(2));
+    /// // 0.05
+    /// assert_eq!((1, 2), 5_u32.count_digits_fixed_point(2));
+    /// // -1.50
+    /// assert_eq!((1, 2), (-150_i32).count_digits_fixed_point(2));
+    /// // 12345
+    /// assert_eq!((5, 0), 12345_u32.count_digits_fixed_point(0));
+    /// ```
+    fn count_digits_fixed_point(self, scale: u32) -> (usize, usize) {
+        let scale = scale as usize;
+        match self.count_digits() {
+            0 => (0, 0),
+            count if count > scale => (count - scale, scale),
+            _ => (1, scale),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the width of an integer when formatted as hexadecimal with Rust's
+    /// [LowerHex](core::fmt::LowerHex) or [UpperHex](core::fmt::UpperHex) formatting traits.
+    ///
+    /// The alternate flag (`{:#x}` or `{:#X}`) adds two characters for the `0x` prefix.
+    /// The case of the digits never affects the width, so `_uppercase` is accepted only to mirror
+    /// the choice between the two formatting traits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(format!("{:x}", 0xF00D_u32).len(), 0xF00D_u32.hex_formatted_width(false, false));
+    /// assert_eq!(format!("{:X}", 0xF00D_u32).len(), 0xF00D_u32.hex_formatted_width(false, true));
+    /// assert_eq!(format!("{:#x}", 0xF00D_u32).len(), 0xF00D_u32.hex_formatted_width(true, false));
+    /// assert_eq!(format!("{:#X}", 0xF00D_u32).len(), 0xF00D_u32.hex_formatted_width(true, true));
+    /// assert_eq!(format!("{:#X}", -1_i16).len(), (-1_i16).hex_formatted_width(true, true));
+    /// ```
+    fn hex_formatted_width(self, alternate: bool, _uppercase: bool) -> usize {
+        self.count_hex_digits() as usize + if alternate { 2 } else { 0 }
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the first valid
+    /// [radix](https://en.wikipedia.org/wiki/Radix) in the given slice.
+    ///
+    /// Radix values of 0 or 1 are skipped. Returns [None] if no radix in the slice is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(Some(3), 255_u32.count_digits_in_first_valid_radix(&[0, 1, 10, 16]));
+    /// assert_eq!(Some(2), 255_u32.count_digits_in_first_valid_radix(&[16, 10]));
+    /// assert_eq!(None, 255_u32.count_digits_in_first_valid_radix(&[0, 1]));
+    /// assert_eq!(None, 255_u32.count_digits_in_first_valid_radix(&[]));
+    /// ```
+    fn count_digits_in_first_valid_radix(self, radices: &[Self::Radix]) -> Option<usize>
+    where
+        Self::Radix: Copy,
+    {
+        radices
+            .iter()
+            .find_map(|&radix| self.checked_count_digits_radix(radix))
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Returns the count of digits in an integer for each of the given [radices](https://en.wikipedia.org/wiki/Radix),
+    /// in the same order, or [None] for each radix that is 0 or 1.
+    ///
+    /// Each element is equal to [checked_count_digits_radix()](CountDigits::checked_count_digits_radix)
+    /// for the corresponding radix.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(
+    ///     vec![Some(8), None, Some(3), Some(2)],
+    ///     255_u32.count_digits_radices(&[2, 1, 10, 16]),
+    /// );
+    /// assert!(255_u32.count_digits_radices(&[]).is_empty());
+    /// ```
+    fn count_digits_radices(self, radices: &[Self::Radix]) -> Vec<Option<usize>>
+    where
+        Self::Radix: Copy,
+    {
+        radices
+            .iter()
+            .map(|&radix| self.checked_count_digits_radix(radix))
+            .collect()
+    }
+
+    /// Returns whether an integer is the largest value with its count of digits
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// This is true when the integer is equal to `radix^k - 1` for some `k >= 1`,
+    /// which is exactly when incrementing it would increase its count of digits.
+    /// Zero and negative integers are never at a boundary.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(9_u32.is_radix_boundary(10));
+    /// assert!(999_u32.is_radix_boundary(10));
+    /// assert!(!1000_u32.is_radix_boundary(10));
+    ///
+    /// assert!(15_u32.is_radix_boundary(2));
+    /// assert!(0xFF_u32.is_radix_boundary(16));
+    /// assert!(!0_u32.is_radix_boundary(2));
+    /// assert!(!(-1_i32).is_radix_boundary(10));
+    /// ```
+    fn is_radix_boundary(self, radix: Self::Radix) -> bool;
+
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// along with its most-significant digit.
+    ///
+    /// Both values are computed from the same integer logarithm. The count is equal to
+    /// [count_digits_radix()](CountDigits::count_digits_radix), and the leading digit is taken
+    /// from the same representation, so negative integers use their magnitude in radix 10
+    /// and their twos-complement representation in every other radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1, or greater than 256.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!((4, 1), 1337_u32.count_and_msd_radix(10));
+    /// assert_eq!((4, 1), (-1337_i32).count_and_msd_radix(10));
+    /// assert_eq!((2, 15), 0xF0_u8.count_and_msd_radix(16));
+    /// assert_eq!((1, 0), 0_u8.count_and_msd_radix(2));
+    /// assert_eq!((8, 1), (-1_i8).count_and_msd_radix(2));
+    /// ```
+    fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8);
+
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// along with whether the integer is an exact power of the radix.
+    ///
+    /// Both values are computed from the same integer logarithm. The count is equal to
+    /// [count_digits_radix()](CountDigits::count_digits_radix), and the power is checked against the
+    /// same representation, so negative integers use their magnitude in radix 10 and their
+    /// twos-complement representation in every other radix. Zero is not a power of any radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!((4, true), 1000_u32.count_and_is_power_radix(10));
+    /// assert_eq!((4, false), 1001_u32.count_and_is_power_radix(10));
+    /// assert_eq!((1, true), 1_u32.count_and_is_power_radix(10));
+    /// assert_eq!((1, false), 0_u32.count_and_is_power_radix(10));
+    /// assert_eq!((4, true), (-1000_i32).count_and_is_power_radix(10));
+    /// assert_eq!((8, true), i8::MIN.count_and_is_power_radix(2));
+    /// ```
+    fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool);
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in an integer, but at least `min_width`.
+    ///
+    /// This models padding a field to a minimum width that grows to fit wider values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(4, 7_u32.count_digits_min(4));
+    /// assert_eq!(4, 1337_u32.count_digits_min(4));
+    /// assert_eq!(5, 13370_u32.count_digits_min(4));
+    /// ```
+    fn count_digits_min(self, min_width: usize) -> usize {
+        self.count_digits().max(min_width)
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// but at least `min_width`.
+    ///
+    /// This models padding a field to a minimum width that grows to fit wider values.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(4, 0xF_u32.count_digits_radix_min(16, 4));
+    /// assert_eq!(4, 0xFFFF_u32.count_digits_radix_min(16, 4));
+    /// assert_eq!(5, 0xFFFFF_u32.count_digits_radix_min(16, 4));
+    /// ```
+    fn count_digits_radix_min(self, radix: Self::Radix, min_width: usize) -> usize {
+        self.count_digits_radix(radix).max(min_width)
+    }
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in the absolute difference between two integers.
+    ///
+    /// The absolute difference is never negative, so this avoids the sign handling that
+    /// comes with counting the digits of a signed difference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 42_u32.count_digits_of_abs_diff(42));
+    /// assert_eq!(3, 1000_u32.count_digits_of_abs_diff(1));
+    /// assert_eq!(3, (-50_i8).count_digits_of_abs_diff(50));
+    /// assert_eq!(3, i8::MIN.count_digits_of_abs_diff(i8::MAX));
+    /// ```
+    fn count_digits_of_abs_diff(self, other: Self) -> usize
+    where
+        Self::Radix: From<u8>,
+    {
+        self.count_digits_of_abs_diff_radix(other, 10.into())
+    }
+
+    /// Returns the count of digits in the absolute difference between two integers
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 42_u32.count_digits_of_abs_diff_radix(42, 16));
+    /// assert_eq!(2, 0_u32.count_digits_of_abs_diff_radix(0xFF, 16));
+    /// assert_eq!(8, i8::MIN.count_digits_of_abs_diff_radix(i8::MAX, 2));
+    /// ```
+    fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize;
+
+    /// Returns the count of digits in the magnitude of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// <div class="warning" style="text-align: left;">
+    /// Negative integers can be counted in three ways in radix values other than 10:
+    ///
+    /// - [count_digits_radix()](CountDigits::count_digits_radix) counts the digits of the
+    ///   <a href="https://en.wikipedia.org/wiki/Two%27s_complement">twos-complement</a> representation.
+    /// - This function counts the digits of the magnitude, so the count never depends on the sign.
+    /// - A <a href="https://en.wikipedia.org/wiki/Signed_number_representations#Sign%E2%80%93magnitude">sign-magnitude</a>
+    ///   representation needs one more position for the sign than this function counts.
+    ///
+    /// For radix 10, this is equal to [count_digits_radix()](CountDigits::count_digits_radix).
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(2, (-255_i16).count_digits_radix_abs(16));
+    /// assert_eq!(4, (-255_i16).count_digits_radix(16));
+    ///
+    /// assert_eq!(3, (-255_i16).count_digits_radix_abs(10));
+    /// assert_eq!(2, 255_u16.count_digits_radix_abs(16));
+    /// ```
+    fn count_digits_radix_abs(self, radix: Self::Radix) -> usize;
+
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// where negative integers are represented in twos complement with the given bit width
+    /// instead of with the width of the type.
+    ///
+    /// This models values of a narrower register stored in a wider type, such as a 12-bit register
+    /// stored in an [i16]. For radix 10, negative integers count the digits of their magnitude,
+    /// as with [count_digits_radix()](CountDigits::count_digits_radix).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1, if the bit width is 0 or greater
+    /// than the width of the type, or if the integer does not fit in the bit width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(12, (-1_i16).count_digits_radix_width(2, 12));
+    /// assert_eq!(04, (-1_i16).count_digits_radix_width(8, 12));
+    /// assert_eq!(03, (-1_i16).count_digits_radix_width(16, 12));
+    /// assert_eq!(04, (-2048_i16).count_digits_radix_width(10, 12));
+    /// assert_eq!(11, 2047_i16.count_digits_radix_width(2, 12));
+    /// ```
+    fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize;
+
+    /// Returns the count of digits in an integer as interpreted with the given power-of-two
+    /// [radix](https://en.wikipedia.org/wiki/Radix), where negative integers are represented
+    /// in [ones' complement](https://en.wikipedia.org/wiki/Ones%27_complement) instead of twos complement.
+    ///
+    /// The ones'-complement bit pattern of a negative integer is the bitwise complement of its magnitude.
+    /// Every negative integer has its sign bit set in both representations, so this count is equal to
+    /// [count_digits_radix()](CountDigits::count_digits_radix) for every integer except the minimum value
+    /// of a signed type, which has no ones'-complement representation: the complement of its magnitude is
+    /// the bit pattern of the maximum value, so it counts one fewer bit. Unsigned integers are counted as usual.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1, or is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(8, (-1_i8).count_digits_radix_ones_complement(2));
+    /// assert_eq!(2, (-1_i8).count_digits_radix_ones_complement(16));
+    ///
+    /// assert_eq!(7, i8::MIN.count_digits_radix_ones_complement(2));
+    /// assert_eq!(8, i8::MIN.count_digits_radix(2));
+    ///
+    /// assert_eq!(3, 0o777_u16.count_digits_radix_ones_complement(8));
+    /// ```
+    fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize;
+
+    /// Returns the count of separators inserted when the decimal digits of an integer are
+    /// grouped with the given pattern of group sizes.
+    ///
+    /// The pattern lists group sizes starting from the least-significant digit, and its last
+    /// size repeats for the rest of the digits. An empty pattern inserts no separators.
+    /// The negative sign is not a digit, so it is never separated.
+    ///
+    /// [Panics](panic) if a group size that is reached is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// // 10,000,000
+    /// assert_eq!(2, 10_000_000_u32.count_separators_pattern(&[3]));
+    ///
+    /// // 1,00,00,000
+    /// assert_eq!(3, 10_000_000_u32.count_separators_pattern(&[3, 2]));
+    ///
+    /// assert_eq!(0, 999_u32.count_separators_pattern(&[3]));
+    /// assert_eq!(1, (-1000_i32).count_separators_pattern(&[3]));
+    /// assert_eq!(0, 1000_u32.count_separators_pattern(&[]));
+    /// ```
+    fn count_separators_pattern(self, pattern: &[u32]) -> usize {
+        let last = match pattern.last() {
+            Some(&last) => last,
+            None => return 0,
+        };
+        let mut remaining = self.count_digits();
+        let mut separators = 0;
+        for &size in pattern.iter().chain(core::iter::repeat(&last)) {
+            assert!(size > 0, "group sizes must be at least 1");
+            if remaining <= size as usize {
+                break;
+            }
+            remaining -= size as usize;
+            separators += 1;
+        }
+        separators
+    }
+
+    /// Returns the width of an integer formatted in decimal with a separator between each group
+    /// of `group_size` digits, including the negative sign of a negative integer.
+    ///
+    /// This is [count_digits()](CountDigits::count_digits), plus the
+    /// [count of separators](CountDigits::count_separators_pattern) for the pattern `&[group_size]`,
+    /// plus one for a negative sign, which is the width needed to right-align a column of grouped decimal integers.
+    ///
+    /// [Panics](panic) if the group size is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// // -1,234,567
+    /// assert_eq!(10, (-1_234_567_i32).grouped_width_with_sign(3));
+    ///
+    /// // 1,234,567
+    /// assert_eq!(9, 1_234_567_i32.grouped_width_with_sign(3));
+    ///
+    /// // -999
+    /// assert_eq!(4, (-999_i32).grouped_width_with_sign(3));
+    /// ```
+    fn grouped_width_with_sign(self, group_size: u32) -> usize;
+
+    /// Returns the count of characters in the [Debug](core::fmt::Debug) output of an integer.
+    ///
+    /// For the integer types, this is the count of decimal digits plus one for the negative sign of a
+    /// negative integer, since their [Debug](core::fmt::Debug) output is the same as their
+    /// [Display](core::fmt::Display) output. The wrappers include the width of their own formatting,
+    /// such as `Some(...)` and `None` for [Option], and `Reverse(...)` for [Reverse].
+    /// Implementations derived with `#[derive(CountDigits)]` return the width of their field.
+    ///
+    /// The [alternate](core::fmt::Formatter::alternate) and hexadecimal debug flags are not accounted for.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    /// use core::cmp::Reverse;
+    /// use core::num::NonZeroU32;
+    ///
+    /// assert_eq!(format!("{:?}", 1337_u32).len(), 1337_u32.debug_width());
+    /// assert_eq!(format!("{:?}", -1337_i32).len(), (-1337_i32).debug_width());
+    ///
+    /// let five = NonZeroU32::new(5);
+    /// assert_eq!(format!("{:?}", five.unwrap()).len(), five.unwrap().debug_width());
+    /// assert_eq!(format!("{:?}", five).len(), five.debug_width());
+    /// assert_eq!(format!("{:?}", None::<u32>).len(), None::<u32>.debug_width());
+    /// assert_eq!(format!("{:?}", Reverse(-7_i8)).len(), Reverse(-7_i8).debug_width());
+    /// ```
+    fn debug_width(self) -> usize;
+
+    #[inline(always)]
+    /// Compares two integers by their count of decimal digits, then by value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// let mut numbers = [100, -5, 42, 7, -42];
+    /// numbers.sort_by(|a, b| a.cmp_by_digit_width(b));
+    ///
+    /// assert_eq!(numbers, [-5, 7, -42, 42, 100]);
+    /// ```
+    fn cmp_by_digit_width(self, other: Self) -> Ordering
+    where
+        Self: Ord,
+    {
+        self.count_digits()
+            .cmp(&other.count_digits())
+            .then_with(|| self.cmp(&other))
+    }
+
+    #[inline(always)]
+    /// Compares two integers by their count of digits as interpreted with the given
+    /// [radix](https://en.wikipedia.org/wiki/Radix), then by value.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// let mut numbers = [0x100_u32, 0xF, 0x10, 0x2];
+    /// numbers.sort_by(|a, b| a.cmp_by_digit_width_radix(b, 16));
+    ///
+    /// assert_eq!(numbers, [0x2, 0xF, 0x10, 0x100]);
+    /// ```
+    fn cmp_by_digit_width_radix(self, other: Self, radix: Self::Radix) -> Ordering
+    where
+        Self: Ord,
+        Self::Radix: Copy,
+    {
+        self.count_digits_radix(radix)
+            .cmp(&other.count_digits_radix(radix))
+            .then_with(|| self.cmp(&other))
+    }
+
+    #[inline(always)]
+    /// Returns the zero-based digit-length class of an integer as interpreted with the given
+    /// [radix](https://en.wikipedia.org/wiki/Radix), which is one less than its count of digits.
+    ///
+    /// This is convenient for indexing into tables that have one entry per count of digits.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(0, 0_u32.digit_class_radix(10));
+    /// assert_eq!(0, 9_u32.digit_class_radix(10));
+    /// assert_eq!(1, 10_u32.digit_class_radix(10));
+    /// assert_eq!(1, 99_u32.digit_class_radix(10));
+    /// assert_eq!(2, 0x100_u32.digit_class_radix(16));
+    /// ```
+    fn digit_class_radix(self, radix: Self::Radix) -> usize {
+        self.count_digits_radix(radix).saturating_sub(1)
+    }
+
+    /// Returns whether an integer is the smallest value with its count of digits
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// This is true when the integer is equal to `radix^(count - 1)`, such as 1, 10, 100, and so on
+    /// in base 10, and it is also true for zero. Negative integers are never at a floor.
+    ///
+    /// Together with [is_radix_boundary()](CountDigits::is_radix_boundary), this brackets each class
+    /// of integers with the same count of digits.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert!(0_u32.is_radix_width_floor(10));
+    /// assert!(1_u32.is_radix_width_floor(10));
+    /// assert!(1000_u32.is_radix_width_floor(10));
+    /// assert!(!1001_u32.is_radix_width_floor(10));
+    ///
+    /// assert!(0x100_u32.is_radix_width_floor(16));
+    /// assert!(!(-10_i32).is_radix_width_floor(10));
+    /// ```
+    fn is_radix_width_floor(self, radix: Self::Radix) -> bool;
+
+    #[inline(always)]
+    /// Returns the count of bits in an integer, which is always [Some].
+    ///
+    /// This cannot fail; it exists so that generic code can call a `checked_` variant of every method.
+    /// See [count_bits()](CountDigits::count_bits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(Some(8), (-1_i8).checked_count_bits());
+    /// ```
+    fn checked_count_bits(self) -> Option<u32> {
+        Some(self.count_bits())
+    }
+
+    #[inline(always)]
+    /// Returns the count of octal digits in an integer, which is always [Some].
+    ///
+    /// This cannot fail; it exists so that generic code can call a `checked_` variant of every method.
+    /// See [count_octal_digits()](CountDigits::count_octal_digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(Some(3), 0o777_u32.checked_count_octal_digits());
+    /// ```
+    fn checked_count_octal_digits(self) -> Option<u32> {
+        Some(self.count_octal_digits())
+    }
+
+    #[inline(always)]
+    /// Returns the count of hexadecimal digits in an integer, which is always [Some].
+    ///
+    /// This cannot fail; it exists so that generic code can call a `checked_` variant of every method.
+    /// See [count_hex_digits()](CountDigits::count_hex_digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(Some(4), 0xF00D_u32.checked_count_hex_digits());
+    /// ```
+    fn checked_count_hex_digits(self) -> Option<u32> {
+        Some(self.count_hex_digits())
+    }
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in an integer, which is always [Some].
+    ///
+    /// This cannot fail; it exists so that generic code can call a `checked_` variant of every method.
+    /// See [count_digits()](CountDigits::count_digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(Some(4), (-1337_i32).checked_count_digits());
+    /// ```
+    fn checked_count_digits(self) -> Option<usize> {
+        Some(self.count_digits())
+    }
+
+    #[inline(always)]
+    /// Returns the count of base-36 digits in an integer.
+    ///
+    /// Base 36 uses the digits `0-9` and `a-z`, which makes it the largest radix whose digits
+    /// are each a single alphanumeric character.
+    ///
+    /// This is equal to [count_digits_radix(36)](CountDigits::count_digits_radix), so negative, signed
+    /// integers are counted according to their twos-complement representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 35_u32.count_base36_digits());
+    /// assert_eq!(2, 36_u32.count_base36_digits());
+    /// assert_eq!(4, 1_679_615_u32.count_base36_digits());
+    /// assert_eq!(2, (-1_i8).count_base36_digits());
+    /// ```
+    fn count_base36_digits(self) -> usize
+    where
+        Self::Radix: From<u8>,
+    {
+        self.count_digits_radix(36.into())
+    }
+
+    #[inline(always)]
+    /// Returns the count of base-85 digits in an integer.
+    ///
+    /// Base 85 is used by binary-to-text encodings such as [Ascii85](https://en.wikipedia.org/wiki/Ascii85),
+    /// which appears in PostScript and PDF, and [Z85](https://rfc.zeromq.org/spec/32/), which is used by ZeroMQ.
+    ///
+    /// This is equal to [count_digits_radix(85)](CountDigits::count_digits_radix), so negative, signed
+    /// integers are counted according to their twos-complement representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 84_u32.count_base85_digits());
+    /// assert_eq!(2, 85_u32.count_base85_digits());
+    /// assert_eq!(5, u32::MAX.count_base85_digits());
+    /// assert_eq!(2, (-1_i8).count_base85_digits());
+    /// ```
+    fn count_base85_digits(self) -> usize
+    where
+        Self::Radix: From<u8>,
+    {
+        self.count_digits_radix(85.into())
+    }
+
+    /// Returns the count of digit positions that differ between two integers as interpreted
+    /// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// The digits are aligned at the least-significant end, and the missing leading positions of
+    /// the shorter integer are treated as zeros. Integers are represented as in
+    /// [count_digits_radix()](CountDigits::count_digits_radix), so the sign of a negative integer
+    /// is ignored in radix 10, and its twos-complement representation is compared in every other radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(0, 123_u32.digit_diff_count_radix(123, 10));
+    /// assert_eq!(1, 123_u32.digit_diff_count_radix(923, 10));
+    /// assert_eq!(1, 123_u32.digit_diff_count_radix(1123, 10));
+    /// assert_eq!(3, 123_u32.digit_diff_count_radix(231, 10));
+    /// assert_eq!(2, 0b1010_u8.digit_diff_count_radix(0b0110, 2));
+    /// ```
+    fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize;
+
+    /// Returns the count of leading digits that two integers share as interpreted with the given
+    /// [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// The digits are aligned at the most-significant end, so integers with different counts of digits
+    /// share no prefix. Integers are represented as in [count_digits_radix()](CountDigits::count_digits_radix),
+    /// so the sign of a negative integer is ignored in radix 10, and its twos-complement representation
+    /// is compared in every other radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(3, 12345_u32.common_prefix_len_radix(12399, 10));
+    /// assert_eq!(5, 12345_u32.common_prefix_len_radix(12345, 10));
+    /// assert_eq!(0, 12345_u32.common_prefix_len_radix(22345, 10));
+    /// assert_eq!(0, 12345_u32.common_prefix_len_radix(1234, 10));
+    /// assert_eq!(2, 0b1101_u8.common_prefix_len_radix(0b1110, 2));
+    /// ```
+    fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize;
+
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// excluding its trailing zeros.
+    ///
+    /// This is the width of the integer with its trailing zeros stripped, so 1200 has the same
+    /// count of significant digits as 12. Zero is written as a single `0` digit, which is kept,
+    /// so zero has one significant digit.
+    ///
+    /// Integers are represented as in [count_digits_radix()](CountDigits::count_digits_radix), so the sign
+    /// of a negative integer is ignored in radix 10, and its twos-complement representation is counted in every other radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(2, 1200_u32.count_significant_digits_radix(10));
+    /// assert_eq!(1, 1000_u32.count_significant_digits_radix(10));
+    /// assert_eq!(4, 1234_u32.count_significant_digits_radix(10));
+    /// assert_eq!(1, 0_u32.count_significant_digits_radix(10));
+    /// assert_eq!(3, 0b1010_0000_u32.count_significant_digits_radix(2));
+    /// assert_eq!(2, (-1200_i32).count_significant_digits_radix(10));
+    /// ```
+    fn count_significant_digits_radix(self, radix: Self::Radix) -> usize;
+
+    #[inline(always)]
+    /// Returns the count of digits in the integer whose digits are the reverse of this integer's digits
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// The trailing zeros of an integer become leading zeros of its reverse, which are not counted,
+    /// so this is the same count as [count_significant_digits_radix()](CountDigits::count_significant_digits_radix).
+    /// The reverse is never constructed, so it cannot overflow, even when it would not fit in the integer's type.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(1, 100_u32.count_digits_of_reverse_radix(10));
+    /// assert_eq!(2, 120_u32.count_digits_of_reverse_radix(10));
+    /// assert_eq!(3, 121_u32.count_digits_of_reverse_radix(10));
+    /// assert_eq!(1, 0_u32.count_digits_of_reverse_radix(10));
+    ///
+    /// // The reverse of 4294967295 does not fit in a u32.
+    /// assert_eq!(10, u32::MAX.count_digits_of_reverse_radix(10));
+    /// ```
+    fn count_digits_of_reverse_radix(self, radix: Self::Radix) -> usize {
+        self.count_significant_digits_radix(radix)
+    }
+
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+    /// after rounding its magnitude to the nearest power of the radix, such as for the ticks of a log-scale axis.
+    ///
+    /// The rounding is decided at the geometric midpoint between consecutive powers, `radix^k` and `radix^(k+1)`,
+    /// which is `radix^k * sqrt(radix)`. Magnitudes at or above the midpoint round up to `radix^(k+1)`, which has one more
+    /// digit, and magnitudes below it round down to `radix^k`. The midpoint is only an integer when the radix is a perfect square.
+    /// Zero rounds to `radix^0`, which has one digit.
+    ///
+    /// Integers are represented as in [count_digits_radix()](CountDigits::count_digits_radix), so the sign
+    /// of a negative integer is ignored in radix 10, and its twos-complement representation is counted in every other radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// // The geometric midpoint between 1000 and 10000 is about 3162.3.
+    /// assert_eq!(4, 1234_u32.count_digits_radix_rounded_to_power(10));
+    /// assert_eq!(4, 3162_u32.count_digits_radix_rounded_to_power(10));
+    /// assert_eq!(5, 3163_u32.count_digits_radix_rounded_to_power(10));
+    /// assert_eq!(5, 7600_u32.count_digits_radix_rounded_to_power(10));
+    /// assert_eq!(5, (-7600_i32).count_digits_radix_rounded_to_power(10));
+    ///
+    /// // The geometric midpoint between 4 and 16 is 8, which rounds up.
+    /// assert_eq!(2, 7_u32.count_digits_radix_rounded_to_power(4));
+    /// assert_eq!(3, 8_u32.count_digits_radix_rounded_to_power(4));
+    ///
+    /// assert_eq!(1, 0_u32.count_digits_radix_rounded_to_power(10));
+    /// ```
+    fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize;
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in an integer, excluding its trailing zeros.
+    ///
+    /// Zero has one significant digit. See [count_significant_digits_radix()](CountDigits::count_significant_digits_radix).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(2, 1200_u32.count_significant_digits());
+    /// assert_eq!(3, (-1010_i32).count_significant_digits());
+    /// assert_eq!(1, 0_u32.count_significant_digits());
+    /// ```
+    fn count_significant_digits(self) -> usize
+    where
+        Self::Radix: From<u8>,
+    {
+        self.count_significant_digits_radix(10.into())
+    }
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in the twos-complement representation of an integer.
+    ///
+    /// Radix 10 is the only radix in which [count_digits_radix()](CountDigits::count_digits_radix) counts
+    /// the magnitude of a negative integer. This counts the bit pattern of a negative integer instead,
+    /// reinterpreted as the corresponding unsigned type, as every other radix does. Non-negative integers
+    /// have the same count as [count_digits()](CountDigits::count_digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(3, (-1_i8).count_decimal_digits_twos_complement());
+    /// assert_eq!(1, (-1_i8).count_digits());
+    /// assert_eq!(5, (-1_i16).count_decimal_digits_twos_complement());
+    /// assert_eq!(3, 127_i8.count_decimal_digits_twos_complement());
+    /// ```
+    fn count_decimal_digits_twos_complement(self) -> usize
+    where
+        Self::Radix: CountDigits,
+    {
+        self.bit_pattern_as_unsigned().count_digits()
+    }
+
+    /// Returns the count of decimal digits in the sum of two integers, even if the sum overflows the type.
+    ///
+    /// The sum is computed with wider arithmetic, so this is the count of digits that the
+    /// mathematical sum would have. As with [count_digits()](CountDigits::count_digits),
+    /// the negative sign of a negative sum is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// assert_eq!(3, 40_u8.count_digits_of_sum(60));
+    /// assert_eq!(3, 200_u8.count_digits_of_sum(200));
+    /// assert_eq!(10, u32::MAX.count_digits_of_sum(u32::MAX));
+    /// assert_eq!(3, i8::MIN.count_digits_of_sum(i8::MIN));
+    /// assert_eq!(1, (-5_i32).count_digits_of_sum(5));
+    /// ```
+    fn count_digits_of_sum(self, other: Self) -> usize;
+
+    /// Returns the count of rotations of the digits of an integer, as interpreted with the given
+    /// [radix](https://en.wikipedia.org/wiki/Radix), that are palindromes.
+    ///
+    /// An integer with `k` digits has `k` rotations, including itself, and each rotation keeps all
+    /// `k` digits, including any leading zeros. A single-digit integer has one rotation, which is a palindrome.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// <div class="warning" style="text-align: left;">
+    /// For radix 10, negative, signed integers rotate the digits of their magnitude.
+    ///
+    /// For all other radix values, rotates digits according to the
+    /// <a href="https://en.wikipedia.org/wiki/Two%27s_complement">twos-complement</a> representation.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigits;
+    ///
+    /// // 121, 211, 112
+    /// assert_eq!(1, 121_u32.palindromic_rotation_count_radix(10));
+    /// // 1212, 2121, 1212, 2121
+    /// assert_eq!(0, 1212_u32.palindromic_rotation_count_radix(10));
+    /// // 1221, 2211, 2112, 1122
+    /// assert_eq!(2, 1221_u32.palindromic_rotation_count_radix(10));
+    /// assert_eq!(1, 7_u32.palindromic_rotation_count_radix(10));
+    /// assert_eq!(8, (-1_i8).palindromic_rotation_count_radix(2));
+    /// ```
+    fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32;
+}
+
+/// The reason that a [radix](https://en.wikipedia.org/wiki/Radix) is invalid.
+///
+/// Returned by [try_count_digits_radix_u32()](CountDigits::try_count_digits_radix_u32).
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::{CountDigits, RadixError};
+///
+/// assert_eq!(Err(RadixError::TooLargeForType), 1_u8.try_count_digits_radix_u32(300));
+/// assert_eq!("radix is too large for the type", RadixError::TooLargeForType.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RadixError {
+    /// The radix is 0.
+    Zero,
+    /// The radix is 1.
+    One,
+    /// The radix is greater than the maximum value of the [Radix](CountDigits::Radix) type.
+    TooLargeForType,
+}
+
+impl fmt::Display for RadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RadixError::Zero => "radix must not be 0",
+            RadixError::One => "radix must not be 1",
+            RadixError::TooLargeForType => "radix is too large for the type",
+        })
+    }
+}
+
+/// Whether the value zero is counted as a single digit or as having no digits.
+///
+/// Used by [count_digits_with_zero_policy()](CountDigits::count_digits_with_zero_policy)
+/// and [count_digits_radix_with_zero_policy()](CountDigits::count_digits_radix_with_zero_policy).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ZeroPolicy {
+    /// Zero has a single digit, `0`, as with [count_digits()](CountDigits::count_digits).
+    #[default]
+    OneDigit,
+    /// Zero has no digits, as with [count_digits_nonempty()](CountDigits::count_digits_nonempty).
+    ZeroDigits,
+}
+
+/// The number bases that have their own functions in [CountDigits].
+///
+/// Used by [count_digits_base()](CountDigits::count_digits_base).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CommonBase {
+    /// Base 2, as with [count_bits()](CountDigits::count_bits).
+    Binary,
+    /// Base 8, as with [count_octal_digits()](CountDigits::count_octal_digits).
+    Octal,
+    /// Base 10, as with [count_digits()](CountDigits::count_digits).
+    Decimal,
+    /// Base 16, as with [count_hex_digits()](CountDigits::count_hex_digits).
+    Hexadecimal,
+}
+
+/// Returns the count of balanced-ternary trits needed to represent the given magnitude.
+///
+/// Each step removes the least-significant trit, which rounds the magnitude to the
+/// nearest multiple of three: `(m + 1) / 3`, written so that it cannot overflow.
+const fn count_balanced_ternary_digits_of_magnitude(mut magnitude: u128) -> u32 {
+    let mut count = 1;
+    magnitude = magnitude / 3 + (magnitude % 3 + 1) / 3;
+    while magnitude != 0 {
+        count += 1;
+        magnitude = magnitude / 3 + (magnitude % 3 + 1) / 3;
+    }
+    count
+}
+
+/// Returns the count of octal digits in an integer whose base-2 logarithm is `ilog2`.
+///
+/// This is `1 + ilog2 / 3`, with the division replaced by a multiply-shift that is
+/// exact for every `ilog2` of a primitive integer (`0..=127`).
+#[inline(always)]
+const fn octal_digits_of_ilog2(ilog2: u32) -> u32 {
+    1 + ((ilog2 * 43) >> 7)
+}
+
+/// The count of digits in every integer is at least one.
+const ONE_DIGIT: NonZeroU32 = match NonZeroU32::new(1) {
+    Some(one) => one,
+    None => unreachable!(),
+};
+
+/// The integer logarithm functions, which were stabilized in Rust 1.67.
+///
+/// Every integer logarithm in this crate is computed through the traits of this module, called by path,
+/// such as `IntegerLogarithm::checked_ilog2(n)`, so that the call never resolves to an inherent function.
+/// The traits call the functions of the standard library, or the `fallback` implementations when the build
+/// script sets `count_digits_no_ilog` because the compiler does not provide those functions. This is the only
+/// module that calls functions that are newer than the minimum supported Rust version.
+#[allow(clippy::incompatible_msrv)]
+mod ilog {
+    use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+    pub(crate) trait IntegerLogarithm: Copy {
+        fn checked_ilog(self, base: Self) -> Option<u32>;
+        fn checked_ilog2(self) -> Option<u32>;
+        fn checked_ilog10(self) -> Option<u32>;
+        fn ilog(self, base: Self) -> u32;
+        fn ilog2(self) -> u32;
+        fn ilog10(self) -> u32;
+    }
+
+    pub(crate) trait NonZeroIntegerLogarithm: Copy {
+        fn ilog2(self) -> u32;
+        fn ilog10(self) -> u32;
+    }
+
+    /// Implements the traits by calling the functions of the standard library.
+    #[cfg(not(count_digits_no_ilog))]
+    macro_rules! impl_integer_logarithm {
+        ($($type:ty => $trait:ident { $($function:ident($($arg:ident: $arg_type:ty),*) -> $output:ty;)* })*) => {
+            $(impl $trait for $type {
+                $(
+                    #[inline(always)]
+                    fn $function(self, $($arg: $arg_type),*) -> $output {
+                        <$type>::$function(self, $($arg),*)
+                    }
+                )*
+            })*
+        };
+    }
+
+    /// Implements the traits by calling the fallback implementations.
+    #[cfg(count_digits_no_ilog)]
+    macro_rules! impl_integer_logarithm {
+        ($($type:ty => $trait:ident { $($function:ident($($arg:ident: $arg_type:ty),*) -> $output:ty;)* })*) => {
+            $(impl $trait for $type {
+                $(
+                    #[inline(always)]
+                    fn $function(self, $($arg: $arg_type),*) -> $output {
+                        fallback::$trait::$function(self, $($arg),*)
+                    }
+                )*
+            })*
+        };
+    }
+
+    macro_rules! impl_integer_logarithms {
+        ($($primitive_type:ty),* ; $($non_zero_type:ty),* $(;)?) => {
+            impl_integer_logarithm! {
+                $($primitive_type => IntegerLogarithm {
+                    checked_ilog(base: Self) -> Option<u32>;
+                    checked_ilog2() -> Option<u32>;
+                    checked_ilog10() -> Option<u32>;
+                    ilog(base: Self) -> u32;
+                    ilog2() -> u32;
+                    ilog10() -> u32;
+                })*
+                $($non_zero_type => NonZeroIntegerLogarithm {
+                    ilog2() -> u32;
+                    ilog10() -> u32;
+                })*
+            }
+        };
+    }
+
+    impl_integer_logarithms!(
+        u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+        NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize;
+    );
+
+    /// Fallback implementations of the integer logarithm functions, for compilers that do not provide them.
+    #[cfg(any(count_digits_no_ilog, test))]
+    pub(crate) mod fallback {
+        use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+        pub(crate) trait IntegerLogarithm: Copy {
+            fn checked_ilog(self, base: Self) -> Option<u32>;
+            fn checked_ilog2(self) -> Option<u32>;
+            fn checked_ilog10(self) -> Option<u32>;
+
+            fn ilog(self, base: Self) -> u32 {
+                self.checked_ilog(base)
+                    .expect("argument of integer logarithm must be positive")
+            }
+
+            fn ilog2(self) -> u32 {
+                self.checked_ilog2()
+                    .expect("argument of integer logarithm must be positive")
+            }
+
+            fn ilog10(self) -> u32 {
+                self.checked_ilog10()
+                    .expect("argument of integer logarithm must be positive")
+            }
+        }
+
+        pub(crate) trait NonZeroIntegerLogarithm: Copy {
+            fn ilog2(self) -> u32;
+            fn ilog10(self) -> u32;
+        }
+
+        macro_rules! impl_integer_logarithm {
+            (
+                primitive_type = $primitive_type:ty,
+                non_zero_type = $non_zero_type:ty $(,)?
+            ) => {
+                impl IntegerLogarithm for $primitive_type {
+                    fn checked_ilog(self, base: Self) -> Option<u32> {
+                        if self == 0 || base < 2 {
+                            return None;
+                        }
+                        let mut n = self;
+                        let mut log = 0;
+                        while n >= base {
+                            n /= base;
+                            log += 1;
+                        }
+                        Some(log)
+                    }
+
+                    fn checked_ilog2(self) -> Option<u32> {
+                        match self {
+                            0 => None,
+                            n => Some(<$primitive_type>::BITS - 1 - n.leading_zeros()),
+                        }
+                    }
+
+                    fn checked_ilog10(self) -> Option<u32> {
+                        IntegerLogarithm::checked_ilog(self, 10)
+                    }
+                }
+
+                impl NonZeroIntegerLogarithm for $non_zero_type {
+                    fn ilog2(self) -> u32 {
+                        IntegerLogarithm::ilog2(self.get())
+                    }
+
+                    fn ilog10(self) -> u32 {
+                        IntegerLogarithm::ilog10(self.get())
+                    }
+                }
+            };
+            (
+                primitive_type = $primitive_type:ty,
+                unsigned_type = $unsigned_type:ty $(,)?
+            ) => {
+                impl IntegerLogarithm for $primitive_type {
+                    fn checked_ilog(self, base: Self) -> Option<u32> {
+                        if self <= 0 || base < 2 {
+                            return None;
+                        }
+                        IntegerLogarithm::checked_ilog(
+                            self as $unsigned_type,
+                            base as $unsigned_type,
+                        )
+                    }
+
+                    fn checked_ilog2(self) -> Option<u32> {
+                        if self <= 0 {
+                            return None;
+                        }
+                        IntegerLogarithm::checked_ilog2(self as $unsigned_type)
+                    }
+
+                    fn checked_ilog10(self) -> Option<u32> {
+                        IntegerLogarithm::checked_ilog(self, 10)
+                    }
+                }
+            };
+        }
+
+        impl_integer_logarithm!(primitive_type = u8, non_zero_type = NonZeroU8);
+        impl_integer_logarithm!(primitive_type = u16, non_zero_type = NonZeroU16);
+        impl_integer_logarithm!(primitive_type = u32, non_zero_type = NonZeroU32);
+        impl_integer_logarithm!(primitive_type = u64, non_zero_type = NonZeroU64);
+        impl_integer_logarithm!(primitive_type = u128, non_zero_type = NonZeroU128);
+        impl_integer_logarithm!(primitive_type = usize, non_zero_type = NonZeroUsize);
+
+        impl_integer_logarithm!(primitive_type = i8, unsigned_type = u8);
+        impl_integer_logarithm!(primitive_type = i16, unsigned_type = u16);
+        impl_integer_logarithm!(primitive_type = i32, unsigned_type = u32);
+        impl_integer_logarithm!(primitive_type = i64, unsigned_type = u64);
+        impl_integer_logarithm!(primitive_type = i128, unsigned_type = u128);
+        impl_integer_logarithm!(primitive_type = isize, unsigned_type = usize);
+    }
+}
+
+/// An unsigned integer type that holds the magnitudes of integers, so that the helpers that inspect
+/// the digits of a magnitude are shared by the primitive integers and the 256-bit integers.
+trait Magnitude:
+    CountDigits<Radix = Self> + Ord + From<u8> + Div<Output = Self> + Rem<Output = Self> + DivAssign
+{
+    /// The magnitude zero.
+    const ZERO: Self;
+
+    /// The count of decimal digits in every sum of two magnitudes that overflows the type.
+    const OVERFLOWING_SUM_DIGITS: usize;
+
+    /// Returns the magnitude raised to the given power.
+    fn pow(self, exp: u32) -> Self;
+
+    /// Returns the sum of two magnitudes, or [None] if it overflows.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns the high and low halves of the double-width product of two magnitudes.
+    fn widening_mul(self, other: Self) -> (Self, Self);
+
+    /// Returns the magnitude as a [u128], or [u128::MAX] if it does not fit.
+    fn narrow(self) -> u128;
+}
+
+impl Magnitude for u128 {
+    const ZERO: Self = 0;
+
+    /// Such a sum is at least `2^128` and less than `2^129`, which are both 39-digit numbers.
+    const OVERFLOWING_SUM_DIGITS: usize = 39;
+
+    #[inline(always)]
+    fn pow(self, exp: u32) -> Self {
+        u128::pow(self, exp)
+    }
+
+    #[inline(always)]
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u128::checked_add(self, other)
+    }
+
+    fn widening_mul(self, other: Self) -> (Self, Self) {
+        const LOW: u128 = u64::MAX as u128;
+        let (a_high, a_low) = (self >> 64, self & LOW);
+        let (b_high, b_low) = (other >> 64, other & LOW);
+        let low_low = a_low * b_low;
+        let low_high = a_low * b_high;
+        let high_low = a_high * b_low;
+        let middle = (low_low >> 64) + (low_high & LOW) + (high_low & LOW);
+        let high = a_high * b_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+        (high, (low_low & LOW) | (middle << 64))
+    }
+
+    #[inline(always)]
+    fn narrow(self) -> u128 {
+        self
+    }
+}
+
+/// The count of bits in the widest [Magnitude], which is the largest count of digits in any magnitude.
+#[cfg(all(feature = "alloc", not(feature = "ethnum")))]
+const MAX_MAGNITUDE_BITS: usize = u128::BITS as usize;
+
+/// The count of bits in the widest [Magnitude], which is the largest count of digits in any magnitude.
+#[cfg(all(feature = "alloc", feature = "ethnum"))]
+const MAX_MAGNITUDE_BITS: usize = ethnum::U256::BITS as usize;
+
+/// Writes the ASCII digits of a magnitude with the given radix to the start of the buffer,
+/// preceded by a negative sign if requested.
+///
+/// Returns the count of bytes written, or the count of bytes required if the buffer is too short,
+/// in which case the buffer is left unchanged.
+///
+/// [Panics](panic) if the provided radix is 0, 1, or greater than 36.
+fn write_ascii_digits_of_magnitude<M: Magnitude>(
+    negative: bool,
+    magnitude: M,
+    radix: M,
+    uppercase: bool,
+    buf: &mut [u8],
+) -> Result<usize, usize> {
+    assert!(
+        radix >= M::from(2),
+        "base of integer logarithm must be at least 2"
+    );
+    assert!(
+        radix.narrow() <= 36,
+        "radix must be at most 36 to render digits"
+    );
+
+    let count = magnitude.count_digits_radix(radix);
+    write_ascii_digits(
+        negative,
+        count,
+        magnitude.digits_radix_le(radix),
+        uppercase,
+        buf,
+    )
+}
+
+/// Writes `count` ASCII digits, taken from an iterator over the digits of a magnitude,
+/// to the start of the buffer, preceded by a negative sign if requested.
+///
+/// Returns the count of bytes written, or the count of bytes required if the buffer is too short,
+/// in which case the buffer is left unchanged. The radix of the digits must be at most 36.
+fn write_ascii_digits(
+    negative: bool,
+    count: usize,
+    digits: DigitsRadixLe,
+    uppercase: bool,
+    buf: &mut [u8],
+) -> Result<usize, usize> {
+    let len = negative as usize + count;
+    if buf.len() < len {
+        return Err(len);
+    }
+
+    let radix = digits.radix as u32;
+    for (byte, digit) in buf[negative as usize..len].iter_mut().rev().zip(digits) {
+        let digit = core::char::from_digit(digit as u32, radix).unwrap_or_default();
+        *byte = if uppercase {
+            digit.to_ascii_uppercase()
+        } else {
+            digit
+        } as u8;
+    }
+    if negative {
+        buf[0] = b'-';
+    }
+    Ok(len)
+}
+
+#[cfg(feature = "alloc")]
+/// Renders the digits of a magnitude with the given radix, preceded by a negative sign if requested.
+fn to_string_radix_of_magnitude<M: Magnitude>(
+    negative: bool,
+    magnitude: M,
+    radix: M,
+    uppercase: bool,
+) -> String {
+    let mut digits = [0_u8; 1 + MAX_MAGNITUDE_BITS];
+    let len = write_ascii_digits_of_magnitude(negative, magnitude, radix, uppercase, &mut digits)
+        .expect("every magnitude fits in the buffer");
+
+    let mut string = String::with_capacity(len);
+    string.extend(digits[..len].iter().map(|&digit| digit as char));
+    string
+}
+
+/// Returns whether the given magnitude is equal to `radix^k - 1` for some `k >= 1`,
+/// which is when every one of its digits is the largest digit in the radix.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+const fn is_radix_boundary_of_magnitude(mut magnitude: u128, radix: u128) -> bool {
+    if radix < 2 {
+        panic!("base of integer logarithm must be at least 2");
+    }
+    if magnitude == 0 {
+        return false;
+    }
+    while magnitude != 0 {
+        if magnitude % radix != radix - 1 {
+            return false;
+        }
+        magnitude /= radix;
+    }
+    true
+}
+
+/// Returns the largest value of a single digit in the given radix.
+///
+/// [Panics](panic) if the provided radix is 0 or 1, or greater than 256.
+fn max_digit_value_of_radix(radix: u128) -> u8 {
+    assert!(radix >= 2, "base of integer logarithm must be at least 2");
+    assert!(
+        radix <= 256,
+        "radix must be at most 256 for a digit to fit in u8"
+    );
+    (radix - 1) as u8
+}
+
+/// Returns the count of digits in the given magnitude as interpreted with the given radix,
+/// along with its most-significant digit.
+///
+/// [Panics](panic) if the provided radix is 0 or 1, or greater than 256.
+fn count_and_msd_of_magnitude<M: Magnitude>(magnitude: M, radix: M) -> (usize, u8) {
+    assert!(
+        radix >= M::from(2),
+        "base of integer logarithm must be at least 2"
+    );
+    assert!(
+        radix.narrow() <= 256,
+        "radix must be at most 256 for the leading digit to fit in u8"
+    );
+
+    let count = magnitude.count_digits_radix(radix);
+    let leading_digit = magnitude / radix.pow(count as u32 - 1);
+    (count, leading_digit.narrow() as u8)
+}
+
+/// Returns the count of digits in the given magnitude as interpreted with the given radix,
+/// along with whether the magnitude is an exact power of the radix.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+fn count_and_is_power_of_magnitude<M: Magnitude>(magnitude: M, radix: M) -> (usize, bool) {
+    assert!(
+        radix >= M::from(2),
+        "base of integer logarithm must be at least 2"
+    );
+
+    let count = magnitude.count_digits_radix(radix);
+    (count, magnitude == radix.pow(count as u32 - 1))
+}
+
+/// Returns the twos-complement bit pattern of the given value in the given bit width.
+///
+/// [Panics](panic) if the bit width is 0 or greater than `type_bits`, or if the value
+/// does not fit in the bit width.
+fn bit_pattern_in_width(value: i128, type_bits: u32, bit_width: u32) -> u128 {
+    assert!(
+        0 < bit_width && bit_width <= type_bits,
+        "bit width must be between 1 and the width of the type"
+    );
+    let sign_extension = value >> (bit_width - 1);
+    assert!(
+        sign_extension == 0 || sign_extension == -1,
+        "value does not fit in the given bit width"
+    );
+    (value as u128) & (u128::MAX >> (u128::BITS - bit_width))
+}
+
+/// Checks that the given value fits in the given unsigned bit width.
+///
+/// [Panics](panic) if the bit width is 0 or greater than `type_bits`, or if the value
+/// does not fit in the bit width.
+fn assert_fits_in_width(value: u128, type_bits: u32, bit_width: u32) {
+    assert!(
+        0 < bit_width && bit_width <= type_bits,
+        "bit width must be between 1 and the width of the type"
+    );
+    assert!(
+        value.checked_shr(bit_width).unwrap_or_default() == 0,
+        "value does not fit in the given bit width"
+    );
+}
+
+/// Returns the count of digits in the given bit pattern as interpreted with the given radix.
+///
+/// [Panics](panic) if the provided radix is 0 or 1, or is not a power of two.
+fn count_digits_of_power_of_two_radix(pattern: u128, radix: u128) -> usize {
+    assert!(radix >= 2, "base of integer logarithm must be at least 2");
+    assert!(radix.is_power_of_two(), "radix must be a power of two");
+    pattern.count_digits_radix(radix)
+}
+
+/// The powers of ten that fit in a [u64], indexed by their exponent.
+const U64_POWERS_OF_TEN: [u64; 20] = {
+    let mut powers = [1; 20];
+    let mut i = 1;
+    while i < powers.len() {
+        powers[i] = powers[i - 1] * 10;
+        i += 1;
+    }
+    powers
+};
+
+/// Returns the count of decimal digits in a [u64].
+///
+/// The bit length of the integer bounds its base-10 logarithm to one of two values:
+/// multiplying the bit length by `1233 / 4096`, an approximation of `log10(2)`, gives
+/// the larger one, and a single comparison with a power of ten picks between them.
+#[inline(always)]
+const fn count_decimal_digits_u64(n: u64) -> usize {
+    // Zero has the same count of digits as one.
+    let n = n | 1;
+    let bits = u64::BITS - n.leading_zeros();
+    let guess = ((bits * 1233) >> 12) as usize;
+    1 + guess - (n < U64_POWERS_OF_TEN[guess]) as usize
+}
+
+/// Returns whether the given magnitude is zero or equal to `radix^k` for some `k >= 0`.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+const fn is_radix_width_floor_of_magnitude(mut magnitude: u128, radix: u128) -> bool {
+    if radix < 2 {
+        panic!("base of integer logarithm must be at least 2");
+    }
+    if magnitude == 0 {
+        return true;
+    }
+    while magnitude % radix == 0 {
+        magnitude /= radix;
+    }
+    magnitude == 1
+}
+
+/// Returns the count of digits in the given bit pattern as interpreted with the given radix.
+///
+/// Every implementation routes radix values without a fast path through this one function,
+/// passing the unsigned bit pattern of the integer, which is its twos-complement representation
+/// when the integer is negative.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+fn count_digits_radix_u128(bit_pattern: u128, radix: u128) -> usize {
+    assert!(radix >= 2, "base of integer logarithm must be at least 2");
+    1 + IntegerLogarithm::checked_ilog(bit_pattern, radix).unwrap_or_default() as usize
+}
+
+/// Returns the count of digit positions that differ between two magnitudes as interpreted with the given radix,
+/// treating the missing leading positions of the shorter magnitude as zeros.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+const fn digit_diff_count_of_magnitudes(mut a: u128, mut b: u128, radix: u128) -> usize {
+    if radix < 2 {
+        panic!("base of integer logarithm must be at least 2");
+    }
+    let mut count = 0;
+    while a != 0 || b != 0 {
+        if a % radix != b % radix {
+            count += 1;
+        }
+        a /= radix;
+        b /= radix;
+    }
+    count
+}
+
+/// Returns the count of leading digits that two magnitudes share as interpreted with the given radix,
+/// or 0 if they have different counts of digits.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+fn common_prefix_len_of_magnitudes<M: Magnitude>(mut a: M, mut b: M, radix: M) -> usize {
+    let count = a.count_digits_radix(radix);
+    if b.count_digits_radix(radix) != count {
+        return 0;
+    }
+    // Removing trailing digits until the magnitudes are equal leaves their shared prefix.
+    let mut prefix_len = count;
+    while a != b {
+        a /= radix;
+        b /= radix;
+        prefix_len -= 1;
+    }
+    prefix_len
+}
+
+/// Returns the count of digits in the given magnitude as interpreted with the given radix,
+/// excluding its trailing zeros. Zero has one significant digit.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+fn count_significant_digits_of_magnitude<M: Magnitude>(mut magnitude: M, radix: M) -> usize {
+    assert!(
+        radix >= M::from(2),
+        "base of integer logarithm must be at least 2"
+    );
+    if magnitude == M::ZERO {
+        return 1;
+    }
+    while magnitude % radix == M::ZERO {
+        magnitude /= radix;
+    }
+    magnitude.count_digits_radix(radix)
+}
+
+/// Returns the count of digits in the given magnitude as interpreted with the given radix,
+/// after rounding it to the nearest power of the radix at the geometric midpoint between powers.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+fn count_digits_rounded_to_power_of_magnitude<M: Magnitude>(magnitude: M, radix: M) -> usize {
+    let count = magnitude.count_digits_radix(radix);
+    let power = radix.pow(count as u32 - 1);
+    // The magnitude is at least the midpoint, power * sqrt(radix), if its square is at least
+    // power^2 * radix. The squares are compared as double-width integers, and a product of
+    // power^2 and the radix that does not fit in double width is larger than any square.
+    let square = magnitude.widening_mul(magnitude);
+    let (power_square_high, power_square_low) = power.widening_mul(power);
+    let (carry, low) = power_square_low.widening_mul(radix);
+    let (overflow, high) = power_square_high.widening_mul(radix);
+    let rounds_up = match high.checked_add(carry) {
+        Some(high) if overflow == M::ZERO => square >= (high, low),
+        _ => false,
+    };
+    count + rounds_up as usize
+}
+
+/// Returns the count of decimal digits in the sum of two magnitudes, even if the sum overflows the type.
+fn count_digits_of_magnitude_sum<M: Magnitude>(a: M, b: M) -> usize {
+    match a.checked_add(b) {
+        Some(sum) => sum.count_digits(),
+        None => M::OVERFLOWING_SUM_DIGITS,
+    }
+}
+
+/// Returns the count of rotations of the digits of a magnitude with the given radix that are palindromes.
+///
+/// Each digit is computed from the magnitude as `(magnitude / radix^position) % radix`,
+/// because a rotation of a magnitude can be too large for its type.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+fn palindromic_rotation_count_of_magnitude<M: Magnitude>(magnitude: M, radix: M) -> u32 {
+    let count = magnitude.count_digits_radix(radix);
+    let digit = |position: usize| (magnitude / radix.pow(position as u32)) % radix;
+    (0..count)
+        .filter(|&shift| {
+            (0..count / 2)
+                .all(|i| digit((shift + i) % count) == digit((shift + count - 1 - i) % count))
+        })
+        .count() as u32
+}
+
+macro_rules! impl_count_digits {
+    (
+        primitive_type = $primitive_type:ty,
+        non_zero_type = $non_zero_type:ty,
+        radix_type = $radix_type:ty,
+        min_value_bits = $min_value_bits:expr,
+        min_value_octal_digits = $min_value_octal_digits:expr,
+        min_value_hex_digits = $min_value_hex_digits:expr $(,)?
+    ) => {
+        impl CountDigits for $primitive_type {
+            type Radix = $radix_type;
+
+            #[inline(always)]
+            /// Returns the count of bits in an integer.
+            fn count_bits(self) -> u32 {
+                // Negative integers have no leading zeros, so they count all of the bits.
+                // Setting the lowest bit counts zero as a single bit without a branch.
+                <$primitive_type>::BITS - (self | 1).leading_zeros()
+            }
+
+            #[inline(always)]
+            /// Returns the count of octal digits in an integer.
+            fn count_octal_digits(self) -> u32 {
+                if self.is_negative() {
+                    $min_value_octal_digits
+                } else {
+                    octal_digits_of_ilog2(IntegerLogarithm::checked_ilog2(self).unwrap_or_default())
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of hexadecimal digits in an integer.
+            fn count_hex_digits(self) -> u32 {
+                if self.is_negative() {
+                    $min_value_hex_digits
+                } else {
+                    1 + IntegerLogarithm::checked_ilog2(self).unwrap_or_default() / 4
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer.
+            fn count_digits(self) -> usize {
+                if <$primitive_type>::BITS == u64::BITS {
+                    count_decimal_digits_u64(self.abs_diff(0) as u64)
+                } else {
+                    1 + IntegerLogarithm::checked_ilog10(self.abs_diff(0)).unwrap_or_default()
+                        as usize
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// [Panics](panic) if the provided radix is 0 or 1.
+            ///
+            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
+            fn count_digits_radix(self, radix: Self::Radix) -> usize {
+                match radix {
+                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
+                    02 => self.count_bits() as usize,
+                    08 => self.count_octal_digits() as usize,
+                    10 => self.count_digits(),
+                    16 => self.count_hex_digits() as usize,
+                    radix => match u32::try_from(radix as u128).ok().and_then(radix_log2) {
+                        Some(k) => 1 + (self.count_bits() - 1) as usize / k as usize,
+                        None => count_digits_radix_u128(self as $radix_type as u128, radix as u128),
+                    },
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// Returns [None] if the provided radix is 0 or 1.
+            ///
+            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
+            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+                match radix {
+                    0 | 1 => None,
+                    radix => Some(self.count_digits_radix(radix)),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of trits in the balanced ternary representation of an integer.
+            fn count_balanced_ternary_digits(self) -> u32 {
+                count_balanced_ternary_digits_of_magnitude(self.unsigned_abs() as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the bits of an integer reinterpreted as the corresponding unsigned primitive type.
+            fn bit_pattern_as_unsigned(self) -> Self::Radix {
+                self as $radix_type
+            }
+
+            #[inline(always)]
+            /// Returns the count of bits in the bitwise complement of an integer.
+            fn count_bits_of_complement(self) -> u32 {
+                (!self).count_bits()
+            }
+
+            #[inline(always)]
+            /// Returns the minimum count of bits that can hold an integer in twos-complement representation.
+            fn min_twos_complement_bits(self) -> u32 {
+                // The complement of a negative integer is non-negative and has the same significant bits.
+                let magnitude = if self < 0 { !self } else { self };
+                <$primitive_type>::BITS - magnitude.leading_zeros() + 1
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the count of decimal digits of each byte of an integer's
+            /// little-endian representation.
+            fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+                self.bit_pattern_as_unsigned().per_byte_decimal_widths()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the bitwise complement of an integer as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+                (!self).count_digits_radix(radix)
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline(always)]
+            /// Returns the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// without any prefix.
+            fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+                if radix == 10 && self.is_negative() {
+                    to_string_radix_of_magnitude(true, self.unsigned_abs() as u128, 10, uppercase)
+                } else {
+                    to_string_radix_of_magnitude(
+                        false,
+                        self as $radix_type as u128,
+                        radix as u128,
+                        uppercase,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            /// Writes the ASCII digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+            /// to the start of the buffer, without any prefix.
+            fn write_ascii_digits_radix(
+                self,
+                radix: Self::Radix,
+                uppercase: bool,
+                buf: &mut [u8],
+            ) -> Result<usize, usize> {
+                if radix == 10 && self.is_negative() {
+                    write_ascii_digits_of_magnitude(
+                        true,
+                        self.unsigned_abs() as u128,
+                        10,
+                        uppercase,
+                        buf,
+                    )
+                } else {
+                    write_ascii_digits_of_magnitude(
+                        false,
+                        self as $radix_type as u128,
+                        radix as u128,
+                        uppercase,
+                        buf,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// from the least-significant digit to the most-significant digit.
+            fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+                if radix == 10 && self.is_negative() {
+                    DigitsRadixLe::of_magnitude(self.unsigned_abs() as u128, 10)
+                } else {
+                    DigitsRadixLe::of_magnitude(self as $radix_type as u128, radix as u128)
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the largest value of a single digit in the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn max_digit_value(radix: Self::Radix) -> u8 {
+                max_digit_value_of_radix(radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, counting zero as having no digits.
+            fn count_digits_nonempty(self) -> usize {
+                match self {
+                    0 => 0,
+                    _ => self.count_digits(),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// counting zero as having no digits.
+            fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+                match self {
+                    0 if radix >= 2 => 0,
+                    _ => self.count_digits_radix(radix),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the largest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+                let magnitude = if self.is_negative() { 0 } else { self as u128 };
+                is_radix_boundary_of_magnitude(magnitude, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with its most-significant digit.
+            fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+                if radix == 10 {
+                    count_and_msd_of_magnitude(self.unsigned_abs() as u128, 10)
+                } else {
+                    count_and_msd_of_magnitude(self as $radix_type as u128, radix as u128)
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with whether the integer is an exact power of the radix.
+            fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+                if radix == 10 && self.is_negative() {
+                    count_and_is_power_of_magnitude(self.unsigned_abs() as u128, 10)
+                } else {
+                    count_and_is_power_of_magnitude(self as $radix_type as u128, radix as u128)
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the absolute difference between two integers.
+            fn count_digits_of_abs_diff(self, other: Self) -> usize {
+                self.abs_diff(other).count_digits()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the absolute difference between two integers
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.abs_diff(other).count_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the magnitude of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+                self.unsigned_abs().count_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// where negative integers are represented in twos complement with the given bit width.
+            fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+                let pattern =
+                    bit_pattern_in_width(self as i128, <$primitive_type>::BITS, bit_width);
+                if radix == 10 {
+                    self.count_digits()
+                } else {
+                    pattern.count_digits_radix(radix as u128)
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given power-of-two
+            /// [radix](https://en.wikipedia.org/wiki/Radix), where negative integers are represented in ones' complement.
+            fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+                let pattern = match self.is_negative() {
+                    true => !self.unsigned_abs(),
+                    false => self as $radix_type,
+                };
+                count_digits_of_power_of_two_radix(pattern as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the width of an integer formatted in decimal with a separator between each group
+            /// of `group_size` digits, including the negative sign of a negative integer.
+            fn grouped_width_with_sign(self, group_size: u32) -> usize {
+                let separators = self.count_separators_pattern(&[group_size]);
+                self.count_digits() + separators + self.is_negative() as usize
+            }
+
+            #[inline(always)]
+            /// Returns the count of characters in the [Debug](core::fmt::Debug) output of an integer.
+            fn debug_width(self) -> usize {
+                self.count_digits() + self.is_negative() as usize
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the smallest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+                let is_floor =
+                    is_radix_width_floor_of_magnitude(self.unsigned_abs() as u128, radix as u128);
+                is_floor && !self.is_negative()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digit positions that differ between two integers as interpreted
+            /// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+                if radix == 10 {
+                    digit_diff_count_of_magnitudes(
+                        self.unsigned_abs() as u128,
+                        other.unsigned_abs() as u128,
+                        10,
+                    )
+                } else {
+                    digit_diff_count_of_magnitudes(
+                        self as $radix_type as u128,
+                        other as $radix_type as u128,
+                        radix as u128,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of leading digits that two integers share as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+                if radix == 10 {
+                    common_prefix_len_of_magnitudes(
+                        self.unsigned_abs() as u128,
+                        other.unsigned_abs() as u128,
+                        10,
+                    )
+                } else {
+                    common_prefix_len_of_magnitudes(
+                        self as $radix_type as u128,
+                        other as $radix_type as u128,
+                        radix as u128,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// excluding its trailing zeros.
+            fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+                if radix == 10 {
+                    count_significant_digits_of_magnitude(self.unsigned_abs() as u128, 10)
+                } else {
+                    count_significant_digits_of_magnitude(
+                        self as $radix_type as u128,
+                        radix as u128,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// after rounding its magnitude to the nearest power of the radix.
+            fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+                if radix == 10 {
+                    count_digits_rounded_to_power_of_magnitude(self.unsigned_abs() as u128, 10)
+                } else {
+                    count_digits_rounded_to_power_of_magnitude(
+                        self as $radix_type as u128,
+                        radix as u128,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, excluding its trailing zeros.
+            fn count_significant_digits(self) -> usize {
+                count_significant_digits_of_magnitude(self.unsigned_abs() as u128, 10)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the sum of two integers, even if the sum overflows the type.
+            fn count_digits_of_sum(self, other: Self) -> usize {
+                match self.checked_add(other) {
+                    Some(sum) => sum.count_digits(),
+                    // The sum only overflows when both integers have the same sign.
+                    None => count_digits_of_magnitude_sum(
+                        self.unsigned_abs() as u128,
+                        other.unsigned_abs() as u128,
+                    ),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of rotations of the digits of an integer, as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix), that are palindromes.
+            fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+                if radix == 10 && self.is_negative() {
+                    palindromic_rotation_count_of_magnitude(self.unsigned_abs() as u128, 10)
+                } else {
+                    palindromic_rotation_count_of_magnitude(
+                        self as $radix_type as u128,
+                        radix as u128,
+                    )
+                }
+            }
+        }
+
+        impl CountDigits for $non_zero_type {
+            type Radix = $radix_type;
+
+            #[inline(always)]
+            /// Returns the count of bits in an integer.
+            fn count_bits(self) -> u32 {
+                if self.get().is_negative() {
+                    $min_value_bits
+                } else {
+                    1 + IntegerLogarithm::ilog2(self.get())
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of octal digits in an integer.
+            fn count_octal_digits(self) -> u32 {
+                if self.get().is_negative() {
+                    $min_value_octal_digits
+                } else {
+                    octal_digits_of_ilog2(IntegerLogarithm::ilog2(self.get()))
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of hexadecimal digits in an integer.
+            fn count_hex_digits(self) -> u32 {
+                if self.get().is_negative() {
+                    $min_value_hex_digits
+                } else {
+                    1 + IntegerLogarithm::ilog2(self.get()) / 4
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer.
+            fn count_digits(self) -> usize {
+                if <$primitive_type>::BITS == u64::BITS {
+                    count_decimal_digits_u64(self.get().abs_diff(0) as u64)
+                } else {
+                    1 + IntegerLogarithm::ilog10(self.get().abs_diff(0)) as usize
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// [Panics](panic) if the provided radix is 0 or 1.
+            ///
+            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
+            fn count_digits_radix(self, radix: Self::Radix) -> usize {
+                match radix {
+                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
+                    02 => self.count_bits() as usize,
+                    08 => self.count_octal_digits() as usize,
+                    10 => self.count_digits(),
+                    16 => self.count_hex_digits() as usize,
+                    radix => match u32::try_from(radix as u128).ok().and_then(radix_log2) {
+                        Some(k) => 1 + (self.count_bits() - 1) as usize / k as usize,
+                        None => count_digits_radix_u128(
+                            self.get() as $radix_type as u128,
+                            radix as u128,
+                        ),
+                    },
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// Returns [None] if the provided radix is 0 or 1.
+            ///
+            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
+            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+                match radix {
+                    0 | 1 => None,
+                    radix => Some(self.count_digits_radix(radix)),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of trits in the balanced ternary representation of an integer.
+            fn count_balanced_ternary_digits(self) -> u32 {
+                count_balanced_ternary_digits_of_magnitude(self.unsigned_abs().get() as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the bits of an integer reinterpreted as the corresponding unsigned primitive type.
+            fn bit_pattern_as_unsigned(self) -> Self::Radix {
+                self.get() as $radix_type
+            }
+
+            #[inline(always)]
+            /// Returns the count of bits in the bitwise complement of an integer.
+            fn count_bits_of_complement(self) -> u32 {
+                self.get().count_bits_of_complement()
+            }
+
+            #[inline(always)]
+            /// Returns the minimum count of bits that can hold an integer in twos-complement representation.
+            fn min_twos_complement_bits(self) -> u32 {
+                self.get().min_twos_complement_bits()
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the count of decimal digits of each byte of an integer's
+            /// little-endian representation.
+            fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+                self.get().per_byte_decimal_widths()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the bitwise complement of an integer as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_of_complement(radix)
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline(always)]
+            /// Returns the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// without any prefix.
+            fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+                self.get().to_string_radix(radix, uppercase)
+            }
+
+            #[inline(always)]
+            /// Writes the ASCII digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+            /// to the start of the buffer, without any prefix.
+            fn write_ascii_digits_radix(
+                self,
+                radix: Self::Radix,
+                uppercase: bool,
+                buf: &mut [u8],
+            ) -> Result<usize, usize> {
+                self.get().write_ascii_digits_radix(radix, uppercase, buf)
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// from the least-significant digit to the most-significant digit.
+            fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+                self.get().digits_radix_le(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the largest value of a single digit in the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn max_digit_value(radix: Self::Radix) -> u8 {
+                <$primitive_type>::max_digit_value(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, counting zero as having no digits.
+            fn count_digits_nonempty(self) -> usize {
+                self.count_digits()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// counting zero as having no digits.
+            fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+                self.count_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the largest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+                self.get().is_radix_boundary(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with its most-significant digit.
+            fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+                self.get().count_and_msd_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with whether the integer is an exact power of the radix.
+            fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+                self.get().count_and_is_power_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the absolute difference between two integers.
+            fn count_digits_of_abs_diff(self, other: Self) -> usize {
+                self.get().count_digits_of_abs_diff(other.get())
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the absolute difference between two integers
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.get()
+                    .count_digits_of_abs_diff_radix(other.get(), radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the magnitude of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_abs(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// where negative integers are represented in twos complement with the given bit width.
+            fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+                self.get().count_digits_radix_width(radix, bit_width)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given power-of-two
+            /// [radix](https://en.wikipedia.org/wiki/Radix), where negative integers are represented in ones' complement.
+            fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_ones_complement(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the width of an integer formatted in decimal with a separator between each group
+            /// of `group_size` digits, including the negative sign of a negative integer.
+            fn grouped_width_with_sign(self, group_size: u32) -> usize {
+                self.get().grouped_width_with_sign(group_size)
+            }
+
+            #[inline(always)]
+            /// Returns the count of characters in the [Debug](core::fmt::Debug) output of an integer.
+            fn debug_width(self) -> usize {
+                self.get().debug_width()
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the smallest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+                self.get().is_radix_width_floor(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digit positions that differ between two integers as interpreted
+            /// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.get().digit_diff_count_radix(other.get(), radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of leading digits that two integers share as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.get().common_prefix_len_radix(other.get(), radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// excluding its trailing zeros.
+            fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+                self.get().count_significant_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// after rounding its magnitude to the nearest power of the radix.
+            fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_rounded_to_power(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, excluding its trailing zeros.
+            fn count_significant_digits(self) -> usize {
+                self.get().count_significant_digits()
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the sum of two integers, even if the sum overflows the type.
+            fn count_digits_of_sum(self, other: Self) -> usize {
+                self.get().count_digits_of_sum(other.get())
+            }
+
+            #[inline(always)]
+            /// Returns the count of rotations of the digits of an integer, as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix), that are palindromes.
+            fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+                self.get().palindromic_rotation_count_radix(radix)
+            }
+        }
+    };
+    (
+        primitive_type = $primitive_type:ty,
+        non_zero_type = $non_zero_type:ty,
+    ) => {
+        impl CountDigits for $primitive_type {
+            type Radix = $primitive_type;
+
+            #[inline(always)]
+            /// Returns the count of bits in an integer.
+            fn count_bits(self) -> u32 {
+                // Setting the lowest bit counts zero as a single bit without a branch.
+                <$primitive_type>::BITS - (self | 1).leading_zeros()
+            }
+
+            #[inline(always)]
+            /// Returns the count of octal digits in an integer.
+            fn count_octal_digits(self) -> u32 {
+                octal_digits_of_ilog2(IntegerLogarithm::checked_ilog2(self).unwrap_or_default())
+            }
+
+            #[inline(always)]
+            /// Returns the count of hexadecimal digits in an integer.
+            fn count_hex_digits(self) -> u32 {
+                1 + IntegerLogarithm::checked_ilog2(self).unwrap_or_default() / 4
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer.
+            fn count_digits(self) -> usize {
+                if <$primitive_type>::BITS == u64::BITS {
+                    count_decimal_digits_u64(self as u64)
+                } else {
+                    1 + IntegerLogarithm::checked_ilog10(self).unwrap_or_default() as usize
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// [Panics](panic) if the provided radix is 0 or 1.
+            ///
+            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
+            fn count_digits_radix(self, radix: Self::Radix) -> usize {
+                match radix {
+                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
+                    02 => self.count_bits() as usize,
+                    08 => self.count_octal_digits() as usize,
+                    10 => self.count_digits(),
+                    16 => self.count_hex_digits() as usize,
+                    radix => match u32::try_from(radix as u128).ok().and_then(radix_log2) {
+                        Some(k) => 1 + (self.count_bits() - 1) as usize / k as usize,
+                        None => count_digits_radix_u128(self as u128, radix as u128),
+                    },
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// Returns [None] if the provided radix is 0 or 1.
+            ///
+            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
+            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+                match radix {
+                    0 | 1 => None,
+                    radix => Some(self.count_digits_radix(radix)),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of trits in the balanced ternary representation of an integer.
+            fn count_balanced_ternary_digits(self) -> u32 {
+                count_balanced_ternary_digits_of_magnitude(self as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the bits of an integer reinterpreted as the corresponding unsigned primitive type.
+            fn bit_pattern_as_unsigned(self) -> Self::Radix {
+                self
+            }
+
+            #[inline(always)]
+            /// Returns the count of bits in the bitwise complement of an integer.
+            fn count_bits_of_complement(self) -> u32 {
+                (!self).count_bits()
+            }
+
+            #[inline(always)]
+            /// Returns the minimum count of bits that can hold an integer in twos-complement representation.
+            fn min_twos_complement_bits(self) -> u32 {
+                <$primitive_type>::BITS - self.leading_zeros() + 1
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the count of decimal digits of each byte of an integer's
+            /// little-endian representation.
+            fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+                PerByteDecimalWidths::of_le_bytes(&self.to_le_bytes())
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the bitwise complement of an integer as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+                (!self).count_digits_radix(radix)
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline(always)]
+            /// Returns the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// without any prefix.
+            fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+                to_string_radix_of_magnitude(false, self as u128, radix as u128, uppercase)
+            }
+
+            #[inline(always)]
+            /// Writes the ASCII digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+            /// to the start of the buffer, without any prefix.
+            fn write_ascii_digits_radix(
+                self,
+                radix: Self::Radix,
+                uppercase: bool,
+                buf: &mut [u8],
+            ) -> Result<usize, usize> {
+                write_ascii_digits_of_magnitude(false, self as u128, radix as u128, uppercase, buf)
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// from the least-significant digit to the most-significant digit.
+            fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+                DigitsRadixLe::of_magnitude(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the largest value of a single digit in the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn max_digit_value(radix: Self::Radix) -> u8 {
+                max_digit_value_of_radix(radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, counting zero as having no digits.
+            fn count_digits_nonempty(self) -> usize {
+                match self {
+                    0 => 0,
+                    _ => self.count_digits(),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// counting zero as having no digits.
+            fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+                match self {
+                    0 if radix >= 2 => 0,
+                    _ => self.count_digits_radix(radix),
+                }
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the largest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+                is_radix_boundary_of_magnitude(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with its most-significant digit.
+            fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+                count_and_msd_of_magnitude(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with whether the integer is an exact power of the radix.
+            fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+                count_and_is_power_of_magnitude(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the absolute difference between two integers.
+            fn count_digits_of_abs_diff(self, other: Self) -> usize {
+                self.abs_diff(other).count_digits()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the absolute difference between two integers
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.abs_diff(other).count_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the magnitude of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+                self.count_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// after checking that it fits in the given bit width.
+            fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+                assert_fits_in_width(self as u128, <$primitive_type>::BITS, bit_width);
+                self.count_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given power-of-two
+            /// [radix](https://en.wikipedia.org/wiki/Radix), where negative integers are represented in ones' complement.
+            fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+                count_digits_of_power_of_two_radix(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the width of an integer formatted in decimal with a separator between each group
+            /// of `group_size` digits, including the negative sign of a negative integer.
+            fn grouped_width_with_sign(self, group_size: u32) -> usize {
+                self.count_digits() + self.count_separators_pattern(&[group_size])
+            }
+
+            #[inline(always)]
+            /// Returns the count of characters in the [Debug](core::fmt::Debug) output of an integer.
+            fn debug_width(self) -> usize {
+                self.count_digits()
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the smallest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+                is_radix_width_floor_of_magnitude(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digit positions that differ between two integers as interpreted
+            /// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+                digit_diff_count_of_magnitudes(self as u128, other as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of leading digits that two integers share as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+                common_prefix_len_of_magnitudes(self as u128, other as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// excluding its trailing zeros.
+            fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+                count_significant_digits_of_magnitude(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// after rounding its magnitude to the nearest power of the radix.
+            fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+                count_digits_rounded_to_power_of_magnitude(self as u128, radix as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, excluding its trailing zeros.
+            fn count_significant_digits(self) -> usize {
+                count_significant_digits_of_magnitude(self as u128, 10)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the sum of two integers, even if the sum overflows the type.
+            fn count_digits_of_sum(self, other: Self) -> usize {
+                count_digits_of_magnitude_sum(self as u128, other as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the count of rotations of the digits of an integer, as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix), that are palindromes.
+            fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+                palindromic_rotation_count_of_magnitude(self as u128, radix as u128)
+            }
+        }
+
+        impl CountDigits for $non_zero_type {
+            type Radix = $primitive_type;
+
+            #[inline(always)]
+            /// Returns the count of bits in an integer.
+            fn count_bits(self) -> u32 {
+                1 + NonZeroIntegerLogarithm::ilog2(self)
+            }
+
+            #[inline(always)]
+            /// Returns the count of octal digits in an integer.
+            fn count_octal_digits(self) -> u32 {
+                octal_digits_of_ilog2(NonZeroIntegerLogarithm::ilog2(self))
+            }
+
+            #[inline(always)]
+            /// Returns the count of hexadecimal digits in an integer.
+            fn count_hex_digits(self) -> u32 {
+                1 + NonZeroIntegerLogarithm::ilog2(self) / 4
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer.
+            fn count_digits(self) -> usize {
+                if <$primitive_type>::BITS == u64::BITS {
+                    count_decimal_digits_u64(self.get() as u64)
+                } else {
+                    1 + NonZeroIntegerLogarithm::ilog10(self) as usize
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// [Panics](panic) if the provided radix is 0 or 1.
+            ///
+            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
+            fn count_digits_radix(self, radix: Self::Radix) -> usize {
+                match radix {
+                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
+                    02 => self.count_bits() as usize,
+                    08 => self.count_octal_digits() as usize,
+                    10 => self.count_digits(),
+                    16 => self.count_hex_digits() as usize,
+                    radix => match u32::try_from(radix as u128).ok().and_then(radix_log2) {
+                        Some(k) => 1 + (self.count_bits() - 1) as usize / k as usize,
+                        None => count_digits_radix_u128(self.get() as u128, radix as u128),
+                    },
+                }
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            ///
+            /// Returns [None] if the provided radix is 0 or 1.
+            ///
+            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
+            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+                match radix {
+                    0 | 1 => None,
                     radix => Some(self.count_digits_radix(radix)),
                 }
             }
-        }
-    };
-    (
-        primitive_type = $primitive_type:ty,
-        non_zero_type = $non_zero_type:ty,
-    ) => {
-        impl CountDigits for $primitive_type {
-            type Radix = $primitive_type;
 
-            #[inline(always)]
-            /// Returns the count of bits in an integer.
-            fn count_bits(self) -> u32 {
-                1 + self.checked_ilog2().unwrap_or_default()
+            #[inline(always)]
+            /// Returns the count of trits in the balanced ternary representation of an integer.
+            fn count_balanced_ternary_digits(self) -> u32 {
+                count_balanced_ternary_digits_of_magnitude(self.get() as u128)
+            }
+
+            #[inline(always)]
+            /// Returns the bits of an integer reinterpreted as the corresponding unsigned primitive type.
+            fn bit_pattern_as_unsigned(self) -> Self::Radix {
+                self.get()
+            }
+
+            #[inline(always)]
+            /// Returns the count of bits in the bitwise complement of an integer.
+            fn count_bits_of_complement(self) -> u32 {
+                self.get().count_bits_of_complement()
+            }
+
+            #[inline(always)]
+            /// Returns the minimum count of bits that can hold an integer in twos-complement representation.
+            fn min_twos_complement_bits(self) -> u32 {
+                self.get().min_twos_complement_bits()
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the count of decimal digits of each byte of an integer's
+            /// little-endian representation.
+            fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+                self.get().per_byte_decimal_widths()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the bitwise complement of an integer as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_of_complement(radix)
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline(always)]
+            /// Returns the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// without any prefix.
+            fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+                self.get().to_string_radix(radix, uppercase)
+            }
+
+            #[inline(always)]
+            /// Writes the ASCII digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+            /// to the start of the buffer, without any prefix.
+            fn write_ascii_digits_radix(
+                self,
+                radix: Self::Radix,
+                uppercase: bool,
+                buf: &mut [u8],
+            ) -> Result<usize, usize> {
+                self.get().write_ascii_digits_radix(radix, uppercase, buf)
+            }
+
+            #[inline(always)]
+            /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// from the least-significant digit to the most-significant digit.
+            fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+                self.get().digits_radix_le(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the largest value of a single digit in the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn max_digit_value(radix: Self::Radix) -> u8 {
+                <$primitive_type>::max_digit_value(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, counting zero as having no digits.
+            fn count_digits_nonempty(self) -> usize {
+                self.count_digits()
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// counting zero as having no digits.
+            fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+                self.count_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the largest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+                self.get().is_radix_boundary(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with its most-significant digit.
+            fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+                self.get().count_and_msd_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// along with whether the integer is an exact power of the radix.
+            fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+                self.get().count_and_is_power_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the absolute difference between two integers.
+            fn count_digits_of_abs_diff(self, other: Self) -> usize {
+                self.get().count_digits_of_abs_diff(other.get())
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the absolute difference between two integers
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.get()
+                    .count_digits_of_abs_diff_radix(other.get(), radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in the magnitude of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_abs(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// after checking that it fits in the given bit width.
+            fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+                self.get().count_digits_radix_width(radix, bit_width)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given power-of-two
+            /// [radix](https://en.wikipedia.org/wiki/Radix), where negative integers are represented in ones' complement.
+            fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_ones_complement(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the width of an integer formatted in decimal with a separator between each group
+            /// of `group_size` digits, including the negative sign of a negative integer.
+            fn grouped_width_with_sign(self, group_size: u32) -> usize {
+                self.get().grouped_width_with_sign(group_size)
+            }
+
+            #[inline(always)]
+            /// Returns the count of characters in the [Debug](core::fmt::Debug) output of an integer.
+            fn debug_width(self) -> usize {
+                self.get().debug_width()
+            }
+
+            #[inline(always)]
+            /// Returns whether an integer is the smallest value with its count of digits
+            /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+                self.get().is_radix_width_floor(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digit positions that differ between two integers as interpreted
+            /// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+            fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.get().digit_diff_count_radix(other.get(), radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of leading digits that two integers share as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix).
+            fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+                self.get().common_prefix_len_radix(other.get(), radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// excluding its trailing zeros.
+            fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+                self.get().count_significant_digits_radix(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+            /// after rounding its magnitude to the nearest power of the radix.
+            fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+                self.get().count_digits_radix_rounded_to_power(radix)
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in an integer, excluding its trailing zeros.
+            fn count_significant_digits(self) -> usize {
+                self.get().count_significant_digits()
+            }
+
+            #[inline(always)]
+            /// Returns the count of decimal digits in the sum of two integers, even if the sum overflows the type.
+            fn count_digits_of_sum(self, other: Self) -> usize {
+                self.get().count_digits_of_sum(other.get())
+            }
+
+            #[inline(always)]
+            /// Returns the count of rotations of the digits of an integer, as interpreted with the given
+            /// [radix](https://en.wikipedia.org/wiki/Radix), that are palindromes.
+            fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+                self.get().palindromic_rotation_count_radix(radix)
+            }
+        }
+    };
+}
+
+impl<T: CountDigits> CountDigits for &T {
+    type Radix = <T as CountDigits>::Radix;
+
+    #[inline(always)]
+    /// Calls [count_bits()][CountDigits::count_bits] on the inner value.
+    fn count_bits(self) -> u32 {
+        (*self).count_bits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_octal_digits()][CountDigits::count_octal_digits] on the inner value.
+    fn count_octal_digits(self) -> u32 {
+        (*self).count_octal_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits()][CountDigits::count_digits] on the inner value.
+    fn count_digits(self) -> usize {
+        (*self).count_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_hex_digits()][CountDigits::count_hex_digits] on the inner value.
+    fn count_hex_digits(self) -> u32 {
+        (*self).count_hex_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix()][CountDigits::count_digits_radix] on the inner value.
+    fn count_digits_radix(self, radix: Self::Radix) -> usize {
+        (*self).count_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [checked_count_digits_radix()][CountDigits::checked_count_digits_radix] on the inner value.
+    fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+        (*self).checked_count_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_balanced_ternary_digits()][CountDigits::count_balanced_ternary_digits] on the inner value.
+    fn count_balanced_ternary_digits(self) -> u32 {
+        (*self).count_balanced_ternary_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [bit_pattern_as_unsigned()][CountDigits::bit_pattern_as_unsigned] on the inner value.
+    fn bit_pattern_as_unsigned(self) -> Self::Radix {
+        (*self).bit_pattern_as_unsigned()
+    }
+
+    #[inline(always)]
+    /// Calls [count_bits_of_complement()][CountDigits::count_bits_of_complement] on the inner value.
+    fn count_bits_of_complement(self) -> u32 {
+        (*self).count_bits_of_complement()
+    }
+
+    #[inline(always)]
+    /// Calls [min_twos_complement_bits()][CountDigits::min_twos_complement_bits] on the inner value.
+    fn min_twos_complement_bits(self) -> u32 {
+        (*self).min_twos_complement_bits()
+    }
+
+    #[inline(always)]
+    /// Calls [per_byte_decimal_widths()][CountDigits::per_byte_decimal_widths] on the inner value.
+    fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+        (*self).per_byte_decimal_widths()
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_of_complement()][CountDigits::count_digits_radix_of_complement] on the inner value.
+    fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+        (*self).count_digits_radix_of_complement(radix)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    /// Calls [to_string_radix()][CountDigits::to_string_radix] on the inner value.
+    fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+        (*self).to_string_radix(radix, uppercase)
+    }
+
+    #[inline(always)]
+    /// Calls [write_ascii_digits_radix()][CountDigits::write_ascii_digits_radix] on the inner value.
+    fn write_ascii_digits_radix(
+        self,
+        radix: Self::Radix,
+        uppercase: bool,
+        buf: &mut [u8],
+    ) -> Result<usize, usize> {
+        (*self).write_ascii_digits_radix(radix, uppercase, buf)
+    }
+
+    #[inline(always)]
+    /// Calls [digits_radix_le()][CountDigits::digits_radix_le] on the inner value.
+    fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+        (*self).digits_radix_le(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [max_digit_value()][CountDigits::max_digit_value] on the inner type.
+    fn max_digit_value(radix: Self::Radix) -> u8 {
+        T::max_digit_value(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_nonempty()][CountDigits::count_digits_radix_nonempty] on the inner value.
+    fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+        (*self).count_digits_radix_nonempty(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [is_radix_boundary()][CountDigits::is_radix_boundary] on the inner value.
+    fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+        (*self).is_radix_boundary(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_and_msd_radix()][CountDigits::count_and_msd_radix] on the inner value.
+    fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+        (*self).count_and_msd_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_and_is_power_radix()][CountDigits::count_and_is_power_radix] on the inner value.
+    fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+        (*self).count_and_is_power_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_of_abs_diff_radix()][CountDigits::count_digits_of_abs_diff_radix] on the inner value.
+    fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+        (*self).count_digits_of_abs_diff_radix(*other, radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_abs()][CountDigits::count_digits_radix_abs] on the inner value.
+    fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+        (*self).count_digits_radix_abs(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_width()][CountDigits::count_digits_radix_width] on the inner value.
+    fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+        (*self).count_digits_radix_width(radix, bit_width)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_ones_complement()][CountDigits::count_digits_radix_ones_complement] on the inner value.
+    fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+        (*self).count_digits_radix_ones_complement(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [grouped_width_with_sign()][CountDigits::grouped_width_with_sign] on the inner value.
+    fn grouped_width_with_sign(self, group_size: u32) -> usize {
+        (*self).grouped_width_with_sign(group_size)
+    }
+
+    #[inline(always)]
+    /// Calls [debug_width()][CountDigits::debug_width] on the inner value.
+    fn debug_width(self) -> usize {
+        (*self).debug_width()
+    }
+
+    #[inline(always)]
+    /// Calls [is_radix_width_floor()][CountDigits::is_radix_width_floor] on the inner value.
+    fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+        (*self).is_radix_width_floor(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [digit_diff_count_radix()][CountDigits::digit_diff_count_radix] on the inner value.
+    fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+        (*self).digit_diff_count_radix(*other, radix)
+    }
+
+    #[inline(always)]
+    /// Calls [common_prefix_len_radix()][CountDigits::common_prefix_len_radix] on the inner values.
+    fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+        (*self).common_prefix_len_radix(*other, radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_significant_digits_radix()][CountDigits::count_significant_digits_radix] on the inner value.
+    fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+        (*self).count_significant_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_rounded_to_power()][CountDigits::count_digits_radix_rounded_to_power] on the inner value.
+    fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+        (*self).count_digits_radix_rounded_to_power(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_of_sum()][CountDigits::count_digits_of_sum] on the inner value.
+    fn count_digits_of_sum(self, other: Self) -> usize {
+        (*self).count_digits_of_sum(*other)
+    }
+
+    #[inline(always)]
+    /// Calls [palindromic_rotation_count_radix()][CountDigits::palindromic_rotation_count_radix] on the inner value.
+    fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+        (*self).palindromic_rotation_count_radix(radix)
+    }
+}
+
+/// Counts the digits in the wrapped integer, so that values sorted in descending order
+/// can be counted without unwrapping them.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::cmp::Reverse;
+/// use count_digits::CountDigits;
+///
+/// let mut values = [Reverse(7_u32), Reverse(123), Reverse(45)];
+/// values.sort();
+///
+/// assert_eq!(3, values[0].count_digits());
+/// assert_eq!(2, values[1].count_digits());
+/// assert_eq!(1, values[2].count_digits());
+/// ```
+impl<T: CountDigits> CountDigits for Reverse<T> {
+    type Radix = <T as CountDigits>::Radix;
+
+    #[inline(always)]
+    /// Calls [count_bits()][CountDigits::count_bits] on the inner value.
+    fn count_bits(self) -> u32 {
+        self.0.count_bits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_octal_digits()][CountDigits::count_octal_digits] on the inner value.
+    fn count_octal_digits(self) -> u32 {
+        self.0.count_octal_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits()][CountDigits::count_digits] on the inner value.
+    fn count_digits(self) -> usize {
+        self.0.count_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_hex_digits()][CountDigits::count_hex_digits] on the inner value.
+    fn count_hex_digits(self) -> u32 {
+        self.0.count_hex_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix()][CountDigits::count_digits_radix] on the inner value.
+    fn count_digits_radix(self, radix: Self::Radix) -> usize {
+        self.0.count_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [checked_count_digits_radix()][CountDigits::checked_count_digits_radix] on the inner value.
+    fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+        self.0.checked_count_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_balanced_ternary_digits()][CountDigits::count_balanced_ternary_digits] on the inner value.
+    fn count_balanced_ternary_digits(self) -> u32 {
+        self.0.count_balanced_ternary_digits()
+    }
+
+    #[inline(always)]
+    /// Calls [bit_pattern_as_unsigned()][CountDigits::bit_pattern_as_unsigned] on the inner value.
+    fn bit_pattern_as_unsigned(self) -> Self::Radix {
+        self.0.bit_pattern_as_unsigned()
+    }
+
+    #[inline(always)]
+    /// Calls [count_bits_of_complement()][CountDigits::count_bits_of_complement] on the inner value.
+    fn count_bits_of_complement(self) -> u32 {
+        self.0.count_bits_of_complement()
+    }
+
+    #[inline(always)]
+    /// Calls [min_twos_complement_bits()][CountDigits::min_twos_complement_bits] on the inner value.
+    fn min_twos_complement_bits(self) -> u32 {
+        self.0.min_twos_complement_bits()
+    }
+
+    #[inline(always)]
+    /// Calls [per_byte_decimal_widths()][CountDigits::per_byte_decimal_widths] on the inner value.
+    fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+        self.0.per_byte_decimal_widths()
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_of_complement()][CountDigits::count_digits_radix_of_complement] on the inner value.
+    fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+        self.0.count_digits_radix_of_complement(radix)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    /// Calls [to_string_radix()][CountDigits::to_string_radix] on the inner value.
+    fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+        self.0.to_string_radix(radix, uppercase)
+    }
+
+    #[inline(always)]
+    /// Calls [write_ascii_digits_radix()][CountDigits::write_ascii_digits_radix] on the inner value.
+    fn write_ascii_digits_radix(
+        self,
+        radix: Self::Radix,
+        uppercase: bool,
+        buf: &mut [u8],
+    ) -> Result<usize, usize> {
+        self.0.write_ascii_digits_radix(radix, uppercase, buf)
+    }
+
+    #[inline(always)]
+    /// Calls [digits_radix_le()][CountDigits::digits_radix_le] on the inner value.
+    fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+        self.0.digits_radix_le(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [max_digit_value()][CountDigits::max_digit_value] on the inner type.
+    fn max_digit_value(radix: Self::Radix) -> u8 {
+        T::max_digit_value(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_nonempty()][CountDigits::count_digits_radix_nonempty] on the inner value.
+    fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+        self.0.count_digits_radix_nonempty(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [is_radix_boundary()][CountDigits::is_radix_boundary] on the inner value.
+    fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+        self.0.is_radix_boundary(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_and_msd_radix()][CountDigits::count_and_msd_radix] on the inner value.
+    fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+        self.0.count_and_msd_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_and_is_power_radix()][CountDigits::count_and_is_power_radix] on the inner value.
+    fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+        self.0.count_and_is_power_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_of_abs_diff_radix()][CountDigits::count_digits_of_abs_diff_radix] on the inner value.
+    fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+        self.0.count_digits_of_abs_diff_radix(other.0, radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_abs()][CountDigits::count_digits_radix_abs] on the inner value.
+    fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+        self.0.count_digits_radix_abs(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_width()][CountDigits::count_digits_radix_width] on the inner value.
+    fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+        self.0.count_digits_radix_width(radix, bit_width)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_ones_complement()][CountDigits::count_digits_radix_ones_complement] on the inner value.
+    fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+        self.0.count_digits_radix_ones_complement(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [grouped_width_with_sign()][CountDigits::grouped_width_with_sign] on the inner value.
+    fn grouped_width_with_sign(self, group_size: u32) -> usize {
+        self.0.grouped_width_with_sign(group_size)
+    }
+
+    #[inline(always)]
+    /// Returns the count of characters in the [Debug](core::fmt::Debug) output of the inner value,
+    /// plus the width of `Reverse(` and `)`.
+    fn debug_width(self) -> usize {
+        "Reverse()".len() + self.0.debug_width()
+    }
+
+    #[inline(always)]
+    /// Calls [is_radix_width_floor()][CountDigits::is_radix_width_floor] on the inner value.
+    fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+        self.0.is_radix_width_floor(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [digit_diff_count_radix()][CountDigits::digit_diff_count_radix] on the inner value.
+    fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+        self.0.digit_diff_count_radix(other.0, radix)
+    }
+
+    #[inline(always)]
+    /// Calls [common_prefix_len_radix()][CountDigits::common_prefix_len_radix] on the inner values.
+    fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+        self.0.common_prefix_len_radix(other.0, radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_significant_digits_radix()][CountDigits::count_significant_digits_radix] on the inner value.
+    fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+        self.0.count_significant_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_rounded_to_power()][CountDigits::count_digits_radix_rounded_to_power] on the inner value.
+    fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+        self.0.count_digits_radix_rounded_to_power(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_of_sum()][CountDigits::count_digits_of_sum] on the inner value.
+    fn count_digits_of_sum(self, other: Self) -> usize {
+        self.0.count_digits_of_sum(other.0)
+    }
+
+    #[inline(always)]
+    /// Calls [palindromic_rotation_count_radix()][CountDigits::palindromic_rotation_count_radix] on the inner value.
+    fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+        self.0.palindromic_rotation_count_radix(radix)
+    }
+}
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+#[macro_export]
+/// Implements [CountDigits] for a struct with a single field by forwarding every method to the field.
+///
+/// This is the expansion of `#[derive(CountDigits)]`, and it is not part of the public API.
+macro_rules! __derive_count_digits {
+    (
+        impl [$($impl_generics:tt)*] for $type:ty,
+        field $field:tt: $inner:ty,
+        where [$($where_predicates:tt)*]
+    ) => {
+        impl $($impl_generics)* $crate::CountDigits for $type
+        where
+            $inner: $crate::CountDigits,
+            $($where_predicates)*
+        {
+            type Radix = <$inner as $crate::CountDigits>::Radix;
+
+            #[inline(always)]
+            fn count_bits(self) -> u32 {
+                $crate::CountDigits::count_bits(self.$field)
+            }
+
+            #[inline(always)]
+            fn count_octal_digits(self) -> u32 {
+                $crate::CountDigits::count_octal_digits(self.$field)
+            }
+
+            #[inline(always)]
+            fn count_digits(self) -> usize {
+                $crate::CountDigits::count_digits(self.$field)
+            }
+
+            #[inline(always)]
+            fn count_hex_digits(self) -> u32 {
+                $crate::CountDigits::count_hex_digits(self.$field)
+            }
+
+            #[inline(always)]
+            fn count_digits_radix(self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_digits_radix(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn checked_count_digits_radix(self, radix: Self::Radix) -> ::core::option::Option<usize> {
+                $crate::CountDigits::checked_count_digits_radix(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_balanced_ternary_digits(self) -> u32 {
+                $crate::CountDigits::count_balanced_ternary_digits(self.$field)
+            }
+
+            #[inline(always)]
+            fn bit_pattern_as_unsigned(self) -> Self::Radix {
+                $crate::CountDigits::bit_pattern_as_unsigned(self.$field)
+            }
+
+            #[inline(always)]
+            fn count_bits_of_complement(self) -> u32 {
+                $crate::CountDigits::count_bits_of_complement(self.$field)
+            }
+
+            #[inline(always)]
+            fn min_twos_complement_bits(self) -> u32 {
+                $crate::CountDigits::min_twos_complement_bits(self.$field)
+            }
+
+            #[inline(always)]
+            fn per_byte_decimal_widths(self) -> $crate::PerByteDecimalWidths {
+                $crate::CountDigits::per_byte_decimal_widths(self.$field)
+            }
+
+            #[inline(always)]
+            fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_digits_radix_of_complement(self.$field, radix)
+            }
+
+            $crate::__derive_count_digits_to_string_radix!($field);
+
+            #[inline(always)]
+            fn write_ascii_digits_radix(
+                self,
+                radix: Self::Radix,
+                uppercase: bool,
+                buf: &mut [u8],
+            ) -> ::core::result::Result<usize, usize> {
+                $crate::CountDigits::write_ascii_digits_radix(self.$field, radix, uppercase, buf)
+            }
+
+            #[inline(always)]
+            fn digits_radix_le(self, radix: Self::Radix) -> $crate::DigitsRadixLe {
+                $crate::CountDigits::digits_radix_le(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn max_digit_value(radix: Self::Radix) -> u8 {
+                <$inner as $crate::CountDigits>::max_digit_value(radix)
+            }
+
+            #[inline(always)]
+            fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_digits_radix_nonempty(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+                $crate::CountDigits::is_radix_boundary(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+                $crate::CountDigits::count_and_msd_radix(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+                $crate::CountDigits::count_and_is_power_radix(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_digits_of_abs_diff_radix(self.$field, other.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_digits_radix_abs(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+                $crate::CountDigits::count_digits_radix_width(self.$field, radix, bit_width)
+            }
+
+            #[inline(always)]
+            fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_digits_radix_ones_complement(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn grouped_width_with_sign(self, group_size: u32) -> usize {
+                $crate::CountDigits::grouped_width_with_sign(self.$field, group_size)
+            }
+
+            #[inline(always)]
+            fn debug_width(self) -> usize {
+                $crate::CountDigits::debug_width(self.$field)
+            }
+
+            #[inline(always)]
+            fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+                $crate::CountDigits::is_radix_width_floor(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::digit_diff_count_radix(self.$field, other.$field, radix)
+            }
+
+            #[inline(always)]
+            fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::common_prefix_len_radix(self.$field, other.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_significant_digits_radix(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+                $crate::CountDigits::count_digits_radix_rounded_to_power(self.$field, radix)
+            }
+
+            #[inline(always)]
+            fn count_digits_of_sum(self, other: Self) -> usize {
+                $crate::CountDigits::count_digits_of_sum(self.$field, other.$field)
+            }
+
+            #[inline(always)]
+            fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+                $crate::CountDigits::palindromic_rotation_count_radix(self.$field, radix)
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "derive", feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+/// Forwards [to_string_radix()](CountDigits::to_string_radix) to the field of a derived implementation.
+///
+/// The method only exists with the `alloc` feature of this crate, which cannot be checked
+/// by a `cfg` attribute in the crate that expands the derive.
+macro_rules! __derive_count_digits_to_string_radix {
+    ($field:tt) => {
+        #[inline(always)]
+        fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> $crate::__private::String {
+            $crate::CountDigits::to_string_radix(self.$field, radix, uppercase)
+        }
+    };
+}
+
+#[cfg(all(feature = "derive", not(feature = "alloc")))]
+#[doc(hidden)]
+#[macro_export]
+/// Forwards nothing, because [CountDigits] has no `to_string_radix()` without the `alloc` feature.
+macro_rules! __derive_count_digits_to_string_radix {
+    ($field:tt) => {};
+}
+
+#[cfg(all(feature = "derive", feature = "alloc"))]
+#[doc(hidden)]
+/// Items that are used by the expansion of `#[derive(CountDigits)]`, and are not part of the public API.
+pub mod __private {
+    pub use alloc::string::String;
+}
+
+/// Counts the digits in the contained integer, or counts [None] as having no digits.
+///
+/// <div class="warning" style="text-align: left;">
+/// This differs from the convention that zero has one digit: [None] is an absent value rather than zero,
+/// so every count is 0 and every predicate is false. The methods that return a [NonZeroU32] cannot
+/// return 0, so they count [None] as one digit.
+///
+/// Methods that take a radix still panic, or return [None], if the provided radix is 0 or 1.
+/// </div>
+///
+/// Without a contained value, the radix can only be validated by comparing it with 2, so this is implemented
+/// when the radix type of `T` implements [PartialOrd] and [`From<u8>`], as the radix types of all integers do.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::CountDigits;
+///
+/// assert_eq!(4, Some(1337_u32).count_digits());
+/// assert_eq!(0, None::<u32>.count_digits());
+/// assert_eq!(1, Some(0_u32).count_digits());
+///
+/// assert_eq!(Some(0), None::<u32>.checked_count_digits_radix(16));
+/// assert_eq!(None, None::<u32>.checked_count_digits_radix(1));
+/// ```
+impl<T: CountDigits> CountDigits for Option<T>
+where
+    T::Radix: PartialOrd + From<u8>,
+{
+    type Radix = <T as CountDigits>::Radix;
+
+    #[inline(always)]
+    /// Calls [count_bits()][CountDigits::count_bits] on the contained value, or returns 0.
+    fn count_bits(self) -> u32 {
+        self.map_or(0, CountDigits::count_bits)
+    }
+
+    #[inline(always)]
+    /// Calls [count_octal_digits()][CountDigits::count_octal_digits] on the contained value, or returns 0.
+    fn count_octal_digits(self) -> u32 {
+        self.map_or(0, CountDigits::count_octal_digits)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits()][CountDigits::count_digits] on the contained value, or returns 0.
+    fn count_digits(self) -> usize {
+        self.map_or(0, CountDigits::count_digits)
+    }
+
+    #[inline(always)]
+    /// Calls [count_hex_digits()][CountDigits::count_hex_digits] on the contained value, or returns 0.
+    fn count_hex_digits(self) -> u32 {
+        self.map_or(0, CountDigits::count_hex_digits)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix()][CountDigits::count_digits_radix] on the contained value, or returns 0.
+    fn count_digits_radix(self, radix: Self::Radix) -> usize {
+        match self {
+            Some(n) => n.count_digits_radix(radix),
+            None if radix >= 2.into() => 0,
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [checked_count_digits_radix()][CountDigits::checked_count_digits_radix] on the contained value, or returns 0.
+    fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+        match self {
+            Some(n) => n.checked_count_digits_radix(radix),
+            None if radix >= 2.into() => Some(0),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_balanced_ternary_digits()][CountDigits::count_balanced_ternary_digits] on the contained value, or returns 0.
+    fn count_balanced_ternary_digits(self) -> u32 {
+        self.map_or(0, CountDigits::count_balanced_ternary_digits)
+    }
+
+    #[inline(always)]
+    /// Calls [bit_pattern_as_unsigned()][CountDigits::bit_pattern_as_unsigned] on the contained value, or returns 0.
+    fn bit_pattern_as_unsigned(self) -> Self::Radix {
+        self.map_or(0.into(), CountDigits::bit_pattern_as_unsigned)
+    }
+
+    #[inline(always)]
+    /// Calls [count_bits_of_complement()][CountDigits::count_bits_of_complement] on the contained value,
+    /// or returns 0.
+    fn count_bits_of_complement(self) -> u32 {
+        match self {
+            Some(n) => n.count_bits_of_complement(),
+            None => self.count_bits(),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [min_twos_complement_bits()][CountDigits::min_twos_complement_bits] on the contained value,
+    /// or returns 0.
+    fn min_twos_complement_bits(self) -> u32 {
+        self.map_or(0, CountDigits::min_twos_complement_bits)
+    }
+
+    #[inline(always)]
+    /// Calls [per_byte_decimal_widths()][CountDigits::per_byte_decimal_widths] on the contained value,
+    /// or returns an iterator that yields no widths.
+    fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+        match self {
+            Some(n) => n.per_byte_decimal_widths(),
+            None => PerByteDecimalWidths::of_le_bytes(&[]),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_of_complement()][CountDigits::count_digits_radix_of_complement] on the contained value,
+    /// or returns 0.
+    fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+        match self {
+            Some(n) => n.count_digits_radix_of_complement(radix),
+            None => self.count_digits_radix(radix),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    /// Calls [to_string_radix()][CountDigits::to_string_radix] on the contained value, or returns an empty string.
+    fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+        match self {
+            Some(n) => n.to_string_radix(radix, uppercase),
+            None if radix >= 2.into() => String::new(),
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [write_ascii_digits_radix()][CountDigits::write_ascii_digits_radix] on the contained value,
+    /// or writes nothing.
+    fn write_ascii_digits_radix(
+        self,
+        radix: Self::Radix,
+        uppercase: bool,
+        buf: &mut [u8],
+    ) -> Result<usize, usize> {
+        match self {
+            Some(n) => n.write_ascii_digits_radix(radix, uppercase, buf),
+            None if radix >= 2.into() => Ok(0),
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [digits_radix_le()][CountDigits::digits_radix_le] on the contained value,
+    /// or returns an iterator that yields no digits.
+    fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+        match self {
+            Some(n) => n.digits_radix_le(radix),
+            None if radix >= 2.into() => DigitsRadixLe::empty(),
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [max_digit_value()][CountDigits::max_digit_value] on the contained type.
+    fn max_digit_value(radix: Self::Radix) -> u8 {
+        T::max_digit_value(radix)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_nonempty()][CountDigits::count_digits_radix_nonempty] on the contained value, or returns 0.
+    fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+        match self {
+            Some(n) => n.count_digits_radix_nonempty(radix),
+            None => self.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [fits_in_digits_radix()][CountDigits::fits_in_digits_radix] on the contained value, or returns false.
+    fn fits_in_digits_radix(self, digits: u32, radix: Self::Radix) -> bool {
+        match self {
+            Some(n) => n.fits_in_digits_radix(digits, radix),
+            None if radix >= 2.into() => false,
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [is_radix_boundary()][CountDigits::is_radix_boundary] on the contained value, or returns false.
+    fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+        match self {
+            Some(n) => n.is_radix_boundary(radix),
+            None if radix >= 2.into() => false,
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_and_msd_radix()][CountDigits::count_and_msd_radix] on the contained value, or returns zeros.
+    fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+        match self {
+            Some(n) => n.count_and_msd_radix(radix),
+            None => (self.count_digits_radix(radix), 0),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_and_is_power_radix()][CountDigits::count_and_is_power_radix] on the contained value,
+    /// or returns zero digits and false.
+    fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+        match self {
+            Some(n) => n.count_and_is_power_radix(radix),
+            None => (self.count_digits_radix(radix), false),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_of_abs_diff_radix()][CountDigits::count_digits_of_abs_diff_radix] on the contained values,
+    /// or returns 0 if either value is absent.
+    fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+        match (self, other) {
+            (Some(n), Some(other)) => n.count_digits_of_abs_diff_radix(other, radix),
+            _ => None::<T>.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_abs()][CountDigits::count_digits_radix_abs] on the contained value, or returns 0.
+    fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+        match self {
+            Some(n) => n.count_digits_radix_abs(radix),
+            None => self.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_width()][CountDigits::count_digits_radix_width] on the contained value, or returns 0.
+    fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+        match self {
+            Some(n) => n.count_digits_radix_width(radix, bit_width),
+            None => self.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_ones_complement()][CountDigits::count_digits_radix_ones_complement] on the contained value,
+    /// or returns zero.
+    fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+        match self {
+            Some(n) => n.count_digits_radix_ones_complement(radix),
+            None => self.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [grouped_width_with_sign()][CountDigits::grouped_width_with_sign] on the contained value,
+    /// or returns 0.
+    fn grouped_width_with_sign(self, group_size: u32) -> usize {
+        match self {
+            Some(n) => n.grouped_width_with_sign(group_size),
+            None => self.count_separators_pattern(&[group_size]),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of characters in the [Debug](core::fmt::Debug) output of the contained value,
+    /// plus the width of `Some(` and `)`, or the width of `None`.
+    fn debug_width(self) -> usize {
+        match self {
+            Some(n) => "Some()".len() + n.debug_width(),
+            None => "None".len(),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [is_radix_width_floor()][CountDigits::is_radix_width_floor] on the contained value, or returns false.
+    fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+        match self {
+            Some(n) => n.is_radix_width_floor(radix),
+            None if radix >= 2.into() => false,
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [digit_diff_count_radix()][CountDigits::digit_diff_count_radix] on the contained values,
+    /// or returns 0 if either value is absent.
+    fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+        match (self, other) {
+            (Some(n), Some(other)) => n.digit_diff_count_radix(other, radix),
+            _ => None::<T>.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [common_prefix_len_radix()][CountDigits::common_prefix_len_radix] on the contained values,
+    /// or returns 0 if either value is [None].
+    fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+        match (self, other) {
+            (Some(n), Some(other)) => n.common_prefix_len_radix(other, radix),
+            _ => None::<T>.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_significant_digits_radix()][CountDigits::count_significant_digits_radix] on the contained value, or returns 0.
+    fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+        match self {
+            Some(n) => n.count_significant_digits_radix(radix),
+            None => self.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_radix_rounded_to_power()][CountDigits::count_digits_radix_rounded_to_power] on the contained value,
+    /// or returns 0.
+    fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+        match self {
+            Some(n) => n.count_digits_radix_rounded_to_power(radix),
+            None => self.count_digits_radix(radix),
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [count_decimal_digits_twos_complement()][CountDigits::count_decimal_digits_twos_complement] on the contained value, or returns 0.
+    fn count_decimal_digits_twos_complement(self) -> usize
+    where
+        Self::Radix: CountDigits,
+    {
+        self.map_or(0, CountDigits::count_decimal_digits_twos_complement)
+    }
+
+    #[inline(always)]
+    /// Calls [count_digits_of_sum()][CountDigits::count_digits_of_sum] on the contained values,
+    /// or returns 0 if either value is absent.
+    fn count_digits_of_sum(self, other: Self) -> usize {
+        match (self, other) {
+            (Some(n), Some(other)) => n.count_digits_of_sum(other),
+            _ => 0,
+        }
+    }
+
+    #[inline(always)]
+    /// Calls [palindromic_rotation_count_radix()][CountDigits::palindromic_rotation_count_radix] on the contained value,
+    /// or returns 0.
+    fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+        match self {
+            Some(n) => n.palindromic_rotation_count_radix(radix),
+            None if radix >= 2.into() => 0,
+            None => panic!("base of integer logarithm must be at least 2"),
+        }
+    }
+}
+
+impl_count_digits! {
+    primitive_type = i8,
+    non_zero_type = NonZeroI8,
+    radix_type = u8,
+    min_value_bits = 8,
+    min_value_octal_digits = 3,
+    min_value_hex_digits = 2,
+}
+
+impl_count_digits! {
+    primitive_type = i16,
+    non_zero_type = NonZeroI16,
+    radix_type = u16,
+    min_value_bits = 16,
+    min_value_octal_digits = 6,
+    min_value_hex_digits = 4,
+}
+
+impl_count_digits! {
+    primitive_type = i32,
+    non_zero_type = NonZeroI32,
+    radix_type = u32,
+    min_value_bits = 32,
+    min_value_octal_digits = 11,
+    min_value_hex_digits = 8,
+}
+
+impl_count_digits! {
+    primitive_type = i64,
+    non_zero_type = NonZeroI64,
+    radix_type = u64,
+    min_value_bits = 64,
+    min_value_octal_digits = 22,
+    min_value_hex_digits = 16,
+}
+
+impl_count_digits! {
+    primitive_type = i128,
+    non_zero_type = NonZeroI128,
+    radix_type = u128,
+    min_value_bits = 128,
+    min_value_octal_digits = 43,
+    min_value_hex_digits = 32,
+}
+
+#[cfg(target_pointer_width = "64")]
+impl_count_digits! {
+    primitive_type = isize,
+    non_zero_type = NonZeroIsize,
+    radix_type = usize,
+    min_value_bits = 64,
+    min_value_octal_digits = 22,
+    min_value_hex_digits = 16,
+}
+
+#[cfg(target_pointer_width = "32")]
+impl_count_digits! {
+    primitive_type = isize,
+    non_zero_type = NonZeroIsize,
+    radix_type = usize,
+    min_value_bits = 32,
+    min_value_octal_digits = 11,
+    min_value_hex_digits = 8,
+}
+
+#[cfg(target_pointer_width = "16")]
+impl_count_digits! {
+    primitive_type = isize,
+    non_zero_type = NonZeroIsize,
+    radix_type = usize,
+    min_value_bits = 16,
+    min_value_octal_digits = 6,
+    min_value_hex_digits = 4,
+}
+
+#[cfg(target_pointer_width = "8")]
+impl_count_digits! {
+    primitive_type = isize,
+    non_zero_type = NonZeroIsize,
+    radix_type = usize,
+    min_value_bits = 8,
+    min_value_octal_digits = 3,
+    min_value_hex_digits = 2,
+}
+
+impl_count_digits! {
+    primitive_type = u8,
+    non_zero_type = NonZeroU8,
+}
+
+impl_count_digits! {
+    primitive_type = u16,
+    non_zero_type = NonZeroU16,
+}
+
+impl_count_digits! {
+    primitive_type = u32,
+    non_zero_type = NonZeroU32,
+}
+
+impl_count_digits! {
+    primitive_type = u64,
+    non_zero_type = NonZeroU64,
+}
+
+impl_count_digits! {
+    primitive_type = u128,
+    non_zero_type = NonZeroU128,
+}
+
+impl_count_digits! {
+    primitive_type = usize,
+    non_zero_type = NonZeroUsize,
+}
+
+#[cfg(feature = "ethnum")]
+/// Implementations of [CountDigits] for the 256-bit integers of the [ethnum] crate.
+///
+/// The helpers mirror the [u128] helpers above, but take 256-bit magnitudes.
+mod ethnum_impls {
+    use super::*;
+    use ethnum::{I256, U256};
+
+    /// Returns the provided radix as a [u128], or [u128::MAX] if it does not fit.
+    ///
+    /// Every radix that does not fit is rejected by the checks that compare it with a small maximum.
+    fn narrow(radix: U256) -> u128 {
+        u128::try_from(radix).unwrap_or(u128::MAX)
+    }
+
+    /// Checks that the provided radix is a power of two.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1, or is not a power of two.
+    fn assert_power_of_two_radix(radix: U256) {
+        assert!(radix >= 2, "base of integer logarithm must be at least 2");
+        assert!(radix.is_power_of_two(), "radix must be a power of two");
+    }
+
+    /// Returns the base-`radix` logarithm of a magnitude, rounded down, or [None] if the magnitude is zero.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    fn checked_ilog(magnitude: U256, radix: U256) -> Option<u32> {
+        assert!(radix >= 2, "base of integer logarithm must be at least 2");
+        if magnitude == 0 {
+            return None;
+        }
+        let radix = match u128::try_from(radix) {
+            Ok(radix) => radix,
+            // The square of a radix that does not fit in a u128 is greater than every magnitude.
+            Err(_) => return Some((magnitude >= radix) as u32),
+        };
+
+        // Dividing by the largest power of the radix that fits in a u128 removes that many digits
+        // at a time, until the remaining leading digits fit in a u128.
+        let chunk_exponent = IntegerLogarithm::ilog(u128::MAX, radix);
+        let chunk = radix.pow(chunk_exponent);
+        let mut magnitude = magnitude;
+        let mut exponent = 0;
+        while *magnitude.high() != 0 {
+            magnitude /= chunk;
+            exponent += chunk_exponent;
+        }
+        Some(exponent + IntegerLogarithm::ilog(magnitude.as_u128(), radix))
+    }
+
+    /// Returns the count of digits in a magnitude as interpreted with the given radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    fn count_digits_radix_u256(magnitude: U256, radix: U256) -> usize {
+        1 + checked_ilog(magnitude, radix).unwrap_or_default() as usize
+    }
+
+    /// Returns an iterator over the digits of a magnitude with the given radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 256.
+    fn digits_radix_le_of_magnitude(magnitude: U256, radix: U256) -> DigitsRadixLe {
+        let (high, low) = magnitude.into_words();
+        DigitsRadixLe::of_wide_magnitude(high, low, narrow(radix))
+    }
+
+    // The next four helpers repeat the u128 helpers of the same names instead of being generic over
+    // a `Magnitude`, because the u128 helpers are `const fn`s that the `const_evaluation` tests call in
+    // `const` contexts, and a `const fn` cannot call the trait functions, such as division, of a generic type.
+
+    /// Returns the count of balanced-ternary trits needed to represent the given magnitude.
+    fn count_balanced_ternary_digits_of_magnitude(mut magnitude: U256) -> u32 {
+        let mut count = 1;
+        magnitude = magnitude / 3 + (magnitude % 3 + 1) / 3;
+        while magnitude != 0 {
+            count += 1;
+            magnitude = magnitude / 3 + (magnitude % 3 + 1) / 3;
+        }
+        count
+    }
+
+    /// Returns whether the given magnitude is equal to `radix^k - 1` for some `k >= 1`.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    fn is_radix_boundary_of_magnitude(mut magnitude: U256, radix: U256) -> bool {
+        assert!(radix >= 2, "base of integer logarithm must be at least 2");
+        if magnitude == 0 {
+            return false;
+        }
+        while magnitude != 0 {
+            if magnitude % radix != radix - 1 {
+                return false;
+            }
+            magnitude /= radix;
+        }
+        true
+    }
+
+    /// Returns whether the given magnitude is zero or equal to `radix^k` for some `k >= 0`.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    fn is_radix_width_floor_of_magnitude(mut magnitude: U256, radix: U256) -> bool {
+        assert!(radix >= 2, "base of integer logarithm must be at least 2");
+        if magnitude == 0 {
+            return true;
+        }
+        while magnitude % radix == 0 {
+            magnitude /= radix;
+        }
+        magnitude == 1
+    }
+
+    /// Returns the count of digit positions that differ between two magnitudes as interpreted with the given radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    fn digit_diff_count_of_magnitudes(mut a: U256, mut b: U256, radix: U256) -> usize {
+        assert!(radix >= 2, "base of integer logarithm must be at least 2");
+        let mut count = 0;
+        while a != 0 || b != 0 {
+            if a % radix != b % radix {
+                count += 1;
+            }
+            a /= radix;
+            b /= radix;
+        }
+        count
+    }
+
+    impl Magnitude for U256 {
+        const ZERO: Self = U256::ZERO;
+
+        /// Such a sum is at least `2^256` and less than `2^257`, which are both 78-digit numbers.
+        const OVERFLOWING_SUM_DIGITS: usize = 78;
+
+        #[inline(always)]
+        fn pow(self, exp: u32) -> Self {
+            U256::pow(self, exp)
+        }
+
+        #[inline(always)]
+        fn checked_add(self, other: Self) -> Option<Self> {
+            U256::checked_add(self, other)
+        }
+
+        fn widening_mul(self, other: Self) -> (Self, Self) {
+            let low_mask = U256::from(u128::MAX);
+            let (a_high, a_low) = self.into_words();
+            let (b_high, b_low) = other.into_words();
+            let low_low = U256::from(a_low) * U256::from(b_low);
+            let low_high = U256::from(a_low) * U256::from(b_high);
+            let high_low = U256::from(a_high) * U256::from(b_low);
+            let middle = (low_low >> 128) + (low_high & low_mask) + (high_low & low_mask);
+            let high = U256::from(a_high) * U256::from(b_high)
+                + (low_high >> 128)
+                + (high_low >> 128)
+                + (middle >> 128);
+            (high, (low_low & low_mask) | (middle << 128))
+        }
+
+        #[inline(always)]
+        fn narrow(self) -> u128 {
+            narrow(self)
+        }
+    }
+
+    impl CountDigits for U256 {
+        type Radix = U256;
+
+        #[inline(always)]
+        /// Returns the count of bits in an integer.
+        fn count_bits(self) -> u32 {
+            // Setting the lowest bit counts zero as a single bit without a branch.
+            U256::BITS - (self | 1).leading_zeros()
+        }
+
+        #[inline(always)]
+        /// Returns the count of octal digits in an integer.
+        fn count_octal_digits(self) -> u32 {
+            1 + (self.count_bits() - 1) / 3
+        }
+
+        #[inline(always)]
+        /// Returns the count of hexadecimal digits in an integer.
+        fn count_hex_digits(self) -> u32 {
+            1 + (self.count_bits() - 1) / 4
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in an integer.
+        fn count_digits(self) -> usize {
+            match self.into_words() {
+                (0, low) => low.count_digits(),
+                _ => count_digits_radix_u256(self, U256::new(10)),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        ///
+        /// [Panics](panic) if the provided radix is 0 or 1.
+        ///
+        /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
+        fn count_digits_radix(self, radix: Self::Radix) -> usize {
+            match narrow(radix) {
+                0 | 1 => panic!("base of integer logarithm must be at least 2"),
+                02 => self.count_bits() as usize,
+                08 => self.count_octal_digits() as usize,
+                10 => self.count_digits(),
+                16 => self.count_hex_digits() as usize,
+                narrowed => match u32::try_from(narrowed).ok().and_then(radix_log2) {
+                    Some(k) => 1 + (self.count_bits() - 1) as usize / k as usize,
+                    None => count_digits_radix_u256(self, radix),
+                },
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        ///
+        /// Returns [None] if the provided radix is 0 or 1.
+        ///
+        /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
+        fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+            match radix < 2 {
+                true => None,
+                false => Some(self.count_digits_radix(radix)),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of trits in the balanced ternary representation of an integer.
+        fn count_balanced_ternary_digits(self) -> u32 {
+            count_balanced_ternary_digits_of_magnitude(self)
+        }
+
+        #[inline(always)]
+        /// Returns the bits of an integer reinterpreted as the corresponding unsigned type.
+        fn bit_pattern_as_unsigned(self) -> Self::Radix {
+            self
+        }
+
+        #[inline(always)]
+        /// Returns the count of bits in the bitwise complement of an integer.
+        fn count_bits_of_complement(self) -> u32 {
+            (!self).count_bits()
+        }
+
+        #[inline(always)]
+        /// Returns the minimum count of bits that can hold an integer in twos-complement representation.
+        fn min_twos_complement_bits(self) -> u32 {
+            U256::BITS - self.leading_zeros() + 1
+        }
+
+        #[inline(always)]
+        /// Returns an iterator over the count of decimal digits of each byte of an integer's
+        /// little-endian representation.
+        fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+            PerByteDecimalWidths::of_le_bytes(&self.to_le_bytes())
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in the bitwise complement of an integer as interpreted with the given
+        /// [radix](https://en.wikipedia.org/wiki/Radix).
+        fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+            (!self).count_digits_radix(radix)
+        }
+
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        /// Returns the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// without any prefix.
+        fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+            to_string_radix_of_magnitude(false, self, radix, uppercase)
+        }
+
+        #[inline(always)]
+        /// Writes the ASCII digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+        /// to the start of the buffer, without any prefix.
+        fn write_ascii_digits_radix(
+            self,
+            radix: Self::Radix,
+            uppercase: bool,
+            buf: &mut [u8],
+        ) -> Result<usize, usize> {
+            write_ascii_digits_of_magnitude(false, self, radix, uppercase, buf)
+        }
+
+        #[inline(always)]
+        /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// from the least-significant digit to the most-significant digit.
+        fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+            digits_radix_le_of_magnitude(self, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the largest value of a single digit in the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn max_digit_value(radix: Self::Radix) -> u8 {
+            max_digit_value_of_radix(narrow(radix))
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in an integer, counting zero as having no digits.
+        fn count_digits_nonempty(self) -> usize {
+            match self {
+                U256::ZERO => 0,
+                _ => self.count_digits(),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// counting zero as having no digits.
+        fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+            match self {
+                U256::ZERO if radix >= 2 => 0,
+                _ => self.count_digits_radix(radix),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns whether an integer is the largest value with its count of digits
+        /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+            is_radix_boundary_of_magnitude(self, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// along with its most-significant digit.
+        fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+            count_and_msd_of_magnitude(self, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// along with whether the integer is an exact power of the radix.
+        fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+            count_and_is_power_of_magnitude(self, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in the absolute difference between two integers.
+        fn count_digits_of_abs_diff(self, other: Self) -> usize {
+            self.abs_diff(other).count_digits()
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in the absolute difference between two integers
+        /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+            self.abs_diff(other).count_digits_radix(radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in the magnitude of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+            self.count_digits_radix(radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// after checking that it fits in the given bit width.
+        fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+            assert!(
+                0 < bit_width && bit_width <= U256::BITS,
+                "bit width must be between 1 and the width of the type"
+            );
+            assert!(
+                self.checked_shr(bit_width).unwrap_or_default() == 0,
+                "value does not fit in the given bit width"
+            );
+            self.count_digits_radix(radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given power-of-two
+        /// [radix](https://en.wikipedia.org/wiki/Radix), where negative integers are represented in ones' complement.
+        fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+            assert_power_of_two_radix(radix);
+            self.count_digits_radix(radix)
+        }
+
+        #[inline(always)]
+        /// Returns the width of an integer formatted in decimal with a separator between each group
+        /// of `group_size` digits, including the negative sign of a negative integer.
+        fn grouped_width_with_sign(self, group_size: u32) -> usize {
+            self.count_digits() + self.count_separators_pattern(&[group_size])
+        }
+
+        #[inline(always)]
+        /// Returns the count of characters in the [Debug](core::fmt::Debug) output of an integer.
+        fn debug_width(self) -> usize {
+            self.count_digits()
+        }
+
+        #[inline(always)]
+        /// Returns whether an integer is the smallest value with its count of digits
+        /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+            is_radix_width_floor_of_magnitude(self, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digit positions that differ between two integers as interpreted
+        /// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+            digit_diff_count_of_magnitudes(self, other, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of leading digits that two integers share as interpreted with the given
+        /// [radix](https://en.wikipedia.org/wiki/Radix).
+        fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+            common_prefix_len_of_magnitudes(self, other, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// excluding its trailing zeros.
+        fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+            count_significant_digits_of_magnitude(self, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// after rounding its magnitude to the nearest power of the radix.
+        fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+            count_digits_rounded_to_power_of_magnitude(self, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in an integer, excluding its trailing zeros.
+        fn count_significant_digits(self) -> usize {
+            count_significant_digits_of_magnitude(self, U256::new(10))
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in the sum of two integers, even if the sum overflows the type.
+        fn count_digits_of_sum(self, other: Self) -> usize {
+            count_digits_of_magnitude_sum(self, other)
+        }
+
+        #[inline(always)]
+        /// Returns the count of rotations of the digits of an integer, as interpreted with the given
+        /// [radix](https://en.wikipedia.org/wiki/Radix), that are palindromes.
+        fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+            palindromic_rotation_count_of_magnitude(self, radix)
+        }
+    }
+
+    impl CountDigits for I256 {
+        type Radix = U256;
+
+        #[inline(always)]
+        /// Returns the count of bits in an integer.
+        fn count_bits(self) -> u32 {
+            // Negative integers have no leading zeros, so they count all of the bits.
+            // Setting the lowest bit counts zero as a single bit without a branch.
+            I256::BITS - (self | 1).leading_zeros()
+        }
+
+        #[inline(always)]
+        /// Returns the count of octal digits in an integer.
+        fn count_octal_digits(self) -> u32 {
+            self.as_u256().count_octal_digits()
+        }
+
+        #[inline(always)]
+        /// Returns the count of hexadecimal digits in an integer.
+        fn count_hex_digits(self) -> u32 {
+            self.as_u256().count_hex_digits()
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in an integer.
+        fn count_digits(self) -> usize {
+            self.unsigned_abs().count_digits()
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        ///
+        /// [Panics](panic) if the provided radix is 0 or 1.
+        ///
+        /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
+        fn count_digits_radix(self, radix: Self::Radix) -> usize {
+            match radix == 10 {
+                true => self.count_digits(),
+                false => self.as_u256().count_digits_radix(radix),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        ///
+        /// Returns [None] if the provided radix is 0 or 1.
+        ///
+        /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
+        fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
+            match radix < 2 {
+                true => None,
+                false => Some(self.count_digits_radix(radix)),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of trits in the balanced ternary representation of an integer.
+        fn count_balanced_ternary_digits(self) -> u32 {
+            count_balanced_ternary_digits_of_magnitude(self.unsigned_abs())
+        }
+
+        #[inline(always)]
+        /// Returns the bits of an integer reinterpreted as the corresponding unsigned type.
+        fn bit_pattern_as_unsigned(self) -> Self::Radix {
+            self.as_u256()
+        }
+
+        #[inline(always)]
+        /// Returns the count of bits in the bitwise complement of an integer.
+        fn count_bits_of_complement(self) -> u32 {
+            (!self).count_bits()
+        }
+
+        #[inline(always)]
+        /// Returns the minimum count of bits that can hold an integer in twos-complement representation.
+        fn min_twos_complement_bits(self) -> u32 {
+            let magnitude = if self < 0 { !self } else { self };
+            I256::BITS - magnitude.leading_zeros() + 1
+        }
+
+        #[inline(always)]
+        /// Returns an iterator over the count of decimal digits of each byte of an integer's
+        /// little-endian representation.
+        fn per_byte_decimal_widths(self) -> PerByteDecimalWidths {
+            self.bit_pattern_as_unsigned().per_byte_decimal_widths()
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in the bitwise complement of an integer as interpreted with the given
+        /// [radix](https://en.wikipedia.org/wiki/Radix).
+        fn count_digits_radix_of_complement(self, radix: Self::Radix) -> usize {
+            (!self).count_digits_radix(radix)
+        }
+
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        /// Returns the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// without any prefix.
+        fn to_string_radix(self, radix: Self::Radix, uppercase: bool) -> String {
+            if radix == 10 && self.is_negative() {
+                to_string_radix_of_magnitude(true, self.unsigned_abs(), radix, uppercase)
+            } else {
+                to_string_radix_of_magnitude(false, self.as_u256(), radix, uppercase)
+            }
+        }
+
+        #[inline(always)]
+        /// Writes the ASCII digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix)
+        /// to the start of the buffer, without any prefix.
+        fn write_ascii_digits_radix(
+            self,
+            radix: Self::Radix,
+            uppercase: bool,
+            buf: &mut [u8],
+        ) -> Result<usize, usize> {
+            if radix == 10 && self.is_negative() {
+                write_ascii_digits_of_magnitude(true, self.unsigned_abs(), radix, uppercase, buf)
+            } else {
+                write_ascii_digits_of_magnitude(false, self.as_u256(), radix, uppercase, buf)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns an iterator over the digits of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// from the least-significant digit to the most-significant digit.
+        fn digits_radix_le(self, radix: Self::Radix) -> DigitsRadixLe {
+            if radix == 10 && self.is_negative() {
+                digits_radix_le_of_magnitude(self.unsigned_abs(), radix)
+            } else {
+                digits_radix_le_of_magnitude(self.as_u256(), radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the largest value of a single digit in the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn max_digit_value(radix: Self::Radix) -> u8 {
+            max_digit_value_of_radix(narrow(radix))
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in an integer, counting zero as having no digits.
+        fn count_digits_nonempty(self) -> usize {
+            match self {
+                I256::ZERO => 0,
+                _ => self.count_digits(),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// counting zero as having no digits.
+        fn count_digits_radix_nonempty(self, radix: Self::Radix) -> usize {
+            match self {
+                I256::ZERO if radix >= 2 => 0,
+                _ => self.count_digits_radix(radix),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns whether an integer is the largest value with its count of digits
+        /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn is_radix_boundary(self, radix: Self::Radix) -> bool {
+            let magnitude = if self.is_negative() {
+                U256::ZERO
+            } else {
+                self.as_u256()
+            };
+            is_radix_boundary_of_magnitude(magnitude, radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// along with its most-significant digit.
+        fn count_and_msd_radix(self, radix: Self::Radix) -> (usize, u8) {
+            if radix == 10 {
+                count_and_msd_of_magnitude(self.unsigned_abs(), radix)
+            } else {
+                count_and_msd_of_magnitude(self.as_u256(), radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// along with whether the integer is an exact power of the radix.
+        fn count_and_is_power_radix(self, radix: Self::Radix) -> (usize, bool) {
+            if radix == 10 && self.is_negative() {
+                count_and_is_power_of_magnitude(self.unsigned_abs(), radix)
+            } else {
+                count_and_is_power_of_magnitude(self.as_u256(), radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in the absolute difference between two integers.
+        fn count_digits_of_abs_diff(self, other: Self) -> usize {
+            self.abs_diff(other).count_digits()
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in the absolute difference between two integers
+        /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn count_digits_of_abs_diff_radix(self, other: Self, radix: Self::Radix) -> usize {
+            self.abs_diff(other).count_digits_radix(radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in the magnitude of an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn count_digits_radix_abs(self, radix: Self::Radix) -> usize {
+            self.unsigned_abs().count_digits_radix(radix)
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// where negative integers are represented in twos complement with the given bit width.
+        fn count_digits_radix_width(self, radix: Self::Radix, bit_width: u32) -> usize {
+            assert!(
+                0 < bit_width && bit_width <= I256::BITS,
+                "bit width must be between 1 and the width of the type"
+            );
+            let sign_extension = self >> (bit_width - 1);
+            assert!(
+                sign_extension == 0 || sign_extension == -1,
+                "value does not fit in the given bit width"
+            );
+            if radix == 10 {
+                self.count_digits()
+            } else {
+                let pattern = self.as_u256() & (U256::MAX >> (U256::BITS - bit_width));
+                pattern.count_digits_radix(radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given power-of-two
+        /// [radix](https://en.wikipedia.org/wiki/Radix), where negative integers are represented in ones' complement.
+        fn count_digits_radix_ones_complement(self, radix: Self::Radix) -> usize {
+            let pattern = match self.is_negative() {
+                true => !self.unsigned_abs(),
+                false => self.as_u256(),
+            };
+            pattern.count_digits_radix_ones_complement(radix)
+        }
+
+        #[inline(always)]
+        /// Returns the width of an integer formatted in decimal with a separator between each group
+        /// of `group_size` digits, including the negative sign of a negative integer.
+        fn grouped_width_with_sign(self, group_size: u32) -> usize {
+            let separators = self.count_separators_pattern(&[group_size]);
+            self.count_digits() + separators + self.is_negative() as usize
+        }
+
+        #[inline(always)]
+        /// Returns the count of characters in the [Debug](core::fmt::Debug) output of an integer.
+        fn debug_width(self) -> usize {
+            self.count_digits() + self.is_negative() as usize
+        }
+
+        #[inline(always)]
+        /// Returns whether an integer is the smallest value with its count of digits
+        /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn is_radix_width_floor(self, radix: Self::Radix) -> bool {
+            let is_floor = is_radix_width_floor_of_magnitude(self.unsigned_abs(), radix);
+            is_floor && !self.is_negative()
+        }
+
+        #[inline(always)]
+        /// Returns the count of digit positions that differ between two integers as interpreted
+        /// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+        fn digit_diff_count_radix(self, other: Self, radix: Self::Radix) -> usize {
+            if radix == 10 {
+                digit_diff_count_of_magnitudes(self.unsigned_abs(), other.unsigned_abs(), radix)
+            } else {
+                digit_diff_count_of_magnitudes(self.as_u256(), other.as_u256(), radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of leading digits that two integers share as interpreted with the given
+        /// [radix](https://en.wikipedia.org/wiki/Radix).
+        fn common_prefix_len_radix(self, other: Self, radix: Self::Radix) -> usize {
+            if radix == 10 {
+                common_prefix_len_of_magnitudes(self.unsigned_abs(), other.unsigned_abs(), radix)
+            } else {
+                common_prefix_len_of_magnitudes(self.as_u256(), other.as_u256(), radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// excluding its trailing zeros.
+        fn count_significant_digits_radix(self, radix: Self::Radix) -> usize {
+            if radix == 10 {
+                count_significant_digits_of_magnitude(self.unsigned_abs(), radix)
+            } else {
+                count_significant_digits_of_magnitude(self.as_u256(), radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix),
+        /// after rounding its magnitude to the nearest power of the radix.
+        fn count_digits_radix_rounded_to_power(self, radix: Self::Radix) -> usize {
+            if radix == 10 {
+                count_digits_rounded_to_power_of_magnitude(self.unsigned_abs(), radix)
+            } else {
+                count_digits_rounded_to_power_of_magnitude(self.as_u256(), radix)
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in an integer, excluding its trailing zeros.
+        fn count_significant_digits(self) -> usize {
+            count_significant_digits_of_magnitude(self.unsigned_abs(), U256::new(10))
+        }
+
+        #[inline(always)]
+        /// Returns the count of decimal digits in the sum of two integers, even if the sum overflows the type.
+        fn count_digits_of_sum(self, other: Self) -> usize {
+            match self.checked_add(other) {
+                Some(sum) => sum.count_digits(),
+                // The sum only overflows when both integers have the same sign.
+                None => count_digits_of_magnitude_sum(self.unsigned_abs(), other.unsigned_abs()),
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the count of rotations of the digits of an integer, as interpreted with the given
+        /// [radix](https://en.wikipedia.org/wiki/Radix), that are palindromes.
+        fn palindromic_rotation_count_radix(self, radix: Self::Radix) -> u32 {
+            if radix == 10 && self.is_negative() {
+                palindromic_rotation_count_of_magnitude(self.unsigned_abs(), radix)
+            } else {
+                palindromic_rotation_count_of_magnitude(self.as_u256(), radix)
+            }
+        }
+    }
+}
+
+#[inline(always)]
+/// Returns the count of bits in an integer.
+///
+/// Calls [count_bits()](CountDigits::count_bits) without requiring the [CountDigits] trait to be in scope.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::num::NonZeroU8;
+/// assert_eq!(4, count_digits::bits(0b1011_u8));
+/// assert_eq!(4, count_digits::bits(&0b1011_u8));
+/// assert_eq!(4, count_digits::bits(NonZeroU8::new(0b1011).unwrap()));
+/// assert_eq!(8, count_digits::bits(-0b1011_i8));
+/// ```
+pub fn bits<T: CountDigits>(n: T) -> u32 {
+    n.count_bits()
+}
+
+#[inline(always)]
+/// Returns the count of octal digits in an integer.
+///
+/// Calls [count_octal_digits()](CountDigits::count_octal_digits) without requiring the [CountDigits] trait to be in scope.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::num::NonZeroU16;
+/// assert_eq!(4, count_digits::octal(0o1777_u16));
+/// assert_eq!(4, count_digits::octal(&0o1777_u16));
+/// assert_eq!(4, count_digits::octal(NonZeroU16::new(0o1777).unwrap()));
+/// assert_eq!(6, count_digits::octal(-0o1777_i16));
+/// ```
+pub fn octal<T: CountDigits>(n: T) -> u32 {
+    n.count_octal_digits()
+}
+
+#[inline(always)]
+/// Returns the count of decimal digits in an integer.
+///
+/// Calls [count_digits()](CountDigits::count_digits) without requiring the [CountDigits] trait to be in scope.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::num::NonZeroI32;
+/// assert_eq!(5, count_digits::decimal(61453_i32));
+/// assert_eq!(5, count_digits::decimal(&61453_i32));
+/// assert_eq!(5, count_digits::decimal(NonZeroI32::new(61453).unwrap()));
+/// assert_eq!(5, count_digits::decimal(-61453_i32));
+/// ```
+pub fn decimal<T: CountDigits>(n: T) -> usize {
+    n.count_digits()
+}
+
+#[inline(always)]
+/// Returns the count of hexadecimal digits in an integer.
+///
+/// Calls [count_hex_digits()](CountDigits::count_hex_digits) without requiring the [CountDigits] trait to be in scope.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::num::NonZeroU32;
+/// assert_eq!(4, count_digits::hex(0xF00D_u32));
+/// assert_eq!(4, count_digits::hex(&0xF00D_u32));
+/// assert_eq!(4, count_digits::hex(NonZeroU32::new(0xF00D).unwrap()));
+/// assert_eq!(8, count_digits::hex(-0xF00D_i32));
+/// ```
+pub fn hex<T: CountDigits>(n: T) -> u32 {
+    n.count_hex_digits()
+}
+
+#[inline(always)]
+/// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+///
+/// Calls [count_digits_radix()](CountDigits::count_digits_radix) without requiring the [CountDigits] trait to be in scope.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::num::NonZeroU64;
+/// assert_eq!(3, count_digits::radix(100_u64, 7));
+/// assert_eq!(3, count_digits::radix(&100_u64, 7));
+/// assert_eq!(3, count_digits::radix(NonZeroU64::new(100).unwrap(), 7));
+/// assert!(std::panic::catch_unwind(|| count_digits::radix(100_u64, 1)).is_err());
+/// ```
+pub fn radix<T: CountDigits>(n: T, radix: T::Radix) -> usize {
+    n.count_digits_radix(radix)
+}
+
+#[inline(always)]
+/// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// Returns [None] if the provided radix is 0 or 1.
+///
+/// Calls [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) without requiring the [CountDigits] trait to be in scope.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::num::NonZeroU64;
+/// assert_eq!(Some(3), count_digits::checked_radix(100_u64, 7));
+/// assert_eq!(Some(3), count_digits::checked_radix(&100_u64, 7));
+/// assert_eq!(Some(3), count_digits::checked_radix(NonZeroU64::new(100).unwrap(), 7));
+/// assert_eq!(None, count_digits::checked_radix(100_u64, 1));
+/// ```
+pub fn checked_radix<T: CountDigits>(n: T, radix: T::Radix) -> Option<usize> {
+    n.checked_count_digits_radix(radix)
+}
+
+#[inline(always)]
+/// Returns the smallest value that has the given count of digits
+/// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// This is `radix^(digits - 1)`, except that zero is returned for one digit and for zero digits.
+///
+/// [Panics](panic) if the provided radix is 0 or 1, or if no [u128] has the given count of digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::{min_value_with_digits_radix, CountDigits};
+///
+/// assert_eq!(0, min_value_with_digits_radix(0, 10));
+/// assert_eq!(0, min_value_with_digits_radix(1, 10));
+/// assert_eq!(100, min_value_with_digits_radix(3, 10));
+/// assert_eq!(0x1000, min_value_with_digits_radix(4, 16));
+///
+/// assert_eq!(3, min_value_with_digits_radix(3, 10).count_digits());
+/// ```
+pub fn min_value_with_digits_radix(digits: u32, radix: u32) -> u128 {
+    assert!(radix >= 2, "base of integer logarithm must be at least 2");
+    match digits {
+        0 | 1 => 0,
+        _ => (radix as u128)
+            .checked_pow(digits - 1)
+            .expect("no u128 value has the given count of digits"),
+    }
+}
+
+#[inline(always)]
+/// Returns the largest value that has the given count of digits
+/// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// This is `radix^digits - 1`, which is zero for zero digits. Returns [u128::MAX]
+/// if `radix^digits` does not fit in a [u128].
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::{max_value_with_digits_radix, CountDigits};
+///
+/// assert_eq!(0, max_value_with_digits_radix(0, 10));
+/// assert_eq!(9, max_value_with_digits_radix(1, 10));
+/// assert_eq!(999, max_value_with_digits_radix(3, 10));
+/// assert_eq!(0xFFFF, max_value_with_digits_radix(4, 16));
+/// assert_eq!(u128::MAX, max_value_with_digits_radix(128, 2));
+/// assert_eq!(u128::MAX, max_value_with_digits_radix(200, 10));
+///
+/// assert_eq!(3, max_value_with_digits_radix(3, 10).count_digits());
+/// ```
+pub fn max_value_with_digits_radix(digits: u32, radix: u32) -> u128 {
+    assert!(radix >= 2, "base of integer logarithm must be at least 2");
+    (radix as u128)
+        .checked_pow(digits)
+        .map_or(u128::MAX, |power| power - 1)
+}
+
+#[inline(always)]
+/// Returns the count of digits needed to print every index of `count` items
+/// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// This is the count of digits in the largest index, `count - 1`, so indices `0..count`
+/// can be printed with the same width. Zero items and one item both need one digit.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::index_width_radix;
+///
+/// assert_eq!(1, index_width_radix(0, 10));
+/// assert_eq!(1, index_width_radix(10, 10));
+/// assert_eq!(2, index_width_radix(11, 10));
+/// assert_eq!(2, index_width_radix(100, 10));
+/// assert_eq!(2, index_width_radix(256, 16));
+/// ```
+pub fn index_width_radix(count: u128, radix: u32) -> usize {
+    count_digits_radix_u128(count.saturating_sub(1), radix as u128)
+}
+
+/// Returns the integer square root of a [u128], which is the largest integer whose square is at most `n`.
+///
+/// The root is computed one bit at a time, from the most-significant bit of the result.
+const fn isqrt_u128(n: u128) -> u128 {
+    let mut root = 0_u128;
+    let mut bit = 1_u128 << 63;
+    while bit != 0 {
+        let candidate = root | bit;
+        if candidate * candidate <= n {
+            root = candidate;
+        }
+        bit >>= 1;
+    }
+    root
+}
+
+#[inline(always)]
+/// Returns the count of decimal digits in the geometric midpoint of the range `[a, b]`,
+/// which is the integer square root of `a * b`.
+///
+/// The product is computed as a [u128], so it never overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::count_digits_geomean;
+///
+/// assert_eq!(1, count_digits_geomean(1, 1));
+/// assert_eq!(2, count_digits_geomean(1, 100));
+/// assert_eq!(3, count_digits_geomean(10, 10_000));
+/// assert_eq!(20, count_digits_geomean(u64::MAX, u64::MAX));
+/// ```
+pub fn count_digits_geomean(a: u64, b: u64) -> usize {
+    isqrt_u128(a as u128 * b as u128).count_digits()
+}
+
+/// Returns the count of decimal digits in the product of a slice of integers, without overflowing.
+///
+/// The product is computed exactly while it fits in a [u128], so the count is exact for every product
+/// that can be computed directly. Beyond that, the product is tracked as a mantissa of at most 64 bits
+/// and a power of ten, rounding the mantissa up each time that it is shortened. The rounding keeps exact
+/// powers of ten exact, but the count may be one too large for a product that lies within a relative
+/// `len * 10^-18` below a power of ten. An empty slice has the product 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::count_digits_of_product;
+///
+/// assert_eq!(1, count_digits_of_product(&[]));
+/// assert_eq!(1, count_digits_of_product(&[u64::MAX, 0]));
+/// assert_eq!(4, count_digits_of_product(&[10, 10, 10]));
+/// assert_eq!(39, count_digits_of_product(&[u64::MAX, u64::MAX]));
+///
+/// // 20! has 19 digits, and 100! has 158 digits.
+/// assert_eq!(19, count_digits_of_product(&(1..=20).collect::<Vec<_>>()));
+/// assert_eq!(158, count_digits_of_product(&(1..=100).collect::<Vec<_>>()));
+/// ```
+pub fn count_digits_of_product(slice: &[u64]) -> u128 {
+    if slice.contains(&0) {
+        return 1;
+    }
+
+    let mut mantissa = 1_u128;
+    let mut exponent = 0_u128;
+    for &n in slice {
+        mantissa = match mantissa.checked_mul(n as u128) {
+            Some(product) => product,
+            None => {
+                while mantissa > u64::MAX as u128 {
+                    mantissa = mantissa / 10 + (mantissa % 10 != 0) as u128;
+                    exponent += 1;
+                }
+                mantissa * n as u128
+            }
+        };
+    }
+    mantissa.count_digits() as u128 + exponent
+}
+
+/// Returns the largest count of digits of any integer that is `bits` wide
+/// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// This is the count of digits in `2^bits - 1`, the largest bit pattern of the width, which is
+/// `ceil(bits / log2(radix))` for widths of at least one bit. Every integer of a type whose
+/// [BITS](u32::BITS) is `bits` has at most this many digits in the radix, so it can size a buffer
+/// at compile time. Widths greater than 128 bits return `ceil(bits / floor(log2(radix)))`,
+/// which is still an upper bound, though not always the least one.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::{max_digits_radix, CountDigits};
+///
+/// const WIDTH: usize = max_digits_radix(u32::BITS, 7);
+/// let buf = [0_u8; WIDTH];
+///
+/// assert_eq!(WIDTH, u32::MAX.count_digits_radix(7));
+/// assert_eq!(39, max_digits_radix(u128::BITS, 10));
+/// assert_eq!(3, max_digits_radix(i8::BITS, 10));
+/// assert_eq!(1, max_digits_radix(0, 10));
+/// ```
+pub const fn max_digits_radix(bits: u32, radix: u32) -> usize {
+    if radix < 2 {
+        panic!("base of integer logarithm must be at least 2");
+    }
+    if let Some(digit_bits) = radix_log2(radix) {
+        return match bits {
+            0 => 1,
+            _ => ((bits + digit_bits - 1) / digit_bits) as usize,
+        };
+    }
+    if bits > u128::BITS {
+        // The integer logarithm is not available as a const fn on every supported compiler.
+        let digit_bits = u32::BITS - 1 - radix.leading_zeros();
+        return ((bits + digit_bits - 1) / digit_bits) as usize;
+    }
+
+    let radix = radix as u128;
+    let mut max = match bits {
+        0 => 0,
+        _ => u128::MAX >> (u128::BITS - bits),
+    };
+    let mut count = 1;
+    while max >= radix {
+        max /= radix;
+        count += 1;
+    }
+    count
+}
+
+#[inline(always)]
+/// Returns the total count of decimal digits in a list of integers joined by a separator
+/// of `sep_len` characters.
+///
+/// This is the sum of [count_digits()](CountDigits::count_digits) over every integer, plus `sep_len`
+/// for each separator between two integers. An empty list has a width of 0, and a list with a single
+/// integer has no separators.
+///
+/// <div class="warning" style="text-align: left;">
+/// The negative sign is not a digit, so it is not included in the width.
+/// </div>
+///
+/// # Examples
+///
+/// ```rust
+/// let numbers = [1, 22, 333];
+/// let joined = numbers.map(|n| n.to_string()).join(", ");
+///
+/// assert_eq!(joined.len(), count_digits::joined_width(numbers, 2));
+/// assert_eq!(0, count_digits::joined_width(Vec::<u32>::new(), 2));
+/// assert_eq!(3, count_digits::joined_width([333], 2));
+/// ```
+pub fn joined_width<I, T>(iter: I, sep_len: usize) -> usize
+where
+    I: IntoIterator<Item = T>,
+    T: CountDigits,
+{
+    let (count, digits) = iter.into_iter().fold((0_usize, 0), |(count, digits), n| {
+        (count + 1, digits + n.count_digits())
+    });
+    digits + sep_len * count.saturating_sub(1)
+}
+
+/// An iterator over the digits of an integer, from the least-significant digit to the most-significant digit.
+///
+/// The order is guaranteed, so reversing the digits gives the digits in the order they are written.
+/// Zero yields exactly one `0` digit, rather than no digits. Negative, signed integers yield the digits of their
+/// magnitude in radix 10, and of their [twos-complement](https://en.wikipedia.org/wiki/Two%27s_complement)
+/// representation in every other radix, as with [count_digits_radix()](CountDigits::count_digits_radix).
+///
+/// Returned by [digits_radix_le()](CountDigits::digits_radix_le).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DigitsRadixLe {
+    high: u128,
+    low: u128,
+    radix: u128,
+    done: bool,
+}
+
+impl DigitsRadixLe {
+    #[inline(always)]
+    /// Returns an iterator over the digits of a magnitude with the given radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 256.
+    fn of_magnitude(magnitude: u128, radix: u128) -> Self {
+        assert!(radix >= 2, "base of integer logarithm must be at least 2");
+        assert!(
+            radix <= 256,
+            "radix must be at most 256 to yield digits as u8"
+        );
+        Self {
+            high: 0,
+            low: magnitude,
+            radix,
+            done: false,
+        }
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[inline(always)]
+    /// Returns an iterator over the digits of a 256-bit magnitude, given as its high and low halves,
+    /// with the given radix.
+    ///
+    /// [Panics](panic) if the provided radix is 0, 1, or greater than 256.
+    fn of_wide_magnitude(high: u128, low: u128, radix: u128) -> Self {
+        Self {
+            high,
+            ..Self::of_magnitude(low, radix)
+        }
+    }
+
+    #[inline(always)]
+    /// Returns an iterator that yields no digits.
+    fn empty() -> Self {
+        Self {
+            high: 0,
+            low: 0,
+            radix: 2,
+            done: true,
+        }
+    }
+}
+
+impl Iterator for DigitsRadixLe {
+    type Item = u8;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<u8> {
+        if self.done {
+            return None;
+        }
+        let digit = if self.high == 0 {
+            let digit = self.low % self.radix;
+            self.low /= self.radix;
+            digit
+        } else {
+            // Long division of the 256-bit magnitude, 64 bits at a time. Each remainder is
+            // less than the radix, so shifting it above the next 64 bits cannot overflow.
+            let remainder = self.high % self.radix;
+            self.high /= self.radix;
+            let upper = (remainder << 64) | (self.low >> 64);
+            let lower = ((upper % self.radix) << 64) | (self.low & u64::MAX as u128);
+            self.low = ((upper / self.radix) << 64) | (lower / self.radix);
+            lower % self.radix
+        };
+        self.done = self.high == 0 && self.low == 0;
+        Some(digit as u8)
+    }
+}
+
+impl core::iter::FusedIterator for DigitsRadixLe {}
+
+/// An iterator over the digits of an integer, from the most-significant digit to the least-significant digit,
+/// paired with the positional weight of each digit.
+///
+/// The order is guaranteed to be the order in which the digits are written, which is the reverse of [DigitsRadixLe].
+/// Zero yields exactly one `(0, 1)` pair, and negative, signed integers are represented as with [DigitsRadixLe].
+///
+/// Returned by [weighted_digits_radix()](CountDigits::weighted_digits_radix).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WeightedDigitsRadix {
+    magnitude: u128,
+    weight: u128,
+    radix: u128,
+    remaining: usize,
+}
+
+impl WeightedDigitsRadix {
+    #[inline(always)]
+    /// Returns an iterator over the same digits as the given iterator, in the opposite order, with their weights.
+    ///
+    /// [Panics](panic) if the magnitude does not fit in a [u128].
+    fn of_digits(digits: DigitsRadixLe) -> Self {
+        assert!(
+            digits.high == 0,
+            "magnitude must fit in u128 for its digit weights to fit in u128"
+        );
+        let remaining = if digits.done {
+            0
+        } else {
+            count_digits_radix_u128(digits.low, digits.radix)
+        };
+        Self {
+            magnitude: digits.low,
+            weight: digits.radix.pow(remaining.saturating_sub(1) as u32),
+            radix: digits.radix,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for WeightedDigitsRadix {
+    type Item = (u8, u128);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<(u8, u128)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let weight = self.weight;
+        let digit = self.magnitude / weight;
+        self.magnitude %= weight;
+        self.weight /= self.radix;
+        self.remaining -= 1;
+        Some((digit as u8, weight))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for WeightedDigitsRadix {}
+
+impl core::iter::FusedIterator for WeightedDigitsRadix {}
+
+/// An iterator over the count of decimal digits of each byte of an integer's little-endian representation.
+///
+/// Returned by [per_byte_decimal_widths()](CountDigits::per_byte_decimal_widths).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PerByteDecimalWidths {
+    bytes: [u8; 32],
+    range: core::ops::Range<usize>,
+}
+
+impl PerByteDecimalWidths {
+    #[inline(always)]
+    /// Returns an iterator over the widths of the given bytes, which are at most 32.
+    fn of_le_bytes(le_bytes: &[u8]) -> Self {
+        let mut bytes = [0; 32];
+        bytes[..le_bytes.len()].copy_from_slice(le_bytes);
+        Self {
+            bytes,
+            range: 0..le_bytes.len(),
+        }
+    }
+}
+
+impl Iterator for PerByteDecimalWidths {
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<usize> {
+        self.range
+            .next()
+            .map(|index| self.bytes[index].count_digits())
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for PerByteDecimalWidths {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<usize> {
+        self.range
+            .next_back()
+            .map(|index| self.bytes[index].count_digits())
+    }
+}
+
+impl ExactSizeIterator for PerByteDecimalWidths {}
+
+impl core::iter::FusedIterator for PerByteDecimalWidths {}
+
+/// Counts digits with a [radix](https://en.wikipedia.org/wiki/Radix) that is validated once, upon construction.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::{CountDigits, DigitCounter};
+///
+/// let counter = DigitCounter::new(7_u32).unwrap();
+///
+/// assert_eq!(1, counter.count(6_u32));
+/// assert_eq!(2, counter.count(7_u32));
+/// assert_eq!(2, counter.count(&48_u32));
+/// assert_eq!(3, counter.count(49_i32));
+///
+/// for n in 0..1000_u32 {
+///     assert_eq!(counter.count(n), n.count_digits_radix(7));
+/// }
+///
+/// assert!(DigitCounter::new(0_u32).is_none());
+/// assert!(DigitCounter::new(1_u32).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitCounter<R> {
+    radix: R,
+}
+
+impl<R: Copy + PartialOrd + From<u8>> DigitCounter<R> {
+    #[inline(always)]
+    /// Returns a [DigitCounter] for the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// Returns [None] if the provided radix is 0 or 1.
+    pub fn new(radix: R) -> Option<Self> {
+        if radix >= R::from(2) {
+            Some(Self { radix })
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the [radix](https://en.wikipedia.org/wiki/Radix) with which this [DigitCounter] counts digits.
+    pub fn radix(&self) -> R {
+        self.radix
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with this counter's [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// See [count_digits_radix()](CountDigits::count_digits_radix).
+    pub fn count<T: CountDigits<Radix = R>>(&self, n: T) -> usize {
+        n.count_digits_radix(self.radix)
+    }
+}
+
+/// Counts digits in an encoding whose alphabet has a given count of symbols,
+/// such as the 62 symbols of base 62 or the 85 symbols of base 85.
+///
+/// The length of the alphabet is the [radix](https://en.wikipedia.org/wiki/Radix), and it is validated
+/// once, upon construction. Unlike [DigitCounter], the length is a [u32] that can count the digits of
+/// any integer type.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::{Alphabet, CountDigits};
+///
+/// let base62 = Alphabet::new(62).unwrap();
+///
+/// assert_eq!(1, base62.count(61_u8));
+/// assert_eq!(2, base62.count(62_u64));
+/// assert_eq!(11, base62.count(u64::MAX));
+/// assert_eq!(u64::MAX.count_digits_radix(62), base62.count(u64::MAX));
+///
+/// assert!(Alphabet::new(0).is_none());
+/// assert!(Alphabet::new(1).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Alphabet {
+    len: u32,
+}
+
+impl Alphabet {
+    #[inline(always)]
+    /// Returns an [Alphabet] with the given count of symbols.
+    ///
+    /// Returns [None] if the provided length is 0 or 1.
+    pub fn new(len: u32) -> Option<Self> {
+        if len >= 2 {
+            Some(Self { len })
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the count of symbols in this [Alphabet].
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer when it is encoded with this [Alphabet].
+    ///
+    /// An alphabet that is longer than every bit pattern of the integer's type encodes every
+    /// integer of that type as a single digit.
+    ///
+    /// See [count_digits_radix()](CountDigits::count_digits_radix).
+    pub fn count<T>(&self, n: T) -> usize
+    where
+        T: CountDigits,
+        T::Radix: TryFrom<u32>,
+    {
+        // The length is at least 2, so this is always Some.
+        n.dyn_count_digits_radix(self.len).unwrap_or_default()
+    }
+}
+
+#[inline(always)]
+/// Returns the length of the prefix that Rust's alternate formatting adds for the given
+/// [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// This is 2 for radix 2 (`0b`), radix 8 (`0o`), and radix 16 (`0x`), and 0 for every other radix,
+/// which has no alternate format. Adding it to [count_digits_radix()](CountDigits::count_digits_radix)
+/// gives the width of an alternate-formatted integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::{base_prefix_len, CountDigits};
+///
+/// let n = 0xF00D_u32;
+/// let width = base_prefix_len(16) + n.count_digits_radix(16);
+///
+/// assert_eq!(format!("{n:#x}").len(), width);
+/// assert_eq!(0, base_prefix_len(10));
+/// ```
+pub const fn base_prefix_len(radix: u32) -> usize {
+    match radix {
+        2 | 8 | 16 => 2,
+        _ => 0,
+    }
+}
+
+#[inline(always)]
+/// Returns `k` if the given [radix](https://en.wikipedia.org/wiki/Radix) is `2^k` for some `k >= 1`,
+/// otherwise returns [None].
+///
+/// Each digit in a power-of-two radix is exactly `k` bits, so counts in such a radix can be computed
+/// from the bit width alone, as [max_digits_radix()] does.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::radix_log2;
+///
+/// assert_eq!(Some(1), radix_log2(2));
+/// assert_eq!(Some(3), radix_log2(8));
+/// assert_eq!(Some(4), radix_log2(16));
+/// assert_eq!(None, radix_log2(1));
+/// assert_eq!(None, radix_log2(10));
+/// ```
+pub const fn radix_log2(radix: u32) -> Option<u32> {
+    if radix >= 2 && radix.is_power_of_two() {
+        Some(radix.trailing_zeros())
+    } else {
+        None
+    }
+}
+
+/// Returns the count of digits in an unsigned integer stored as big-endian bytes, as interpreted
+/// with the given [radix](https://en.wikipedia.org/wiki/Radix).
+///
+/// The integer may be wider than any primitive type. Counts in a power-of-two radix are computed
+/// from the count of significant bits; counts in every other radix divide a copy of the bytes
+/// by the largest power of the radix that fits in a [u32] until the rest fits in a [u64].
+/// An integer with no bytes is zero, which has one digit.
+///
+/// [Panics](panic) if the provided radix is 0 or 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::count_digits_radix_be_bytes;
+///
+/// assert_eq!(4, count_digits_radix_be_bytes(&[0x04, 0xD2], 10));
+/// assert_eq!(11, count_digits_radix_be_bytes(&[0x04, 0xD2], 2));
+/// assert_eq!(1, count_digits_radix_be_bytes(&[0, 0, 0], 10));
+/// assert_eq!(78, count_digits_radix_be_bytes(&[0xFF; 32], 10));
+/// assert_eq!(64, count_digits_radix_be_bytes(&[0xFF; 32], 16));
+/// ```
+pub fn count_digits_radix_be_bytes<const N: usize>(bytes: &[u8; N], radix: u32) -> usize {
+    assert!(radix >= 2, "base of integer logarithm must be at least 2");
+
+    let leading_zero_bytes = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let significant = &bytes[leading_zero_bytes..];
+    let first = match significant.first() {
+        Some(&first) => first,
+        None => return 1,
+    };
+    if let Some(digit_bits) = radix_log2(radix) {
+        let bits = 8 * significant.len() - first.leading_zeros() as usize;
+        return (bits + digit_bits as usize - 1) / digit_bits as usize;
+    }
+
+    let chunk_exponent = IntegerLogarithm::ilog(u32::MAX, radix);
+    let chunk = (radix as u64).pow(chunk_exponent);
+    let mut quotient = *bytes;
+    let mut start = leading_zero_bytes;
+    let mut count = 0;
+    while N - start > 8 {
+        // Long division of the remaining bytes by the chunk, one byte at a time.
+        let mut remainder = 0_u64;
+        for byte in &mut quotient[start..] {
+            let dividend = remainder << 8 | *byte as u64;
+            *byte = (dividend / chunk) as u8;
+            remainder = dividend % chunk;
+        }
+        count += chunk_exponent as usize;
+        while start < N && quotient[start] == 0 {
+            start += 1;
+        }
+    }
+    let rest = quotient[start..]
+        .iter()
+        .fold(0_u64, |rest, &byte| rest << 8 | byte as u64);
+    count + rest.count_digits_radix(radix as u64)
+}
+
+/// Returns the count of hexadecimal digits in an unsigned integer stored as big-endian bytes,
+/// such as for sizing the hex string of a fixed-size scalar.
+///
+/// This is two digits per byte, minus the leading zero nibbles, so the bytes are never assembled
+/// into an integer. An integer whose bytes are all zero, or that has no bytes, is zero, which has one digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::count_hex_digits_be_bytes;
+///
+/// assert_eq!(4, count_hex_digits_be_bytes(&[0xF0, 0x0D]));
+/// assert_eq!(3, count_hex_digits_be_bytes(&[0x0F, 0x0D]));
+/// assert_eq!(3, count_hex_digits_be_bytes(&[0x00, 0x0F, 0x0D]));
+/// assert_eq!(1, count_hex_digits_be_bytes(&[0, 0, 0]));
+/// assert_eq!(64, count_hex_digits_be_bytes(&[0xFF; 32]));
+/// ```
+pub const fn count_hex_digits_be_bytes(bytes: &[u8]) -> usize {
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] != 0 {
+            let leading_zero_nibbles = (bytes[index] < 0x10) as usize;
+            return 2 * (bytes.len() - index) - leading_zero_nibbles;
+        }
+        index += 1;
+    }
+    1
+}
+
+/// Orders integers by their count of decimal digits, then by value.
+///
+/// This is the ordering of [cmp_by_digit_width()](CountDigits::cmp_by_digit_width), for use as the key
+/// of a [BTreeSet](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) or
+/// [BTreeMap](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) without a custom comparator.
+/// Formatting a [DigitWidth] formats the inner value.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::DigitWidth;
+/// use std::collections::BTreeSet;
+///
+/// let set = [100, -5, 42, 7, -42].map(DigitWidth).into_iter().collect::<BTreeSet<_>>();
+/// let sorted = set.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+///
+/// assert_eq!(sorted, ["-5", "7", "-42", "42", "100"]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitWidth<T>(pub T);
+
+impl<T: CountDigits + Ord + Copy> PartialOrd for DigitWidth<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: CountDigits + Ord + Copy> Ord for DigitWidth<T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_by_digit_width(other.0)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DigitWidth<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Functions that take integers by reference, for use in point-free style
+/// where the items being counted are references.
+///
+/// Each function takes `&T` for some `T` that implements [CountDigits], so passing
+/// a `&&T` resolves `T` to `&T` through the [CountDigits] implementation for references
+/// without requiring a closure.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::by_ref;
+///
+/// let numbers = [2_u32, 3, 13, 103, 1337];
+/// let references = numbers.iter().collect::<Vec<&u32>>();
+///
+/// // The items of this iterator are &&u32.
+/// let max_digits = references
+///     .iter()
+///     .map(by_ref::count_digits_ref)
+///     .max()
+///     .unwrap();
+///
+/// assert_eq!(4, max_digits);
+///
+/// // The items of this iterator are &u32.
+/// let max_bits = numbers
+///     .iter()
+///     .map(by_ref::count_bits_ref)
+///     .max()
+///     .unwrap();
+///
+/// assert_eq!(11, max_bits);
+/// ```
+pub mod by_ref {
+    use crate::CountDigits;
+
+    #[inline(always)]
+    /// Returns the count of bits in an integer that is passed by reference.
+    ///
+    /// Calls [count_bits()](CountDigits::count_bits) on the referenced value.
+    pub fn count_bits_ref<T: CountDigits>(n: &T) -> u32 {
+        (*n).count_bits()
+    }
+
+    #[inline(always)]
+    /// Returns the count of octal digits in an integer that is passed by reference.
+    ///
+    /// Calls [count_octal_digits()](CountDigits::count_octal_digits) on the referenced value.
+    pub fn count_octal_digits_ref<T: CountDigits>(n: &T) -> u32 {
+        (*n).count_octal_digits()
+    }
+
+    #[inline(always)]
+    /// Returns the count of hexadecimal digits in an integer that is passed by reference.
+    ///
+    /// Calls [count_hex_digits()](CountDigits::count_hex_digits) on the referenced value.
+    pub fn count_hex_digits_ref<T: CountDigits>(n: &T) -> u32 {
+        (*n).count_hex_digits()
+    }
+
+    #[inline(always)]
+    /// Returns the count of decimal digits in an integer that is passed by reference.
+    ///
+    /// Calls [count_digits()](CountDigits::count_digits) on the referenced value.
+    pub fn count_digits_ref<T: CountDigits>(n: &T) -> usize {
+        (*n).count_digits()
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer that is passed by reference
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// [Panics](panic) if the provided radix is 0 or 1.
+    ///
+    /// Calls [count_digits_radix()](CountDigits::count_digits_radix) on the referenced value.
+    pub fn count_digits_radix_ref<T: CountDigits>(n: &T, radix: T::Radix) -> usize {
+        (*n).count_digits_radix(radix)
+    }
+
+    #[inline(always)]
+    /// Returns the count of digits in an integer that is passed by reference
+    /// as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// Returns [None] if the provided radix is 0 or 1.
+    ///
+    /// Calls [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) on the referenced value.
+    pub fn checked_count_digits_radix_ref<T: CountDigits>(n: &T, radix: T::Radix) -> Option<usize> {
+        (*n).checked_count_digits_radix(radix)
+    }
+}
+
+/// Extends iterators over integers with digit-counting adapters.
+///
+/// This trait is implemented for every [Iterator] whose items implement [CountDigits].
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::CountDigitsIterExt;
+///
+/// let widths = [5, 42, 7, 1000]
+///     .into_iter()
+///     .scan_max_count_digits()
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(widths, [1, 2, 2, 4]);
+/// ```
+pub trait CountDigitsIterExt: Iterator + Sized
+where
+    Self::Item: CountDigits,
+{
+    /// Returns an iterator that yields, for each integer, the maximum
+    /// [count_digits()](CountDigits::count_digits) seen so far.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigitsIterExt;
+    ///
+    /// let mut widths = [-5_i32, 42, -1000, 7].iter().scan_max_count_digits();
+    ///
+    /// assert_eq!(widths.next(), Some(1));
+    /// assert_eq!(widths.next(), Some(2));
+    /// assert_eq!(widths.next(), Some(4));
+    /// assert_eq!(widths.next(), Some(4));
+    /// assert_eq!(widths.next(), None);
+    /// ```
+    fn scan_max_count_digits(self) -> ScanMaxCountDigits<Self>;
+
+    /// Returns the maximum [count_digits()](CountDigits::count_digits) of the integers,
+    /// or [None] if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigitsIterExt;
+    ///
+    /// assert_eq!(Some(4), [5, -42, 7, 1000].iter().max_count_digits());
+    /// assert_eq!(None, core::iter::empty::<u8>().max_count_digits());
+    /// ```
+    fn max_count_digits(self) -> Option<usize>;
+
+    /// Returns the sum of the [count_digits()](CountDigits::count_digits) of the integers,
+    /// which is 0 if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use count_digits::CountDigitsIterExt;
+    ///
+    /// assert_eq!(1 + 2 + 1 + 4, [5, -42, 7, 1000].iter().total_count_digits());
+    /// assert_eq!(0, core::iter::empty::<u8>().total_count_digits());
+    /// ```
+    fn total_count_digits(self) -> usize;
+}
+
+impl<I> CountDigitsIterExt for I
+where
+    I: Iterator,
+    I::Item: CountDigits,
+{
+    #[inline(always)]
+    fn scan_max_count_digits(self) -> ScanMaxCountDigits<Self> {
+        ScanMaxCountDigits { iter: self, max: 0 }
+    }
+
+    #[inline(always)]
+    fn max_count_digits(self) -> Option<usize> {
+        self.map(CountDigits::count_digits).max()
+    }
+
+    #[inline(always)]
+    fn total_count_digits(self) -> usize {
+        self.map(CountDigits::count_digits).sum()
+    }
+}
+
+/// An iterator that yields the running maximum count of decimal digits.
+///
+/// Returned by [scan_max_count_digits()](CountDigitsIterExt::scan_max_count_digits).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ScanMaxCountDigits<I> {
+    iter: I,
+    max: usize,
+}
+
+impl<I> Iterator for ScanMaxCountDigits<I>
+where
+    I: Iterator,
+    I::Item: CountDigits,
+{
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<usize> {
+        let count = self.iter.next()?.count_digits();
+        self.max = self.max.max(count);
+        Some(self.max)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "rayon")]
+/// Returns the sum of the counts of decimal digits in a slice of integers,
+/// counted in parallel with [rayon].
+///
+/// The sum is the same as summing [count_digits()](CountDigits::count_digits) sequentially.
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::par_count_digits_sum;
+///
+/// assert_eq!(0, par_count_digits_sum(&[]));
+/// assert_eq!(1 + 2 + 3 + 20, par_count_digits_sum(&[7, 42, 100, u64::MAX]));
+/// ```
+pub fn par_count_digits_sum(slice: &[u64]) -> u128 {
+    slice.par_iter().map(|n| n.count_digits() as u128).sum()
+}
+
+/// Counts digits across collections of integers.
+///
+/// [CountDigits] counts the digits in a single integer; this trait sums the counts
+/// of the integers in a collection, or finds the largest count.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::CountDigitsAggregate;
+///
+/// assert_eq!(1 + 2 + 3, [7, -42, 100].total_count_digits());
+/// assert_eq!(1 + 2 + 3, [7, -42, 100][..].total_count_digits());
+/// assert_eq!(0, <[u8; 0]>::default().total_count_digits());
+///
+/// assert_eq!(Some(3), [7, -42, 100].max_count_digits());
+/// assert_eq!(None, <[u8; 0]>::default().max_count_digits());
+///
+/// // Tuples of up to six integers, which may have different types.
+/// assert_eq!(2 + 4, (-12_i8, 1337_u64).total_count_digits());
+/// ```
+pub trait CountDigitsAggregate {
+    /// Returns the sum of the counts of decimal digits in each integer.
+    ///
+    /// See [count_digits()](CountDigits::count_digits).
+    fn total_count_digits(&self) -> usize;
+
+    /// Returns the largest count of decimal digits of any integer, or [None] if the collection is empty.
+    ///
+    /// See [count_digits()](CountDigits::count_digits).
+    fn max_count_digits(&self) -> Option<usize>;
+}
+
+impl<T: CountDigits> CountDigitsAggregate for [T] {
+    #[inline(always)]
+    fn total_count_digits(&self) -> usize {
+        self.iter().map(CountDigits::count_digits).sum()
+    }
+
+    #[inline(always)]
+    fn max_count_digits(&self) -> Option<usize> {
+        self.iter().map(CountDigits::count_digits).max()
+    }
+}
+
+impl<T: CountDigits, const N: usize> CountDigitsAggregate for [T; N] {
+    #[inline(always)]
+    fn total_count_digits(&self) -> usize {
+        self[..].total_count_digits()
+    }
+
+    #[inline(always)]
+    fn max_count_digits(&self) -> Option<usize> {
+        self[..].max_count_digits()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Requires the `alloc` feature.
+impl<T: CountDigits> CountDigitsAggregate for Vec<T> {
+    #[inline(always)]
+    fn total_count_digits(&self) -> usize {
+        self[..].total_count_digits()
+    }
+
+    #[inline(always)]
+    fn max_count_digits(&self) -> Option<usize> {
+        self[..].max_count_digits()
+    }
+}
+
+macro_rules! impl_count_digits_aggregate_for_tuple {
+    ($($type:ident . $index:tt),+) => {
+        /// Counts the digits in each element of a tuple, which may have different types.
+        impl<$($type: CountDigits),+> CountDigitsAggregate for ($($type,)+) {
+            #[inline(always)]
+            fn total_count_digits(&self) -> usize {
+                0 $(+ self.$index.count_digits())+
+            }
+
+            #[inline(always)]
+            fn max_count_digits(&self) -> Option<usize> {
+                Some(0 $(.max(self.$index.count_digits()))+)
+            }
+        }
+    };
+}
+
+impl_count_digits_aggregate_for_tuple!(A.0);
+impl_count_digits_aggregate_for_tuple!(A.0, B.1);
+impl_count_digits_aggregate_for_tuple!(A.0, B.1, C.2);
+impl_count_digits_aggregate_for_tuple!(A.0, B.1, C.2, D.3);
+impl_count_digits_aggregate_for_tuple!(A.0, B.1, C.2, D.3, E.4);
+impl_count_digits_aggregate_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5);
+
+/// Re-exports the traits of this crate, so that a single glob import
+/// brings every extension method into scope.
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::prelude::*;
+///
+/// assert_eq!(4, 1337_u32.count_digits());
+/// assert_eq!(Some(4), [5, -42, 7, 1000].iter().max_count_digits());
+/// assert_eq!(1 + 2 + 1 + 4, [5, -42, 7, 1000].total_count_digits());
+/// ```
+pub mod prelude {
+    pub use crate::{CountDigits, CountDigitsAggregate, CountDigitsIterExt, DynCountDigits};
+}
+
+/// An object-safe companion to [CountDigits], for storing integers of different types behind `dyn`.
+///
+/// [CountDigits] is not object safe because it takes `self` by value and has an associated
+/// [Radix](CountDigits::Radix) type. This trait takes `&self` and a [u32] radix instead,
+/// and it is implemented for every type that implements [CountDigits].
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::DynCountDigits;
+/// use core::num::NonZeroU64;
+///
+/// let values: Vec<Box<dyn DynCountDigits>> = vec![
+///     Box::new(7_u8),
+///     Box::new(-1337_i32),
+///     Box::new(NonZeroU64::new(100_000).unwrap()),
+/// ];
+///
+/// let widths = values.iter().map(|n| n.dyn_count_digits()).collect::<Vec<_>>();
+/// assert_eq!(widths, [1, 4, 6]);
+///
+/// assert_eq!(Some(2), values[0].dyn_count_digits_radix(4));
+/// assert_eq!(None, values[0].dyn_count_digits_radix(1));
+/// ```
+pub trait DynCountDigits {
+    /// Returns the count of decimal digits in an integer.
+    ///
+    /// See [count_digits()](CountDigits::count_digits).
+    fn dyn_count_digits(&self) -> usize;
+
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// Returns [None] if the provided radix is 0 or 1.
+    ///
+    /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix).
+    fn dyn_count_digits_radix(&self, radix: u32) -> Option<usize>;
+}
+
+impl<T> DynCountDigits for T
+where
+    T: CountDigits,
+    T::Radix: TryFrom<u32>,
+{
+    #[inline(always)]
+    fn dyn_count_digits(&self) -> usize {
+        self.count_digits()
+    }
+
+    #[inline(always)]
+    fn dyn_count_digits_radix(&self, radix: u32) -> Option<usize> {
+        match T::Radix::try_from(radix) {
+            Ok(radix) => self.checked_count_digits_radix(radix),
+            // The radix is larger than every bit pattern of the type, so every integer has one digit.
+            Err(_) => Some(self.count_digits().min(1)),
+        }
+    }
+}
+
+/// Counts digits in the 8-bit integer types with a radix that cannot be zero.
+///
+/// The radix of [i8], [u8], and their [NonZero](core::num) counterparts is a [u8], so every valid
+/// radix fits in a [NonZeroU8], which rules out a radix of 0 when the radix is constructed.
+/// This trait is implemented for every type whose [Radix](CountDigits::Radix) is [u8].
+///
+/// # Examples
+///
+/// ```rust
+/// use count_digits::CountDigitsNz8;
+/// use core::num::NonZeroU8;
+///
+/// assert_eq!(Some(3), 255_u8.count_digits_radix_nz8(NonZeroU8::new(10).unwrap()));
+/// assert_eq!(Some(8), (-1_i8).count_digits_radix_nz8(NonZeroU8::new(2).unwrap()));
+/// assert_eq!(None, 255_u8.count_digits_radix_nz8(NonZeroU8::new(1).unwrap()));
+/// ```
+pub trait CountDigitsNz8: CountDigits<Radix = u8> {
+    #[inline(always)]
+    /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
+    ///
+    /// Returns [None] if the provided radix is 1.
+    ///
+    /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix).
+    fn count_digits_radix_nz8(self, radix: NonZeroU8) -> Option<usize> {
+        self.checked_count_digits_radix(radix.get())
+    }
+}
+
+impl<T: CountDigits<Radix = u8>> CountDigitsNz8 for T {}
+
+#[cfg(test)]
+mod count_digits {
+    use super::*;
+    use paste::paste;
+
+    /// The minimum and maximum values of the primitive and non-zero integer types.
+    ///
+    /// The `MIN` and `MAX` constants of the non-zero integer types are newer than the minimum supported
+    /// Rust version, so the tests name these constants through this trait instead.
+    trait Bounded {
+        const MIN: Self;
+        const MAX: Self;
+    }
+
+    macro_rules! impl_bounded {
+        ($type:ty, $non_zero_type:ty, non_zero_min = $non_zero_min:expr) => {
+            impl Bounded for $type {
+                const MIN: Self = <$type>::MIN;
+                const MAX: Self = <$type>::MAX;
+            }
+
+            impl Bounded for $non_zero_type {
+                const MIN: Self = match <$non_zero_type>::new($non_zero_min) {
+                    Some(n) => n,
+                    None => unreachable!(),
+                };
+                const MAX: Self = match <$non_zero_type>::new(<$type>::MAX) {
+                    Some(n) => n,
+                    None => unreachable!(),
+                };
+            }
+        };
+    }
+
+    impl_bounded!(i8, NonZeroI8, non_zero_min = i8::MIN);
+    impl_bounded!(i16, NonZeroI16, non_zero_min = i16::MIN);
+    impl_bounded!(i32, NonZeroI32, non_zero_min = i32::MIN);
+    impl_bounded!(i64, NonZeroI64, non_zero_min = i64::MIN);
+    impl_bounded!(i128, NonZeroI128, non_zero_min = i128::MIN);
+    impl_bounded!(isize, NonZeroIsize, non_zero_min = isize::MIN);
+    impl_bounded!(u8, NonZeroU8, non_zero_min = 1);
+    impl_bounded!(u16, NonZeroU16, non_zero_min = 1);
+    impl_bounded!(u32, NonZeroU32, non_zero_min = 1);
+    impl_bounded!(u64, NonZeroU64, non_zero_min = 1);
+    impl_bounded!(u128, NonZeroU128, non_zero_min = 1);
+    impl_bounded!(usize, NonZeroUsize, non_zero_min = 1);
+
+    macro_rules! binary_string_count {
+        ($n:expr) => {
+            format!("{:b}", $n).len() as u32
+        };
+    }
+
+    macro_rules! octal_string_count {
+        ($n:expr) => {
+            format!("{:o}", $n).len() as u32
+        };
+    }
+
+    macro_rules! decimal_string_count {
+        ($n:expr) => {{
+            let string = format!("{}", $n);
+            string.strip_prefix('-').unwrap_or(&string).len()
+        }};
+    }
+
+    macro_rules! hex_string_count {
+        ($n:expr) => {
+            format!("{:x}", $n).len() as u32
+        };
+    }
+
+    macro_rules! assert_min {
+        ($type:ty, count_decimal_digits_u32) => {
+            assert_eq!(
+                <$type as Bounded>::MIN.count_decimal_digits_u32(),
+                <$type as Bounded>::MIN.count_digits() as u32,
+            );
+        };
+        ($type:ty, count_bits) => {
+            assert_eq!(
+                <$type as Bounded>::MIN.count_bits(),
+                binary_string_count!(<$type as Bounded>::MIN)
+            );
+            assert_eq!(
+                <$type as Bounded>::MIN.count_digits_radix(2),
+                binary_string_count!(<$type as Bounded>::MIN) as usize,
+            );
+        };
+        ($type:ty, count_octal_digits) => {
+            assert_eq!(
+                <$type as Bounded>::MIN.count_octal_digits(),
+                octal_string_count!(<$type as Bounded>::MIN),
+            );
+            assert_eq!(
+                <$type as Bounded>::MIN.count_digits_radix(8),
+                octal_string_count!(<$type as Bounded>::MIN) as usize,
+            );
+        };
+        ($type:ty, count_digits) => {
+            assert_eq!(
+                <$type as Bounded>::MIN.count_digits(),
+                decimal_string_count!(<$type as Bounded>::MIN)
+            );
+            assert_eq!(
+                <$type as Bounded>::MIN.count_digits_radix(10),
+                decimal_string_count!(<$type as Bounded>::MIN),
+            );
+        };
+        ($type:ty, count_hex_digits) => {
+            assert_eq!(
+                <$type as Bounded>::MIN.count_hex_digits(),
+                hex_string_count!(<$type as Bounded>::MIN),
+            );
+            assert_eq!(
+                <$type as Bounded>::MIN.count_digits_radix(16),
+                hex_string_count!(<$type as Bounded>::MIN) as usize,
+            );
+        };
+    }
+
+    macro_rules! assert_max {
+        ($type:ty, count_decimal_digits_u32) => {
+            assert_eq!(
+                <$type as Bounded>::MAX.count_decimal_digits_u32(),
+                <$type as Bounded>::MAX.count_digits() as u32,
+            );
+        };
+        ($type:ty, count_bits) => {
+            assert_eq!(
+                <$type as Bounded>::MAX.count_bits(),
+                binary_string_count!(<$type as Bounded>::MAX)
+            );
+            assert_eq!(
+                <$type as Bounded>::MAX.count_digits_radix(2),
+                binary_string_count!(<$type as Bounded>::MAX) as usize,
+            );
+        };
+        ($type:ty, count_octal_digits) => {
+            assert_eq!(
+                <$type as Bounded>::MAX.count_octal_digits(),
+                octal_string_count!(<$type as Bounded>::MAX),
+            );
+            assert_eq!(
+                <$type as Bounded>::MAX.count_digits_radix(8),
+                octal_string_count!(<$type as Bounded>::MAX) as usize,
+            );
+        };
+        ($type:ty, count_digits) => {
+            assert_eq!(
+                <$type as Bounded>::MAX.count_digits(),
+                decimal_string_count!(<$type as Bounded>::MAX)
+            );
+            assert_eq!(
+                <$type as Bounded>::MAX.count_digits_radix(10),
+                decimal_string_count!(<$type as Bounded>::MAX),
+            );
+        };
+        ($type:ty, count_hex_digits) => {
+            assert_eq!(
+                <$type as Bounded>::MAX.count_hex_digits(),
+                hex_string_count!(<$type as Bounded>::MAX),
+            );
+            assert_eq!(
+                <$type as Bounded>::MAX.count_digits_radix(16),
+                hex_string_count!(<$type as Bounded>::MAX) as usize,
+            );
+        };
+    }
+
+    macro_rules! assert_representations {
+        ($n:expr, count_bits) => {
+            assert_eq!($n.count_bits(), binary_string_count!($n));
+            assert_eq!($n.count_digits_radix(2), binary_string_count!($n) as usize);
+        };
+        ($n:expr, count_octal_digits) => {
+            assert_eq!($n.count_octal_digits(), octal_string_count!($n));
+            assert_eq!($n.count_digits_radix(8), octal_string_count!($n) as usize);
+        };
+        ($n:expr, count_digits) => {
+            assert_eq!($n.count_digits(), decimal_string_count!($n));
+            assert_eq!($n.count_digits_radix(10), decimal_string_count!($n));
+        };
+        ($n:expr, count_decimal_digits_u32) => {
+            assert_eq!($n.count_decimal_digits_u32(), $n.count_digits() as u32);
+            assert_eq!(
+                $n.count_decimal_digits_u32() as usize,
+                decimal_string_count!($n)
+            );
+        };
+        ($n:expr, count_hex_digits) => {
+            assert_eq!($n.count_hex_digits(), hex_string_count!($n));
+            assert_eq!($n.count_digits_radix(16), hex_string_count!($n) as usize);
+        };
+        ($n:expr, count_digits_radix_ordering) => {
+            assert!([
+                $n.count_digits_radix(02),
+                $n.count_digits_radix(03),
+                $n.count_digits_radix(04),
+                $n.count_digits_radix(05),
+                $n.count_digits_radix(06),
+                $n.count_digits_radix(07),
+                $n.count_digits_radix(08),
+                $n.count_digits_radix(09),
+                $n.count_digits_radix(11),
+                $n.count_digits_radix(12),
+                $n.count_digits_radix(13),
+                $n.count_digits_radix(14),
+                $n.count_digits_radix(15),
+                $n.count_digits_radix(16),
+            ]
+            .windows(2)
+            .all(|window| window[0] >= window[1]));
+        };
+        ($n:expr, checked_count_digits_radix_ordering) => {
+            assert!([
+                $n.checked_count_digits_radix(02).unwrap(),
+                $n.checked_count_digits_radix(03).unwrap(),
+                $n.checked_count_digits_radix(04).unwrap(),
+                $n.checked_count_digits_radix(05).unwrap(),
+                $n.checked_count_digits_radix(06).unwrap(),
+                $n.checked_count_digits_radix(07).unwrap(),
+                $n.checked_count_digits_radix(08).unwrap(),
+                $n.checked_count_digits_radix(09).unwrap(),
+                $n.checked_count_digits_radix(11).unwrap(),
+                $n.checked_count_digits_radix(12).unwrap(),
+                $n.checked_count_digits_radix(13).unwrap(),
+                $n.checked_count_digits_radix(14).unwrap(),
+                $n.checked_count_digits_radix(15).unwrap(),
+                $n.checked_count_digits_radix(16).unwrap(),
+            ]
+            .windows(2)
+            .all(|window| window[0] >= window[1]));
+        };
+    }
+
+    macro_rules! lower_bound {
+        ($type:ty) => {
+            -100_000 as $type
+        };
+    }
+
+    macro_rules! upper_bound {
+        ($type:ty) => {
+            100_000 as $type
+        };
+    }
+
+    macro_rules! min_or_lower_bound {
+        (i8) => {
+            i8::MIN
+        };
+        (i16) => {
+            i16::MIN
+        };
+        (isize) => {{
+            #[cfg(any(target_pointer_width = "64", target_pointer_width = "32"))]
+            const fn min_or_lower_bound() -> isize {
+                lower_bound!(isize)
+            }
+            #[cfg(any(target_pointer_width = "16", target_pointer_width = "8"))]
+            const fn min_or_lower_bound() -> isize {
+                isize::MIN
+            }
+            min_or_lower_bound()
+        }};
+        ($type:ty) => {
+            lower_bound!($type)
+        };
+    }
+
+    macro_rules! max_or_upper_bound {
+        (i8) => {
+            i8::MAX
+        };
+        (i16) => {
+            i16::MAX
+        };
+        (u8) => {
+            u8::MAX
+        };
+        (u16) => {
+            u16::MAX
+        };
+        (isize) => {{
+            #[cfg(any(target_pointer_width = "64", target_pointer_width = "32"))]
+            const fn max_or_upper_bound() -> isize {
+                upper_bound!(isize)
+            }
+            #[cfg(any(target_pointer_width = "16", target_pointer_width = "8"))]
+            const fn max_or_upper_bound() -> isize {
+                isize::MAX
+            }
+            max_or_upper_bound()
+        }};
+        (usize) => {{
+            #[cfg(any(target_pointer_width = "64", target_pointer_width = "32"))]
+            const fn max_or_upper_bound() -> usize {
+                upper_bound!(usize)
+            }
+            #[cfg(any(target_pointer_width = "16", target_pointer_width = "8"))]
+            const fn max_or_upper_bound() -> usize {
+                isize::MAX
+            }
+            max_or_upper_bound()
+        }};
+        ($type:ty) => {
+            upper_bound!($type)
+        };
+    }
+
+    /// Returns an iterator over the pairs boundaries [n, m] for a given radix
+    /// where n is the largest number of its digit-size group and m is the smallest
+    /// number of its digit-size group.
+    macro_rules! radix_boundaries {
+        ($type:ty, $radix:expr) => {
+            std::iter::successors(Some($radix as $type), move |n| {
+                n.checked_mul($radix as $type)
+            })
+            .map(|n| [n - 1, n])
+        };
+    }
+
+    #[test]
+    fn helper_radix_boundaries() {
+        assert_eq!(
+            radix_boundaries!(i8, 2).collect::<Vec<_>>(),
+            [[1, 2], [3, 4], [7, 8], [15, 16], [31, 32], [63, 64]],
+        );
+        assert_eq!(
+            radix_boundaries!(i16, 10).collect::<Vec<_>>(),
+            [[9, 10], [99, 100], [999, 1000], [9999, 10000]],
+        );
+        assert_eq!(
+            radix_boundaries!(i16, 16).collect::<Vec<_>>(),
+            [[0xF, 0x10], [0xFF, 0x100], [0xFFF, 0x1000]],
+        );
+    }
+
+    /// Returns an iterator of increasing pairs staring with (1, 2).
+    fn increasing_pairs() -> impl Iterator<Item = (usize, usize)> {
+        std::iter::successors(Some(1usize), move |n| n.checked_add(1))
+            .zip(std::iter::successors(Some(2usize), move |n| {
+                n.checked_add(1)
+            }))
+    }
+
+    #[test]
+    fn helper_increasing_pairs() {
+        assert_eq!(
+            increasing_pairs().take(5).collect::<Vec<_>>(),
+            [(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)],
+        );
+    }
+
+    macro_rules! min_and_max {
+        ($type:ty, $non_zero_type:ty) => {
+            min_and_max!($type, $non_zero_type, count_bits);
+            min_and_max!($type, $non_zero_type, count_octal_digits);
+            min_and_max!($type, $non_zero_type, count_digits);
+            min_and_max!($type, $non_zero_type, count_decimal_digits_u32);
+            min_and_max!($type, $non_zero_type, count_hex_digits);
+        };
+        ($type:ty, $non_zero_type:ty, $function:ident) => {
+            paste! {
+                #[test]
+                fn [<$type _min_ $function>]() {
+                    assert_min!($type, $function);
+                }
+                #[test]
+                fn [<$type _max_ $function>]() {
+                    assert_max!($type, $function);
+                }
+
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _min_ $function>]() {
+                    assert_min!($non_zero_type, $function);
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _max_ $function>]() {
+                    assert_max!($non_zero_type, $function);
+                }
+            }
+        };
+    }
+
+    macro_rules! iteration {
+        ($signage:ident, $type:ty, $non_zero_type:ty) => {
+            iteration!($signage, $type, $non_zero_type, count_bits);
+            iteration!($signage, $type, $non_zero_type, count_octal_digits);
+            iteration!($signage, $type, $non_zero_type, count_digits);
+            iteration!($signage, $type, $non_zero_type, count_decimal_digits_u32);
+            iteration!($signage, $type, $non_zero_type, count_hex_digits);
+            iteration!($signage, $type, $non_zero_type, count_digits_radix_ordering);
+            iteration!(
+                $signage,
+                $type,
+                $non_zero_type,
+                checked_count_digits_radix_ordering
+            );
+        };
+        (signed, $type:ty, $non_zero_type:ty, $function:ident) => {
+            paste! {
+                #[test]
+                fn [<$type _iteration_ $function>]() {
+                    let max = max_or_upper_bound!($type);
+                    let min = min_or_lower_bound!($type);
+                    for n in min..=max {
+                        assert_representations!(n, $function);
+                    }
+                }
+
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _iteration_ $function>]() {
+                    let max = max_or_upper_bound!($type);
+                    let min = min_or_lower_bound!($type);
+                    for n in min..=max {
+                        if n == 0 { continue; }
+                        let n = $non_zero_type::new(n).unwrap();
+                        assert_representations!(n, $function);
+                    }
+                }
+            }
+        };
+        (unsigned, $type:ty, $non_zero_type:ty, $function:ident) => {
+            paste! {
+                #[test]
+                fn [<$type _iteration_ $function>]() {
+                    let max = max_or_upper_bound!($type);
+                    for n in $type::MIN..=max {
+                        assert_representations!(n, $function);
+                    }
+                }
+
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _iteration_ $function>]() {
+                    let max = max_or_upper_bound!($type);
+                    for n in <$non_zero_type as Bounded>::MIN.get()..=max {
+                        let n = $non_zero_type::new(n).unwrap();
+                        assert_representations!(n, $function);
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! pass_by_reference {
+        ($type:ty, $non_zero_type:ty) => {
+            pass_by_reference!($type, $non_zero_type, count_bits);
+            pass_by_reference!($type, $non_zero_type, count_octal_digits);
+            pass_by_reference!($type, $non_zero_type, count_hex_digits);
+            pass_by_reference!($type, $non_zero_type, count_digits);
+            pass_by_reference!($type, $non_zero_type, count_digits_radix);
+            pass_by_reference!($type, $non_zero_type, checked_count_digits_radix);
+            pass_by_reference!($type, $non_zero_type, count_digits_radix_ref);
+        };
+        ($type:ty, $non_zero_type:ty, count_digits_radix_ref) => {
+            paste! {
+                #[test]
+                fn [<$type _pass_by_reference_count_digits_radix_ref>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            assert_eq!(CountDigits::count_digits_radix(n, radix), CountDigits::count_digits_radix_ref(&n, &radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _pass_by_reference_count_digits_radix_ref>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(CountDigits::count_digits_radix(n, radix), CountDigits::count_digits_radix_ref(&n, &radix));
+                        }
+                    }
+                }
+            }
+        };
+        ($type:ty, $non_zero_type:ty, count_digits_radix) => {
+            paste! {
+                #[test]
+                fn [<$type _pass_by_reference_count_digits_radix>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            assert_eq!(CountDigits::count_digits_radix(n, radix), CountDigits::count_digits_radix(&n, radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _pass_by_reference_count_digits_radix>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(CountDigits::count_digits_radix(n, radix), CountDigits::count_digits_radix(&n, radix));
+                        }
+                    }
+                }
+            }
+        };
+        ($type:ty, $non_zero_type:ty, checked_count_digits_radix) => {
+            paste! {
+                #[test]
+                fn [<$type _pass_by_reference_checked_count_digits_radix>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            assert_eq!(CountDigits::checked_count_digits_radix(n, radix), CountDigits::checked_count_digits_radix(&n, radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _pass_by_reference_checked_count_digits_radix>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(CountDigits::checked_count_digits_radix(n, radix), CountDigits::checked_count_digits_radix(&n, radix));
+                        }
+                    }
+                }
+            }
+        };
+        ($type:ty, $non_zero_type:ty, $function:ident) => {
+            paste! {
+                #[test]
+                fn [<$type _pass_by_reference_ $function>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            assert_eq!(CountDigits::$function(n), CountDigits::$function(&n));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _pass_by_reference_ $function>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(CountDigits::$function(n), CountDigits::$function(&n));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! invalid_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            invalid_radix!(0, $type, $non_zero_type);
+            invalid_radix!(1, $type, $non_zero_type);
+        };
+        ($radix:expr, $type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                #[should_panic(expected = "base of integer logarithm must be at least 2")]
+                fn [<$type _invalid_radix_ $radix>]() {
+                    (1 as $type).count_digits_radix($radix);
+                }
+                #[test]
+                fn [<$type _invalid_radix_ $radix _checked>]() {
+                    assert!((1 as $type).checked_count_digits_radix($radix).is_none());
+                }
+                #[test]
+                #[should_panic(expected = "base of integer logarithm must be at least 2")]
+                fn [<$type _ref_invalid_radix_ $radix>]() {
+                    (&(1 as $type)).count_digits_radix($radix);
+                }
+                #[test]
+                fn [<$type _ref_invalid_radix_ $radix _checked>]() {
+                    assert!((&(1 as $type)).checked_count_digits_radix($radix).is_none());
+                }
+
+
+                #[test]
+                #[allow(non_snake_case)]
+                #[should_panic(expected = "base of integer logarithm must be at least 2")]
+                fn [<$non_zero_type _invalid_radix_ $radix>]() {
+                    $non_zero_type::new(1).unwrap().count_digits_radix($radix);
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _invalid_radix_ $radix _checked>]() {
+                    assert!($non_zero_type::new(1).unwrap().checked_count_digits_radix($radix).is_none());
+                }
+            }
+        };
+    }
+
+    /// Returns the message of the panic caused by the given function.
+    fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let payload = std::panic::catch_unwind(f).expect_err("function did not panic");
+        match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast::<&str>()
+                .map(|message| message.to_string())
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn ref_invalid_radix_panics_like_value() {
+        for radix in [0, 1] {
+            let by_value = panic_message(|| {
+                1_i32.count_digits_radix(radix);
+            });
+            let by_ref = panic_message(|| {
+                (&1_i32).count_digits_radix(radix);
+            });
+            assert_eq!(by_ref, by_value);
+            assert_eq!(by_ref, "base of integer logarithm must be at least 2");
+            assert_eq!((&1_i32).checked_count_digits_radix(radix), None);
+            assert_eq!((&&1_i32).checked_count_digits_radix(radix), None);
+        }
+    }
+
+    macro_rules! boundaries_for_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            boundaries_for_radix!(02, $type, $non_zero_type);
+            boundaries_for_radix!(03, $type, $non_zero_type);
+            boundaries_for_radix!(04, $type, $non_zero_type);
+            boundaries_for_radix!(05, $type, $non_zero_type);
+            boundaries_for_radix!(06, $type, $non_zero_type);
+            boundaries_for_radix!(07, $type, $non_zero_type);
+            boundaries_for_radix!(08, $type, $non_zero_type);
+            boundaries_for_radix!(09, $type, $non_zero_type);
+            boundaries_for_radix!(10, $type, $non_zero_type);
+            boundaries_for_radix!(11, $type, $non_zero_type);
+            boundaries_for_radix!(12, $type, $non_zero_type);
+            boundaries_for_radix!(13, $type, $non_zero_type);
+            boundaries_for_radix!(14, $type, $non_zero_type);
+            boundaries_for_radix!(15, $type, $non_zero_type);
+            boundaries_for_radix!(16, $type, $non_zero_type);
+            boundaries_for_radix!(17, $type, $non_zero_type);
+            boundaries_for_radix!(18, $type, $non_zero_type);
+            boundaries_for_radix!(19, $type, $non_zero_type);
+        };
+        ($radix:expr, $type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _boundaries_for_radix_ $radix>]() {
+                    assert!(
+                        radix_boundaries!($type, $radix)
+                            .map(|[n, m]| (n.count_digits_radix($radix), m.count_digits_radix($radix)))
+                            .zip(increasing_pairs())
+                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
+                                lhs_expected == lhs_actual && rhs_expected == rhs_actual
+                            })
+                    )
+                }
+                #[test]
+                fn [<$type _boundaries_for_radix_ $radix _checked>]() {
+                    assert!(
+                        radix_boundaries!($type, $radix)
+                            .map(|[n, m]| (n.checked_count_digits_radix($radix), m.checked_count_digits_radix($radix)))
+                            .zip(increasing_pairs())
+                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
+                                lhs_expected == lhs_actual.unwrap() && rhs_expected == rhs_actual.unwrap()
+                            })
+                    )
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _boundaries_for_radix_ $radix>]() {
+                    assert!(
+                        radix_boundaries!($type, $radix)
+                            .map(|[n, m]| (<$non_zero_type>::new(n).unwrap(), <$non_zero_type>::new(m).unwrap()))
+                            .map(|(n, m)| (n.count_digits_radix($radix), m.count_digits_radix($radix)))
+                            .zip(increasing_pairs())
+                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
+                                lhs_expected == lhs_actual && rhs_expected == rhs_actual
+                            })
+                    )
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _boundaries_for_radix_ $radix _checked>]() {
+                    assert!(
+                        radix_boundaries!($type, $radix)
+                            .map(|[n, m]| (<$non_zero_type>::new(n).unwrap(), <$non_zero_type>::new(m).unwrap()))
+                            .map(|(n, m)| (n.checked_count_digits_radix($radix), m.checked_count_digits_radix($radix)))
+                            .zip(increasing_pairs())
+                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
+                                lhs_expected == lhs_actual.unwrap() && rhs_expected == rhs_actual.unwrap()
+                            })
+                    )
+                }
+            }
+        };
+    }
+
+    macro_rules! free_functions {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _free_functions>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            assert_eq!(crate::bits(n), n.count_bits());
+                            assert_eq!(crate::octal(n), n.count_octal_digits());
+                            assert_eq!(crate::decimal(n), n.count_digits());
+                            assert_eq!(crate::hex(n), n.count_hex_digits());
+                            assert_eq!(crate::radix(n, radix), n.count_digits_radix(radix));
+                            assert_eq!(crate::checked_radix(n, radix), n.checked_count_digits_radix(radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _free_functions>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(crate::bits(n), n.count_bits());
+                            assert_eq!(crate::octal(n), n.count_octal_digits());
+                            assert_eq!(crate::decimal(n), n.count_digits());
+                            assert_eq!(crate::hex(n), n.count_hex_digits());
+                            assert_eq!(crate::radix(n, radix), n.count_digits_radix(radix));
+                            assert_eq!(crate::checked_radix(n, radix), n.checked_count_digits_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! digit_width_delta {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _digit_width_delta>]() {
+                    for radix in 2..20 {
+                        for [n, m] in radix_boundaries!($type, radix) {
+                            assert_eq!(m.digit_width_delta_radix(n, radix), 1);
+                            assert_eq!(n.digit_width_delta_radix(m, radix), -1);
+                            assert_eq!(n.digit_width_delta_radix(n, radix), 0);
+                            assert_eq!(
+                                m.digit_width_delta(n),
+                                m.count_digits() as isize - n.count_digits() as isize,
+                            );
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _digit_width_delta>]() {
+                    for radix in 2..20 {
+                        for [n, m] in radix_boundaries!($type, radix) {
+                            let n = $non_zero_type::new(n).unwrap();
+                            let m = $non_zero_type::new(m).unwrap();
+                            assert_eq!(m.digit_width_delta_radix(n, radix), 1);
+                            assert_eq!(n.digit_width_delta_radix(m, radix), -1);
+                            assert_eq!(n.digit_width_delta_radix(n, radix), 0);
+                            assert_eq!(
+                                m.digit_width_delta(n),
+                                m.count_digits() as isize - n.count_digits() as isize,
+                            );
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    /// Returns the count of balanced-ternary trits of a magnitude as the smallest k
+    /// for which the magnitude is at most (3^k - 1) / 2.
+    fn balanced_ternary_bound_count(magnitude: u128) -> u32 {
+        let mut count = 1;
+        let mut bound = 1_u128;
+        while magnitude > bound {
+            count += 1;
+            match bound.checked_mul(3).and_then(|bound| bound.checked_add(1)) {
+                Some(next) => bound = next,
+                None => break,
+            }
+        }
+        count
+    }
+
+    macro_rules! balanced_ternary {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _balanced_ternary>]() {
+                    for n in [<$type as Bounded>::MIN, <$type as Bounded>::MAX].into_iter().chain(radix_boundaries!($type, 3).flatten()) {
+                        assert_eq!(
+                            n.count_balanced_ternary_digits(),
+                            balanced_ternary_bound_count(n.abs_diff(0) as u128),
+                        );
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _balanced_ternary>]() {
+                    for n in [<$non_zero_type as Bounded>::MIN, <$non_zero_type as Bounded>::MAX] {
+                        assert_eq!(n.count_balanced_ternary_digits(), n.get().count_balanced_ternary_digits());
+                    }
+                }
+            }
+        };
+    }
+
+    /// Returns the balanced-ternary trits of n, least-significant first.
+    fn balanced_ternary_expansion(mut n: i128) -> Vec<i8> {
+        let mut trits = Vec::new();
+        loop {
+            let trit = match n.rem_euclid(3) {
+                0 => 0,
+                1 => 1,
+                _ => -1,
+            };
+            trits.push(trit);
+            n = (n - trit as i128) / 3;
+            if n == 0 {
+                break trits;
+            }
+        }
+    }
+
+    #[test]
+    fn helper_balanced_ternary_expansion() {
+        assert_eq!(balanced_ternary_expansion(0), [0]);
+        assert_eq!(balanced_ternary_expansion(-1), [-1]);
+        assert_eq!(balanced_ternary_expansion(5), [-1, -1, 1]);
+        assert_eq!(balanced_ternary_expansion(-5), [1, 1, -1]);
+    }
+
+    #[test]
+    fn i32_balanced_ternary_expansion() {
+        for n in -100_i32..=100 {
+            assert_eq!(
+                n.count_balanced_ternary_digits() as usize,
+                balanced_ternary_expansion(n as i128).len(),
+            );
+        }
+    }
+
+    /// Returns the count of bits in the Gray code of the binary representation of n.
+    macro_rules! gray_code_string_count {
+        ($n:expr) => {{
+            let n = u128::from_str_radix(&format!("{:b}", $n), 2).unwrap();
+            binary_string_count!(n ^ (n >> 1))
+        }};
+    }
+
+    macro_rules! gray_code {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _gray_code>]() {
+                    for n in [<$type as Bounded>::MIN, <$type as Bounded>::MAX].into_iter().chain(radix_boundaries!($type, 2).flatten()) {
+                        assert_eq!(n.count_gray_code_bits(), n.count_bits());
+                        assert_eq!(n.count_gray_code_bits(), gray_code_string_count!(n));
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _gray_code>]() {
+                    for n in [<$non_zero_type as Bounded>::MIN, <$non_zero_type as Bounded>::MAX] {
+                        assert_eq!(n.count_gray_code_bits(), n.count_bits());
+                        assert_eq!(n.count_gray_code_bits(), gray_code_string_count!(n));
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! nonzero_counts {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _nonzero_counts>]() {
+                    let samples = [<$type as Bounded>::MIN, <$type as Bounded>::MAX, 0 as $type];
+                    for n in samples.into_iter().chain(radix_boundaries!($type, 2).flatten()) {
+                        assert_eq!(n.count_bits_nonzero().get(), n.count_bits());
+                        assert_eq!(n.count_octal_digits_nonzero().get(), n.count_octal_digits());
+                        assert_eq!(n.count_hex_digits_nonzero().get(), n.count_hex_digits());
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _nonzero_counts>]() {
+                    for n in radix_boundaries!($type, 2).flatten() {
+                        let n = $non_zero_type::new(n).unwrap();
+                        assert_eq!(n.count_bits_nonzero().get(), n.count_bits());
+                        assert_eq!(n.count_octal_digits_nonzero().get(), n.count_octal_digits());
+                        assert_eq!(n.count_hex_digits_nonzero().get(), n.count_hex_digits());
+                    }
+                    for n in [<$non_zero_type as Bounded>::MIN, <$non_zero_type as Bounded>::MAX] {
+                        assert_eq!(n.count_bits_nonzero().get(), n.count_bits());
+                        assert_eq!(n.count_octal_digits_nonzero().get(), n.count_octal_digits());
+                        assert_eq!(n.count_hex_digits_nonzero().get(), n.count_hex_digits());
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! bit_pattern_as_unsigned {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _bit_pattern_as_unsigned>]() {
+                    let samples = [<$type as Bounded>::MIN, <$type as Bounded>::MAX, 0 as $type, 1 as $type];
+                    for n in samples.into_iter().chain(radix_boundaries!($type, 2).flatten()) {
+                        assert_eq!(format!("{:b}", n), format!("{:b}", n.bit_pattern_as_unsigned()));
+                        assert_eq!(n.count_bits(), n.bit_pattern_as_unsigned().count_bits());
+                        assert_eq!(n.count_octal_digits(), n.bit_pattern_as_unsigned().count_octal_digits());
+                        assert_eq!(n.count_hex_digits(), n.bit_pattern_as_unsigned().count_hex_digits());
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _bit_pattern_as_unsigned>]() {
+                    for n in [<$non_zero_type as Bounded>::MIN, <$non_zero_type as Bounded>::MAX] {
+                        assert_eq!(n.bit_pattern_as_unsigned(), n.get().bit_pattern_as_unsigned());
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! count_digits_of_complement {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_of_complement>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.count_bits_of_complement(), (!n).count_bits());
+                            assert_eq!(n.count_digits_radix_of_complement(radix), (!n).count_digits_radix(radix));
+                            assert_eq!((&n).count_digits_radix_of_complement(radix), (!n).count_digits_radix(radix));
+                        }
+                    }
+                    assert_eq!((0 as $type).count_bits_of_complement(), <$type>::BITS);
+                    assert_eq!(None::<$type>.count_bits_of_complement(), 0);
+                    assert_eq!(None::<$type>.count_digits_radix_of_complement(10), 0);
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_of_complement>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_bits_of_complement(), (!n).count_bits());
+                            assert_eq!(non_zero.count_digits_radix_of_complement(radix), (!n).count_digits_radix(radix));
+                        }
+                    }
+                    // The complement of the non-zero integer with every bit set is zero.
+                    let all_ones = $non_zero_type::new(!(0 as $type)).unwrap();
+                    assert_eq!(all_ones.count_bits_of_complement(), 1);
+                    assert_eq!(all_ones.count_digits_radix_of_complement(10), 1);
+                }
+            }
+        };
+    }
+
+    macro_rules! min_twos_complement_bits {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _min_twos_complement_bits>]() {
+                    let fits = |n: i128, width: u32| {
+                        width >= 128 || (-(1_i128 << (width - 1))..1_i128 << (width - 1)).contains(&n)
+                    };
+                    for n in samples!($type, 2) {
+                        let width = n.min_twos_complement_bits();
+                        assert!((1..=<$type>::BITS + 1).contains(&width));
+                        // Every sample fits in an i128 except for the largest u128 samples.
+                        #[allow(irrefutable_let_patterns)]
+                        if let Ok(n) = i128::try_from(n) {
+                            assert!(fits(n, width));
+                            assert!(width == 1 || !fits(n, width - 1));
+                        }
+                        assert_eq!((&n).min_twos_complement_bits(), width);
+                    }
+                    assert_eq!((0 as $type).min_twos_complement_bits(), 1);
+                    assert_eq!(None::<$type>.min_twos_complement_bits(), 0);
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _min_twos_complement_bits>]() {
+                    for n in radix_boundaries!($type, 2).flatten() {
+                        let non_zero = $non_zero_type::new(n).unwrap();
+                        assert_eq!(non_zero.min_twos_complement_bits(), n.min_twos_complement_bits());
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn min_twos_complement_bits_widths() {
+        assert_eq!(127_i32.min_twos_complement_bits(), 8);
+        assert_eq!(128_i32.min_twos_complement_bits(), 9);
+        assert_eq!((-128_i32).min_twos_complement_bits(), 8);
+        assert_eq!((-129_i32).min_twos_complement_bits(), 9);
+        assert_eq!((-1_i32).min_twos_complement_bits(), 1);
+        assert_eq!((-2_i32).min_twos_complement_bits(), 2);
+        assert_eq!(1_i32.min_twos_complement_bits(), 2);
+        assert_eq!(i8::MIN.min_twos_complement_bits(), 8);
+        assert_eq!(i8::MAX.min_twos_complement_bits(), 8);
+        assert_eq!(i128::MIN.min_twos_complement_bits(), 128);
+        assert_eq!(i128::MAX.min_twos_complement_bits(), 128);
+        assert_eq!(255_u8.min_twos_complement_bits(), 9);
+        assert_eq!(u128::MAX.min_twos_complement_bits(), 129);
+        assert_eq!(NonZeroI16::new(-129).unwrap().min_twos_complement_bits(), 9);
+        assert_eq!(Reverse(-129_i16).min_twos_complement_bits(), 9);
+    }
+
+    macro_rules! per_byte_decimal_widths {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _per_byte_decimal_widths>]() {
+                    for n in samples!($type, 2) {
+                        let widths = n.per_byte_decimal_widths();
+                        assert_eq!(widths.len(), core::mem::size_of::<$type>());
+                        assert!(widths.clone().eq(n.to_le_bytes().iter().map(|byte| byte.count_digits())));
+                        assert!(widths.rev().eq(n.to_be_bytes().iter().map(|byte| byte.count_digits())));
+                        assert!((&n).per_byte_decimal_widths().eq(n.per_byte_decimal_widths()));
+                    }
+                    assert_eq!(None::<$type>.per_byte_decimal_widths().next(), None);
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _per_byte_decimal_widths>]() {
+                    for n in radix_boundaries!($type, 2).flatten() {
+                        let non_zero = $non_zero_type::new(n).unwrap();
+                        assert!(non_zero.per_byte_decimal_widths().eq(n.per_byte_decimal_widths()));
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn per_byte_decimal_widths_of_varying_bytes() {
+        assert!(0x01_0A_64_FF_u32.per_byte_decimal_widths().eq([3, 3, 2, 1]));
+        assert!(0x09_63_C7_00_u32.per_byte_decimal_widths().eq([1, 3, 2, 1]));
+        assert!(0x0A_64_u16.per_byte_decimal_widths().eq([3, 2]));
+        assert!((-2_i32).per_byte_decimal_widths().eq([3, 3, 3, 3]));
+        assert!(i32::MIN.per_byte_decimal_widths().eq([1, 1, 1, 3]));
+        assert!(Reverse(0x01_0A_64_FF_u32)
+            .per_byte_decimal_widths()
+            .eq([3, 3, 2, 1]));
+        assert!(Some(0xFF_u8).per_byte_decimal_widths().eq([3]));
+    }
+
+    #[test]
+    fn negative_bit_pattern_as_unsigned() {
+        assert_eq!((-1_i8).bit_pattern_as_unsigned(), u8::MAX);
+        assert_eq!((-1_i16).bit_pattern_as_unsigned(), u16::MAX);
+        assert_eq!((-1_i32).bit_pattern_as_unsigned(), u32::MAX);
+        assert_eq!((-1_i64).bit_pattern_as_unsigned(), u64::MAX);
+        assert_eq!((-1_i128).bit_pattern_as_unsigned(), u128::MAX);
+        assert_eq!((-1_isize).bit_pattern_as_unsigned(), usize::MAX);
+        assert_eq!(
+            NonZeroI8::new(-1).unwrap().bit_pattern_as_unsigned(),
+            u8::MAX
+        );
+        assert_eq!(
+            <NonZeroI128 as Bounded>::MIN.bit_pattern_as_unsigned(),
+            1 << 127
+        );
+    }
+
+    macro_rules! ilog_fallback {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _ilog_fallback>]() {
+                    use ilog::fallback;
+                    for radix in 2..=36 {
+                        let samples = [<$type as Bounded>::MIN, <$type as Bounded>::MAX, 0 as $type, 1 as $type];
+                        for n in samples.into_iter().chain(radix_boundaries!($type, radix).flatten()) {
+                            assert_eq!(IntegerLogarithm::checked_ilog(n, radix), fallback::IntegerLogarithm::checked_ilog(n, radix));
+                            assert_eq!(IntegerLogarithm::checked_ilog2(n), fallback::IntegerLogarithm::checked_ilog2(n));
+                            assert_eq!(IntegerLogarithm::checked_ilog10(n), fallback::IntegerLogarithm::checked_ilog10(n));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _ilog_fallback>]() {
+                    for radix in 2..=36 {
+                        let samples = [<$type as Bounded>::MAX, 1 as $type];
+                        for n in samples.into_iter().chain(radix_boundaries!($type, radix).flatten()) {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(IntegerLogarithm::ilog(n.get(), radix), ilog::fallback::IntegerLogarithm::ilog(n.get(), radix));
+                            assert_eq!(IntegerLogarithm::ilog2(n.get()), ilog::fallback::IntegerLogarithm::ilog2(n.get()));
+                            assert_eq!(IntegerLogarithm::ilog10(n.get()), ilog::fallback::IntegerLogarithm::ilog10(n.get()));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn non_zero_ilog_fallback() {
+        use ilog::fallback;
+        for n in [
+            <NonZeroU8 as Bounded>::MIN,
+            <NonZeroU8 as Bounded>::MAX,
+            NonZeroU8::new(100).unwrap(),
+        ] {
+            assert_eq!(
+                NonZeroIntegerLogarithm::ilog2(n),
+                fallback::NonZeroIntegerLogarithm::ilog2(n)
+            );
+            assert_eq!(
+                NonZeroIntegerLogarithm::ilog10(n),
+                fallback::NonZeroIntegerLogarithm::ilog10(n)
+            );
+        }
+        for n in [
+            <NonZeroU128 as Bounded>::MIN,
+            <NonZeroU128 as Bounded>::MAX,
+            NonZeroU128::new(10_u128.pow(38)).unwrap(),
+        ] {
+            assert_eq!(
+                NonZeroIntegerLogarithm::ilog2(n),
+                fallback::NonZeroIntegerLogarithm::ilog2(n)
+            );
+            assert_eq!(
+                NonZeroIntegerLogarithm::ilog10(n),
+                fallback::NonZeroIntegerLogarithm::ilog10(n)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "argument of integer logarithm must be positive")]
+    fn ilog_fallback_non_positive_argument() {
+        ilog::fallback::IntegerLogarithm::ilog(-1_i32, 10);
+    }
+
+    #[cfg(feature = "alloc")]
+    macro_rules! to_string_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _to_string_radix>]() {
+                    for radix in 2..=36 {
+                        let samples = [<$type as Bounded>::MIN, <$type as Bounded>::MAX, 0 as $type, 1 as $type];
+                        for n in samples.into_iter().chain(radix_boundaries!($type, radix).flatten()) {
+                            let string = n.to_string_radix(radix, false);
+                            match radix {
+                                02 => assert_eq!(string, format!("{n:b}")),
+                                08 => assert_eq!(string, format!("{n:o}")),
+                                10 => assert_eq!(string, format!("{n}")),
+                                16 => assert_eq!(string, format!("{n:x}")),
+                                __ => assert_eq!(string.chars().count(), n.count_digits_radix(radix)),
+                            }
+                            assert_eq!(n.to_string_radix(radix, true), string.to_uppercase());
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _to_string_radix>]() {
+                    for radix in 2..=36 {
+                        for n in [<$non_zero_type as Bounded>::MIN, <$non_zero_type as Bounded>::MAX] {
+                            assert_eq!(n.to_string_radix(radix, false), n.get().to_string_radix(radix, false));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn negative_to_string_radix() {
+        for radix in (2..=36).filter(|&radix| radix != 10) {
+            for n in [-1_i8, -2, -100, i8::MIN] {
+                assert_eq!(
+                    n.to_string_radix(radix, false).chars().count(),
+                    n.count_digits_radix(radix)
+                );
+                assert_eq!(
+                    n.to_string_radix(radix, false),
+                    (n as u8).to_string_radix(radix, false)
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "radix must be at most 36 to render digits")]
+    fn to_string_radix_37() {
+        1_u32.to_string_radix(37, false);
+    }
+
+    macro_rules! negative_non_zero_parity {
+        ($type:ty, $non_zero_type:ty, $unsigned_type:ty) => {
+            paste! {
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _negative_parity>]() {
+                    for n in [-1, -2, <$type as Bounded>::MIN + 1, <$type as Bounded>::MIN] {
+                        let non_zero = <$non_zero_type>::new(n).unwrap();
+                        let pattern = n as $unsigned_type;
+                        for radix in 2..=36 {
+                            let expected = match radix {
+                                10 => n.unsigned_abs().count_digits(),
+                                __ => pattern.count_digits_radix(radix),
+                            };
+                            assert_eq!(n.count_digits_radix(radix), expected, "{n} radix {radix}");
+                            assert_eq!(non_zero.count_digits_radix(radix), expected, "{n} radix {radix}");
+                            assert_eq!(non_zero.checked_count_digits_radix(radix), Some(expected));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn non_zero_minus_one_radix_3() {
+        let non_zero = NonZeroI8::new(-1).unwrap().count_digits_radix(3);
+        let primitive = (-1_i8).count_digits_radix(3);
+        let pattern = 255_u8.count_digits_radix(3);
+        assert_eq!((non_zero, primitive, pattern), (6, 6, 6));
+    }
+
+    #[test]
+    fn negative_count_digits_radix() {
+        assert_eq!((-1_i8).count_digits_radix(3), 6);
+        assert_eq!(i8::MIN.count_digits_radix(3), 5);
+        assert_eq!(NonZeroI8::new(-1).unwrap().count_digits_radix(3), 6);
+        assert_eq!((-1_i32).count_digits_radix(10), 1);
+        for radix in (2..=36).filter(|&radix| radix != 10) {
+            for n in i8::MIN..0 {
+                assert_eq!(
+                    n.count_digits_radix(radix),
+                    (n as u8).count_digits_radix(radix)
+                );
+            }
+        }
+    }
+
+    /// Returns an iterator over the minimum, maximum, zero, and one values of a type,
+    /// followed by the pairs of boundaries for the given radix.
+    macro_rules! samples {
+        ($type:ty, $radix:expr) => {
+            [
+                <$type as Bounded>::MIN,
+                <$type as Bounded>::MAX,
+                0 as $type,
+                1 as $type,
+            ]
+            .into_iter()
+            .chain(radix_boundaries!($type, $radix).flatten())
+        };
+    }
+
+    macro_rules! count_digits_radix_aligned {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_aligned>]() {
+                    for radix in 2..20 {
+                        for n in samples!($type, radix) {
+                            let count = n.count_digits_radix(radix);
+                            assert_eq!(n.count_digits_radix_aligned(radix, 0), count);
+                            assert_eq!(n.count_digits_radix_aligned(radix, 1), count);
+                            for align in [2, 4, 8] {
+                                let aligned = n.count_digits_radix_aligned(radix, align);
+                                assert_eq!(aligned % align as usize, 0);
+                                assert!(aligned >= count);
+                                assert!(aligned - count < align as usize);
+                            }
+                            assert_eq!(
+                                n.count_digits_radix_aligned(radix, u32::MAX),
+                                usize::try_from(u32::MAX).unwrap_or(usize::MAX),
+                            );
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_aligned>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            for align in [0, 1, 2, 4, 8] {
+                                assert_eq!(
+                                    n.count_digits_radix_aligned(radix, align),
+                                    n.get().count_digits_radix_aligned(radix, align),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! count_digit_groups {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digit_groups>]() {
+                    for radix in 2..20 {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.count_digit_groups(0, radix), n.count_digits_radix(radix));
+                            for group_size in 1..=8 {
+                                let aligned = n.count_digits_radix_aligned(radix, group_size);
+                                assert_eq!(n.count_digit_groups(group_size, radix), aligned / group_size as usize);
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digit_groups>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            for group_size in [0, 1, 3, 4] {
+                                assert_eq!(
+                                    n.count_digit_groups(group_size, radix),
+                                    n.get().count_digit_groups(group_size, radix),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! magnitude_tier {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _magnitude_tier>]() {
+                    for n in samples!($type, 10) {
+                        assert_eq!(n.magnitude_tier(), n.magnitude_tier_radix(10, 3));
+                    }
+                    for radix in 2..20 {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.magnitude_tier_radix(radix, 0), 0);
+                            for group in 1..=8 {
+                                assert_eq!(
+                                    n.magnitude_tier_radix(radix, group) as usize,
+                                    (n.count_digits_radix(radix) - 1) / group as usize,
+                                );
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _magnitude_tier>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.magnitude_tier(), n.magnitude_tier());
+                            for group in [0, 1, 3, 10] {
+                                assert_eq!(
+                                    non_zero.magnitude_tier_radix(radix, group),
+                                    n.magnitude_tier_radix(radix, group),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn magnitude_tier_boundaries() {
+        let mut power = 1_u128;
+        for tier in 0..12 {
+            if tier > 0 {
+                assert_eq!((power - 1).magnitude_tier(), tier - 1);
+            }
+            assert_eq!(power.magnitude_tier(), tier);
+            assert_eq!((power * 1000 - 1).magnitude_tier(), tier);
+            power *= 1000;
+        }
+        assert_eq!(0_u64.magnitude_tier(), 0);
+        assert_eq!((-999_i32).magnitude_tier(), 0);
+        assert_eq!((-1_000_i32).magnitude_tier(), 1);
+        assert_eq!(u64::MAX.magnitude_tier(), 6);
+        assert_eq!(i8::MIN.magnitude_tier(), 0);
+        assert_eq!(None::<u32>.magnitude_tier(), 0);
+        for tier in 0..12 {
+            let power = 1_u128 << (10 * tier);
+            assert_eq!(power.magnitude_tier_radix(2, 10), tier);
+            assert_eq!(
+                (power - 1).magnitude_tier_radix(2, 10),
+                tier.saturating_sub(1)
+            );
+        }
+    }
+
+    #[test]
+    fn count_digit_groups_widths() {
+        // Widths that are exact multiples of the group size.
+        assert_eq!(123_456_u32.count_digit_groups(3, 10), 2);
+        assert_eq!(999_u32.count_digit_groups(3, 10), 1);
+        assert_eq!(u32::MAX.count_digit_groups(4, 16), 2);
+        assert_eq!(0b1111_0000_u8.count_digit_groups(8, 2), 1);
+        // Widths that are not.
+        assert_eq!(1_234_567_u32.count_digit_groups(3, 10), 3);
+        assert_eq!(1_000_u32.count_digit_groups(3, 10), 2);
+        assert_eq!((-1_234_i32).count_digit_groups(3, 10), 2);
+        assert_eq!(0_u32.count_digit_groups(3, 10), 1);
+        assert_eq!(u64::MAX.count_digit_groups(3, 10), 7);
+        assert_eq!(1_u32.count_digit_groups(u32::MAX, 10), 1);
+    }
+
+    macro_rules! count_digits_nonempty {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_nonempty>]() {
+                    assert_eq!((0 as $type).count_digits_nonempty(), 0);
+                    for radix in 2..20 {
+                        assert_eq!((0 as $type).count_digits_radix_nonempty(radix), 0);
+                        for n in samples!($type, radix).filter(|&n| n != 0) {
+                            assert_eq!(n.count_digits_nonempty(), n.count_digits());
+                            assert_eq!(n.count_digits_radix_nonempty(radix), n.count_digits_radix(radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_nonempty>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(n.count_digits_nonempty(), n.count_digits());
+                            assert_eq!(n.count_digits_radix_nonempty(radix), n.count_digits_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn count_digits_radix_nonempty_invalid_radix() {
+        0_u32.count_digits_radix_nonempty(1);
+    }
+
+    macro_rules! digit_counter {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _digit_counter>]() {
+                    assert!(DigitCounter::<<$type as CountDigits>::Radix>::new(0).is_none());
+                    assert!(DigitCounter::<<$type as CountDigits>::Radix>::new(1).is_none());
+                    for radix in 2..20 {
+                        let counter = DigitCounter::new(radix).unwrap();
+                        assert_eq!(counter.radix(), radix);
+                        for n in samples!($type, radix) {
+                            assert_eq!(counter.count(n), n.count_digits_radix(radix));
+                            assert_eq!(counter.count(&n), n.count_digits_radix(radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _digit_counter>]() {
+                    for radix in 2..20 {
+                        let counter = DigitCounter::new(radix).unwrap();
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(counter.count(n), n.count_digits_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! hex_formatted_width {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _hex_formatted_width>]() {
+                    for n in samples!($type, 16) {
+                        assert_eq!(n.hex_formatted_width(false, false), format!("{n:x}").len());
+                        assert_eq!(n.hex_formatted_width(false, true), format!("{n:X}").len());
+                        assert_eq!(n.hex_formatted_width(true, false), format!("{n:#x}").len());
+                        assert_eq!(n.hex_formatted_width(true, true), format!("{n:#X}").len());
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _hex_formatted_width>]() {
+                    for n in radix_boundaries!($type, 16).flatten() {
+                        let n = $non_zero_type::new(n).unwrap();
+                        assert_eq!(n.hex_formatted_width(false, false), format!("{n:x}").len());
+                        assert_eq!(n.hex_formatted_width(false, true), format!("{n:X}").len());
+                        assert_eq!(n.hex_formatted_width(true, false), format!("{n:#x}").len());
+                        assert_eq!(n.hex_formatted_width(true, true), format!("{n:#X}").len());
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! non_zero_parity {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _parity_with_ $type>]() {
+                    for radix in 2..=36 {
+                        let samples = [<$non_zero_type as Bounded>::MIN.get(), <$non_zero_type as Bounded>::MAX.get()];
+                        for n in samples.into_iter().chain(radix_boundaries!($type, radix).flatten()) {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_bits(), n.count_bits());
+                            assert_eq!(non_zero.count_octal_digits(), n.count_octal_digits());
+                            assert_eq!(non_zero.count_hex_digits(), n.count_hex_digits());
+                            assert_eq!(non_zero.count_digits(), n.count_digits());
+                            assert_eq!(non_zero.count_digits_radix(radix), n.count_digits_radix(radix));
+                            assert_eq!(non_zero.checked_count_digits_radix(radix), n.checked_count_digits_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! by_ref {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _by_ref>]() {
+                    for radix in 2..20 {
+                        for n in samples!($type, radix) {
+                            assert_eq!(by_ref::count_bits_ref(&n), n.count_bits());
+                            assert_eq!(by_ref::count_octal_digits_ref(&n), n.count_octal_digits());
+                            assert_eq!(by_ref::count_hex_digits_ref(&n), n.count_hex_digits());
+                            assert_eq!(by_ref::count_digits_ref(&&n), n.count_digits());
+                            assert_eq!(by_ref::count_digits_radix_ref(&n, radix), n.count_digits_radix(radix));
+                            assert_eq!(by_ref::checked_count_digits_radix_ref(&&n, radix), n.checked_count_digits_radix(radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _by_ref>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(by_ref::count_bits_ref(&n), n.count_bits());
+                            assert_eq!(by_ref::count_digits_ref(&&n), n.count_digits());
+                            assert_eq!(by_ref::count_digits_radix_ref(&n, radix), n.count_digits_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    /// Returns the count of digits in the magnitude by repeated division.
+    fn count_digits_by_division(mut magnitude: u128, radix: u128) -> usize {
+        let mut count = 1;
+        while magnitude >= radix {
+            magnitude /= radix;
+            count += 1;
+        }
+        count
+    }
+
+    #[test]
+    fn i128_min_count_digits_radix_7() {
+        assert_eq!(i128::MIN.count_digits_radix(7), 46);
+        assert_eq!(
+            NonZeroI128::new(i128::MIN).unwrap().count_digits_radix(7),
+            46
+        );
+    }
+
+    macro_rules! signed_min {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _signed_min>]() {
+                    let magnitude = <$type as Bounded>::MIN.unsigned_abs();
+                    assert_eq!(magnitude, <$type as Bounded>::MIN as <$type as CountDigits>::Radix);
+                    for radix in 2..=u8::MAX {
+                        let expected = count_digits_by_division(magnitude as u128, radix as u128);
+                        assert_eq!(<$type as Bounded>::MIN.count_digits_radix(radix as _), expected);
+                        assert_eq!(<$type as Bounded>::MIN.checked_count_digits_radix(radix as _), Some(expected));
+                        assert_eq!(magnitude.count_digits_radix(radix as _), expected);
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _signed_min>]() {
+                    let min = <$non_zero_type>::new(<$type as Bounded>::MIN).unwrap();
+                    for radix in 2..=u8::MAX {
+                        assert_eq!(
+                            min.count_digits_radix(radix as _),
+                            <$type as Bounded>::MIN.count_digits_radix(radix as _),
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn scan_max_count_digits() {
+        let widths = [5, 42, 7, 1000].into_iter().scan_max_count_digits();
+        assert_eq!(widths.collect::<Vec<_>>(), [1, 2, 2, 4]);
+
+        let widths = [-5_i64, 42, -7, -1000, 999, i64::MIN]
+            .iter()
+            .scan_max_count_digits();
+        assert_eq!(widths.collect::<Vec<_>>(), [1, 2, 2, 4, 4, 19]);
+
+        let widths = [NonZeroI8::new(-100).unwrap(), NonZeroI8::new(5).unwrap()]
+            .into_iter()
+            .scan_max_count_digits();
+        assert_eq!(widths.collect::<Vec<_>>(), [3, 3]);
+
+        let mut widths = core::iter::empty::<u32>().scan_max_count_digits();
+        assert_eq!(widths.size_hint(), (0, Some(0)));
+        assert_eq!(widths.next(), None);
+    }
+
+    macro_rules! count_digits_in_first_valid_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_in_first_valid_radix>]() {
+                    for radix in 2..20 {
+                        for n in samples!($type, radix) {
+                            let expected = Some(n.count_digits_radix(radix));
+                            assert_eq!(n.count_digits_in_first_valid_radix(&[radix]), expected);
+                            assert_eq!(n.count_digits_in_first_valid_radix(&[0, 1, radix]), expected);
+                            assert_eq!(n.count_digits_in_first_valid_radix(&[1, radix, 0, 2]), expected);
+                            assert_eq!(n.count_digits_in_first_valid_radix(&[0, 1, 0]), None);
+                            assert_eq!(n.count_digits_in_first_valid_radix(&[]), None);
+                            assert_eq!((&n).count_digits_in_first_valid_radix(&[1, radix]), expected);
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_in_first_valid_radix>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            let expected = Some(n.count_digits_radix(radix));
+                            assert_eq!(n.count_digits_in_first_valid_radix(&[0, 1, radix, 2]), expected);
+                            assert_eq!(n.count_digits_in_first_valid_radix(&[1, 1]), None);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn octal_digits_of_ilog2_matches_division() {
+        for ilog2 in 0..128 {
+            assert_eq!(octal_digits_of_ilog2(ilog2), 1 + ilog2 / 3);
+        }
+    }
+
+    macro_rules! is_radix_boundary {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _is_radix_boundary>]() {
+                    for radix in [2, 3, 7, 8, 10, 16, 17, 36] {
+                        for [max, next] in radix_boundaries!($type, radix) {
+                            assert!(max.is_radix_boundary(radix));
+                            assert!(!next.is_radix_boundary(radix));
+                            assert!((&max).is_radix_boundary(radix));
+                            if max > 1 {
+                                assert!(!(max - 1).is_radix_boundary(radix));
+                            }
+                            assert!(
+                                max.count_digits_radix(radix) < next.count_digits_radix(radix)
+                            );
+                        }
+                        assert!(!(0 as $type).is_radix_boundary(radix));
+                        for n in (0 as $type)..(radix as $type).min(<$type as Bounded>::MAX) {
+                            assert_eq!(n.is_radix_boundary(radix), n == radix as $type - 1);
+                        }
+                    }
+                    assert!(<$type as Bounded>::MAX.is_radix_boundary(2));
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _is_radix_boundary>]() {
+                    for radix in [2, 3, 7, 8, 10, 16, 17, 36] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.is_radix_boundary(radix), n.is_radix_boundary(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn is_radix_boundary_negative() {
+        for radix in [2, 3, 10, 16] {
+            for n in i8::MIN..0 {
+                assert!(!n.is_radix_boundary(radix));
+            }
+        }
+        assert!(u128::MAX.is_radix_boundary(2));
+        assert!(u128::MAX.is_radix_boundary(16));
+        assert!(!u128::MAX.is_radix_boundary(10));
+        assert!(i128::MAX.is_radix_boundary(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn is_radix_boundary_invalid_radix() {
+        (-1_i32).is_radix_boundary(1);
+    }
+
+    /// Returns the most-significant digit of the magnitude by repeated division.
+    fn most_significant_digit_by_division(mut magnitude: u128, radix: u128) -> u8 {
+        while magnitude >= radix {
+            magnitude /= radix;
+        }
+        magnitude as u8
+    }
+
+    macro_rules! count_and_msd_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_and_msd_radix>]() {
+                    for radix in (2..=36).chain([100, 127]) {
+                        for n in samples!($type, radix) {
+                            let magnitude = if radix == 10 {
+                                n.abs_diff(0) as u128
+                            } else {
+                                n.bit_pattern_as_unsigned() as u128
+                            };
+                            let (count, leading_digit) = n.count_and_msd_radix(radix);
+                            assert_eq!(count, n.count_digits_radix(radix));
+                            assert_eq!(leading_digit, most_significant_digit_by_division(magnitude, radix as u128));
+                            assert_eq!((&n).count_and_msd_radix(radix), (count, leading_digit));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_and_msd_radix>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_and_msd_radix(radix), n.count_and_msd_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn count_and_msd_radix_invalid_radix() {
+        1_u32.count_and_msd_radix(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be at most 256 for the leading digit to fit in u8")]
+    fn count_and_msd_radix_257() {
+        1_u32.count_and_msd_radix(257);
+    }
+
+    macro_rules! count_and_is_power_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_and_is_power_radix>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let magnitude = if radix == 10 {
+                                n.abs_diff(0) as u128
+                            } else {
+                                n.bit_pattern_as_unsigned() as u128
+                            };
+                            let is_power = core::iter::successors(Some(1_u128), |power| power.checked_mul(radix as u128))
+                                .take_while(|&power| power <= magnitude)
+                                .any(|power| power == magnitude);
+                            assert_eq!(n.count_and_is_power_radix(radix), (n.count_digits_radix(radix), is_power), "{n} radix {radix}");
+                            assert_eq!((&n).count_and_is_power_radix(radix), (n.count_digits_radix(radix), is_power));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_and_is_power_radix>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_and_is_power_radix(radix), n.count_and_is_power_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn count_and_is_power_radix_cases() {
+        assert_eq!(0_u8.count_and_is_power_radix(10), (1, false));
+        assert_eq!(1_u8.count_and_is_power_radix(10), (1, true));
+        assert_eq!(99_u8.count_and_is_power_radix(10), (2, false));
+        assert_eq!(100_u8.count_and_is_power_radix(10), (3, true));
+        assert_eq!(101_u8.count_and_is_power_radix(10), (3, false));
+        assert_eq!(0xFFF_u16.count_and_is_power_radix(16), (3, false));
+        assert_eq!(0x1000_u16.count_and_is_power_radix(16), (4, true));
+        assert_eq!(0x1001_u16.count_and_is_power_radix(16), (4, false));
+        assert_eq!((1_u128 << 127).count_and_is_power_radix(2), (128, true));
+        assert_eq!(10_u128.pow(38).count_and_is_power_radix(10), (39, true));
+        assert_eq!((-1_i8).count_and_is_power_radix(2), (8, false));
+        assert_eq!(None::<u8>.count_and_is_power_radix(10), (0, false));
+    }
+
+    macro_rules! count_bits_leading_zeros {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_bits_leading_zeros>]() {
+                    for radix in [2, 3, 10, 16] {
+                        for n in samples!($type, radix) {
+                            let ilog2_formula = if n < 0 as $type {
+                                <$type>::BITS
+                            } else {
+                                1 + n.checked_ilog2().unwrap_or_default()
+                            };
+                            assert_eq!(n.count_bits(), ilog2_formula);
+                            assert_eq!(n.count_bits() as usize, format!("{n:b}").len());
+                        }
+                    }
+                    assert_eq!((0 as $type).count_bits(), 1);
+                }
+            }
+        };
+    }
+
+    macro_rules! value_with_digits_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _value_with_digits_radix>]() {
+                    for radix in 2..=36_u32 {
+                        for n in samples!($type, radix) {
+                            let value = if radix == 10 {
+                                n.abs_diff(0) as u128
+                            } else {
+                                n.bit_pattern_as_unsigned() as u128
+                            };
+                            let count = n.count_digits_radix(radix as _) as u32;
+                            assert!(min_value_with_digits_radix(count, radix) <= value);
+                            assert!(value <= max_value_with_digits_radix(count, radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn value_with_digits_radix_inverse() {
+        for radix in 2..=36_u32 {
+            for digits in 1..=u128::MAX.count_digits_radix(radix as u128) as u32 {
+                let min = min_value_with_digits_radix(digits, radix);
+                let max = max_value_with_digits_radix(digits, radix);
+                assert_eq!(min.count_digits_radix(radix as u128), digits as usize);
+                assert_eq!(max.count_digits_radix(radix as u128), digits as usize);
+                if let Some(next) = max.checked_add(1) {
+                    assert_eq!(next, min_value_with_digits_radix(digits + 1, radix));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn index_width_radix() {
+        let widths = [0, 1, 10, 11, 100, 101].map(|count| super::index_width_radix(count, 10));
+        assert_eq!(widths, [1, 1, 1, 2, 2, 3]);
+        assert_eq!(super::index_width_radix(2, 2), 1);
+        assert_eq!(super::index_width_radix(3, 2), 2);
+        assert_eq!(super::index_width_radix(u128::MAX, 2), 128);
+        for radix in 2..=36_u32 {
+            for count in 0..1000_u128 {
+                let expected = match count {
+                    0 => 1,
+                    _ => (0..count)
+                        .map(|index| index.count_digits_radix(radix as u128))
+                        .max()
+                        .unwrap(),
+                };
+                assert_eq!(super::index_width_radix(count, radix), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn index_width_radix_invalid_radix() {
+        super::index_width_radix(10, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "no u128 value has the given count of digits")]
+    fn min_value_with_digits_radix_overflow() {
+        min_value_with_digits_radix(130, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn max_value_with_digits_radix_invalid_radix() {
+        max_value_with_digits_radix(3, 1);
+    }
+
+    macro_rules! count_digits_radix_min {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_min>]() {
+                    for radix in 2..20 {
+                        for n in samples!($type, radix) {
+                            let count = n.count_digits_radix(radix);
+                            assert_eq!(n.count_digits_radix_min(radix, 0), count);
+                            assert_eq!(n.count_digits_radix_min(radix, count - 1), count);
+                            assert_eq!(n.count_digits_radix_min(radix, count), count);
+                            assert_eq!(n.count_digits_radix_min(radix, count + 1), count + 1);
+                            assert_eq!((&n).count_digits_radix_min(radix, count + 2), count + 2);
+                        }
+                    }
+                    for n in samples!($type, 10) {
+                        let count = n.count_digits();
+                        assert_eq!(n.count_digits_min(count - 1), count);
+                        assert_eq!(n.count_digits_min(count), count);
+                        assert_eq!(n.count_digits_min(count + 1), count + 1);
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_min>]() {
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            let count = non_zero.count_digits_radix(radix);
+                            assert_eq!(non_zero.count_digits_radix_min(radix, count - 1), count);
+                            assert_eq!(non_zero.count_digits_radix_min(radix, count + 1), count + 1);
+                            assert_eq!(non_zero.count_digits_min(40), 40);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! count_digits_of_abs_diff {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_of_abs_diff>]() {
+                    for radix in 2..20 {
+                        let samples = samples!($type, radix).collect::<Vec<_>>();
+                        for &a in &samples {
+                            assert_eq!(a.count_digits_of_abs_diff(a), 1);
+                            assert_eq!(a.count_digits_of_abs_diff_radix(a, radix), 1);
+                            for &b in &samples {
+                                let diff = a.abs_diff(b);
+                                assert_eq!(a.count_digits_of_abs_diff(b), diff.count_digits());
+                                assert_eq!(b.count_digits_of_abs_diff(a), diff.count_digits());
+                                assert_eq!(
+                                    a.count_digits_of_abs_diff_radix(b, radix),
+                                    diff.count_digits_radix(radix)
+                                );
+                                assert_eq!((&a).count_digits_of_abs_diff(&b), diff.count_digits());
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_of_abs_diff>]() {
+                    for radix in 2..20 {
+                        for a in radix_boundaries!($type, radix).flatten() {
+                            for b in radix_boundaries!($type, radix).flatten() {
+                                let (x, y) = ($non_zero_type::new(a).unwrap(), $non_zero_type::new(b).unwrap());
+                                assert_eq!(x.count_digits_of_abs_diff(y), a.count_digits_of_abs_diff(b));
+                                assert_eq!(
+                                    x.count_digits_of_abs_diff_radix(y, radix),
+                                    a.count_digits_of_abs_diff_radix(b, radix)
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn count_digits_of_abs_diff_spanning_zero() {
+        assert_eq!((-1_i32).count_digits_of_abs_diff(1), 1);
+        assert_eq!((-5_i32).count_digits_of_abs_diff(5), 2);
+        assert_eq!((-500_i32).count_digits_of_abs_diff(500), 4);
+        assert_eq!(
+            i128::MIN.count_digits_of_abs_diff(i128::MAX),
+            u128::MAX.count_digits()
+        );
+        assert_eq!((-8_i8).count_digits_of_abs_diff_radix(8, 2), 5);
+        assert_eq!(
+            NonZeroI64::new(-1)
+                .unwrap()
+                .count_digits_of_abs_diff(NonZeroI64::new(99).unwrap()),
+            3
+        );
+    }
+
+    macro_rules! count_digits_radix_abs {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_abs>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let expected = n.abs_diff(0).count_digits_radix(radix as _);
+                            assert_eq!(n.count_digits_radix_abs(radix), expected);
+                            assert_eq!((&n).count_digits_radix_abs(radix), expected);
+                            if radix == 10 {
+                                assert_eq!(n.count_digits_radix_abs(radix), n.count_digits());
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_abs>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_digits_radix_abs(radix), n.count_digits_radix_abs(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn count_digits_radix_abs_negative() {
+        for radix in 2..=36_u8 {
+            for n in i8::MIN..0 {
+                assert_eq!(
+                    n.count_digits_radix_abs(radix),
+                    n.unsigned_abs().count_digits_radix(radix)
+                );
+            }
+        }
+        assert_eq!((-255_i16).count_digits_radix_abs(16), 2);
+        assert_eq!((-1_i128).count_digits_radix_abs(2), 1);
+        assert_eq!(i128::MIN.count_digits_radix_abs(2), 128);
+    }
+
+    #[cfg(feature = "ethnum")]
+    /// Returns the powers of the radix that fit in a [U256](ethnum::U256), along with their neighbors,
+    /// and a few values with bits set in both halves.
+    fn u256_samples(radix: u128) -> Vec<ethnum::U256> {
+        use ethnum::U256;
+        let powers =
+            core::iter::successors(Some(U256::ONE), |power| power.checked_mul(U256::new(radix)));
+        powers
+            .flat_map(|power| [power - 1, power, power.saturating_add(U256::ONE)])
+            .chain([
+                U256::MAX,
+                U256::MAX >> 1,
+                U256::from_words(0xDEAD_BEEF, 0xF00D),
+                U256::from_words(1, 0),
+                U256::from_words(0, u128::MAX),
+            ])
+            .collect()
+    }
+
+    #[cfg(feature = "ethnum")]
+    /// Returns the count of digits in a magnitude, which is the count of powers of the radix that are at most the magnitude.
+    fn u256_count_digits_by_pow(magnitude: ethnum::U256, radix: ethnum::U256) -> usize {
+        let mut count = 1;
+        let mut power = radix;
+        while power <= magnitude {
+            count += 1;
+            match power.checked_mul(radix) {
+                Some(next) => power = next,
+                None => break,
+            }
+        }
+        count
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    fn u256_count_digits_radix() {
+        use ethnum::U256;
+        for radix in 2..=300_u128 {
+            for n in u256_samples(radix) {
+                let expected = u256_count_digits_by_pow(n, U256::new(radix));
+                assert_eq!(
+                    n.count_digits_radix(U256::new(radix)),
+                    expected,
+                    "{n} radix {radix}"
+                );
+                assert_eq!(
+                    n.checked_count_digits_radix(U256::new(radix)),
+                    Some(expected)
+                );
+                if radix <= 256 {
+                    let digits = n.digits_radix_le(U256::new(radix)).collect::<Vec<_>>();
+                    assert_eq!(digits.len(), expected);
+                    let value = digits
+                        .iter()
+                        .rev()
+                        .fold(U256::ZERO, |value, &digit| value * radix + digit as u128);
+                    assert_eq!(value, n);
+                }
+            }
+        }
+        for radix in [
+            U256::from_words(1, 0),
+            U256::from_words(1 << 100, 7),
+            U256::MAX,
+        ] {
+            for n in u256_samples(10) {
+                assert_eq!(
+                    n.count_digits_radix(radix),
+                    u256_count_digits_by_pow(n, radix)
+                );
+            }
+        }
+        assert_eq!(U256::ZERO.checked_count_digits_radix(U256::ONE), None);
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    fn u256_formatted_widths() {
+        use ethnum::U256;
+        for n in u256_samples(2).into_iter().chain(u256_samples(10)) {
+            assert_eq!(n.count_bits() as usize, format!("{n:b}").len());
+            assert_eq!(n.count_octal_digits() as usize, format!("{n:o}").len());
+            assert_eq!(n.count_digits(), format!("{n}").len());
+            assert_eq!(n.count_hex_digits() as usize, format!("{n:x}").len());
+            for (radix, string) in [
+                (2, format!("{n:b}")),
+                (10, format!("{n}")),
+                (16, format!("{n:X}")),
+            ] {
+                let mut buf = [0_u8; 257];
+                let len = n
+                    .write_ascii_digits_radix(U256::new(radix), true, &mut buf)
+                    .unwrap();
+                assert_eq!(&buf[..len], string.as_bytes());
+            }
+        }
+        assert_eq!(U256::MAX.count_digits(), 78);
+        assert_eq!(U256::MAX.count_bits(), 256);
+        assert_eq!(U256::MAX.count_octal_digits(), 86);
+        assert_eq!(U256::MAX.count_hex_digits(), 64);
+        assert_eq!(U256::MAX.count_digits_of_sum(U256::MAX), 78);
+        assert_eq!(U256::MAX.count_digits_of_sum(U256::ZERO), 78);
+        assert_eq!(
+            U256::from_words(0, 1).count_digits_radix_width(U256::new(2), 1),
+            1
+        );
+        assert_eq!(U256::MAX.count_digits_radix_width(U256::new(16), 256), 64);
+        assert!(U256::MAX.is_radix_boundary(U256::new(2)));
+        assert!(U256::from_words(1, 0).is_radix_width_floor(U256::new(2)));
+        assert_eq!(
+            U256::from_words(1, 0).count_and_is_power_radix(U256::new(2)),
+            (129, true)
+        );
+        assert_eq!(U256::MAX.count_and_msd_radix(U256::new(10)), (78, 1));
+        assert_eq!(
+            U256::MAX.palindromic_rotation_count_radix(U256::new(2)),
+            256
+        );
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    fn i256_min_and_max() {
+        use ethnum::{I256, U256};
+        assert_eq!(I256::MIN.count_digits(), format!("{}", I256::MIN).len() - 1);
+        assert_eq!(I256::MAX.count_digits(), format!("{}", I256::MAX).len());
+        assert_eq!(I256::MIN.count_bits(), 256);
+        assert_eq!(I256::MAX.count_bits(), 255);
+        assert_eq!(I256::MIN.count_octal_digits(), 86);
+        assert_eq!(I256::MIN.count_hex_digits(), 64);
+        assert_eq!(I256::MINUS_ONE.count_digits_radix(U256::new(2)), 256);
+        for (n, radix, written) in [
+            (I256::new(-1337), 16, format!("{:x}", I256::new(-1337))),
+            (I256::MIN, 2, format!("{:b}", I256::MIN)),
+            (I256::MIN, 10, I256::MIN.to_string()[1..].to_string()),
+            (I256::ZERO, 7, "0".to_string()),
+        ] {
+            let mut digits = n.digits_radix_le(U256::new(radix)).collect::<Vec<_>>();
+            digits.reverse();
+            let expected = written
+                .chars()
+                .map(|c| c.to_digit(radix as u32).unwrap() as u8)
+                .collect::<Vec<_>>();
+            assert_eq!(digits, expected);
+        }
+        assert_eq!(
+            I256::MINUS_ONE.leading_repeat_count_radix(U256::new(2)),
+            256
+        );
+        assert_eq!(U256::MAX.leading_repeat_count_radix(U256::new(16)), 64);
+        assert_eq!(I256::MIN.min_twos_complement_bits(), 256);
+        assert_eq!(I256::MAX.min_twos_complement_bits(), 256);
+        assert_eq!(I256::new(-129).min_twos_complement_bits(), 9);
+        assert_eq!(U256::MAX.min_twos_complement_bits(), 257);
+        assert_eq!(
+            U256::MAX.count_digits_radix_rounded_to_power(U256::new(2)),
+            257
+        );
+        assert_eq!(
+            I256::MIN.count_digits_radix_rounded_to_power(U256::new(2)),
+            256
+        );
+        assert_eq!(
+            I256::new(-3163).count_digits_radix_rounded_to_power(U256::new(10)),
+            5
+        );
+        assert!(I256::MINUS_ONE.per_byte_decimal_widths().eq([3; 32]));
+        assert!(U256::new(0x01_0A_64_FF)
+            .per_byte_decimal_widths()
+            .eq([3, 3, 2, 1].into_iter().chain([1; 28])));
+        assert!(I256::new(-1337).weighted_digits_radix(U256::new(10)).eq([
+            (1, 1000),
+            (3, 100),
+            (3, 10),
+            (7, 1)
+        ]));
+        assert!(U256::new(u128::MAX)
+            .weighted_digits_radix(U256::new(2))
+            .eq(u128::MAX.weighted_digits_radix(2)));
+        for n in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            assert_eq!(n.debug_width(), format!("{n:?}").len());
+            assert_eq!(
+                n.as_u256().debug_width(),
+                format!("{:?}", n.as_u256()).len()
+            );
+        }
+        assert_eq!(I256::MINUS_ONE.count_bits_of_complement(), 1);
+        assert_eq!(
+            U256::ZERO.count_digits_radix_of_complement(U256::new(16)),
+            64
+        );
+        assert_eq!(I256::MIN.grouped_width_with_sign(3), 77 + 25 + 1);
+        assert_eq!(U256::MAX.grouped_width_with_sign(3), 78 + 25);
+        assert_eq!(I256::max_digit_value(U256::new(36)), 35);
+        assert_eq!(U256::max_digit_value(U256::new(256)), 255);
+        assert_eq!(
+            I256::MIN.common_prefix_len_radix(I256::MINUS_ONE, U256::new(2)),
+            1
+        );
+        assert_eq!(
+            I256::MIN.common_prefix_len_radix(I256::MIN + 1, U256::new(10)),
+            76
+        );
+        assert_eq!(
+            I256::MINUS_ONE.count_digits_radix_ones_complement(U256::new(2)),
+            256
+        );
+        assert_eq!(
+            I256::MIN.count_digits_radix_ones_complement(U256::new(2)),
+            255
+        );
+        assert_eq!(
+            I256::MIN.count_digits_radix_ones_complement(U256::new(16)),
+            64
+        );
+        assert_eq!(I256::MINUS_ONE.count_digits_radix(U256::new(10)), 1);
+        assert_eq!(I256::MINUS_ONE.count_digits_radix_abs(U256::new(16)), 1);
+        assert_eq!(
+            I256::MINUS_ONE.count_digits_radix_width(U256::new(2), 12),
+            12
+        );
+        assert_eq!(I256::MIN.count_digits_of_sum(I256::MIN), 78);
+        assert_eq!(I256::MIN.count_digits_of_sum(I256::MAX), 1);
+        assert_eq!(I256::MIN.count_digits_of_abs_diff(I256::MAX), 78);
+        assert_eq!(
+            I256::MIN.count_decimal_digits_twos_complement(),
+            I256::MIN.bit_pattern_as_unsigned().count_digits()
+        );
+        assert_eq!(I256::MIN.bit_pattern_as_unsigned(), U256::ONE << 255);
+
+        let mut buf = [0_u8; 257];
+        let len = I256::MIN
+            .write_ascii_digits_radix(U256::new(10), false, &mut buf)
+            .unwrap();
+        assert_eq!(&buf[..len], format!("{}", I256::MIN).as_bytes());
+        let len = I256::MIN
+            .write_ascii_digits_radix(U256::new(16), false, &mut buf)
+            .unwrap();
+        assert_eq!(&buf[..len], format!("{:x}", I256::MIN).as_bytes());
+        assert_eq!(
+            I256::MIN.write_ascii_digits_radix(U256::new(10), false, &mut buf[..10]),
+            Err(len_of_min())
+        );
+
+        fn len_of_min() -> usize {
+            format!("{}", ethnum::I256::MIN).len()
+        }
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    fn ethnum_matches_128_bit_integers() {
+        use ethnum::{I256, U256};
+        for radix in 2..=36_u128 {
+            let wide_radix = U256::new(radix);
+            for n in samples!(u128, radix) {
+                let wide = U256::new(n);
+                assert_eq!(wide.count_digits(), n.count_digits());
+                assert_eq!(
+                    wide.count_digits_radix(wide_radix),
+                    n.count_digits_radix(radix)
+                );
+                assert_eq!(
+                    wide.count_balanced_ternary_digits(),
+                    n.count_balanced_ternary_digits()
+                );
+                assert_eq!(wide.count_digits_nonempty(), n.count_digits_nonempty());
+                assert_eq!(
+                    wide.count_digits_radix_nonempty(wide_radix),
+                    n.count_digits_radix_nonempty(radix)
+                );
+                assert_eq!(
+                    wide.is_radix_boundary(wide_radix),
+                    n.is_radix_boundary(radix)
+                );
+                assert_eq!(
+                    wide.count_and_msd_radix(wide_radix),
+                    n.count_and_msd_radix(radix)
+                );
+                assert_eq!(
+                    wide.count_digits_radix_rounded_to_power(wide_radix),
+                    n.count_digits_radix_rounded_to_power(radix)
+                );
+                assert_eq!(
+                    wide.count_and_is_power_radix(wide_radix),
+                    n.count_and_is_power_radix(radix)
+                );
+                assert_eq!(
+                    wide.is_radix_width_floor(wide_radix),
+                    n.is_radix_width_floor(radix)
+                );
+                assert_eq!(wide.count_base36_digits(), n.count_base36_digits());
+                assert_eq!(wide.count_base85_digits(), n.count_base85_digits());
+                assert_eq!(
+                    wide.digit_diff_count_radix(U256::new(n / 3), wide_radix),
+                    n.digit_diff_count_radix(n / 3, radix)
+                );
+                assert_eq!(
+                    wide.count_significant_digits_radix(wide_radix),
+                    n.count_significant_digits_radix(radix)
+                );
+                assert_eq!(
+                    wide.count_significant_digits(),
+                    n.count_significant_digits()
+                );
+                assert_eq!(
+                    wide.palindromic_rotation_count_radix(wide_radix),
+                    n.palindromic_rotation_count_radix(radix)
+                );
+                assert!(wide
+                    .digits_radix_le(wide_radix)
+                    .eq(n.digits_radix_le(radix)));
+            }
+            for n in samples!(i128, radix) {
+                let wide = I256::new(n);
+                let (wide_other, other) = (I256::new(n / 3), n / 3);
+                assert_eq!(wide.count_digits(), n.count_digits());
+                assert_eq!(
+                    wide.count_digits_radix_abs(wide_radix),
+                    n.count_digits_radix_abs(radix)
+                );
+                assert_eq!(
+                    wide.count_balanced_ternary_digits(),
+                    n.count_balanced_ternary_digits()
+                );
+                assert_eq!(wide.count_digits_nonempty(), n.count_digits_nonempty());
+                assert_eq!(
+                    wide.is_radix_width_floor(wide_radix),
+                    n.is_radix_width_floor(radix)
+                );
+                assert_eq!(
+                    wide.count_significant_digits(),
+                    n.count_significant_digits()
+                );
+                assert_eq!(
+                    wide.count_digits_of_sum(wide_other),
+                    n.count_digits_of_sum(other)
+                );
+                assert_eq!(
+                    wide.count_digits_of_abs_diff(wide_other),
+                    n.count_digits_of_abs_diff(other)
+                );
+                assert_eq!(
+                    wide.count_digits_of_abs_diff_radix(wide_other, wide_radix),
+                    n.count_digits_of_abs_diff_radix(other, radix)
+                );
+                if radix == 10 || n >= 0 {
+                    assert_eq!(
+                        wide.count_digits_radix(wide_radix),
+                        n.count_digits_radix(radix)
+                    );
+                    assert_eq!(
+                        wide.is_radix_boundary(wide_radix),
+                        n.is_radix_boundary(radix)
+                    );
+                    assert_eq!(
+                        wide.count_and_msd_radix(wide_radix),
+                        n.count_and_msd_radix(radix)
+                    );
+                    assert_eq!(
+                        wide.count_and_is_power_radix(wide_radix),
+                        n.count_and_is_power_radix(radix)
+                    );
+                    assert_eq!(
+                        wide.count_significant_digits_radix(wide_radix),
+                        n.count_significant_digits_radix(radix)
+                    );
+                    assert_eq!(
+                        wide.palindromic_rotation_count_radix(wide_radix),
+                        n.palindromic_rotation_count_radix(radix)
+                    );
+                    assert!(wide
+                        .digits_radix_le(wide_radix)
+                        .eq(n.digits_radix_le(radix)));
+                    let (mut a, mut b) = ([0_u8; 257], [0_u8; 257]);
+                    assert_eq!(
+                        wide.write_ascii_digits_radix(wide_radix, false, &mut a),
+                        n.write_ascii_digits_radix(radix, false, &mut b)
+                    );
+                    assert_eq!(a, b);
+                } else {
+                    assert_eq!(
+                        wide.count_digits_radix(wide_radix),
+                        wide.bit_pattern_as_unsigned()
+                            .count_digits_radix(wide_radix)
+                    );
+                    assert_eq!(
+                        wide.count_digits_radix_width(wide_radix, 128),
+                        n.count_digits_radix(radix)
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "ethnum", feature = "alloc"))]
+    #[test]
+    fn ethnum_to_string_radix() {
+        use ethnum::{I256, U256};
+        assert_eq!(
+            U256::MAX.to_string_radix(U256::new(10), false),
+            format!("{}", U256::MAX)
+        );
+        assert_eq!(
+            U256::MAX.to_string_radix(U256::new(16), true),
+            format!("{:X}", U256::MAX)
+        );
+        assert_eq!(
+            I256::MIN.to_string_radix(U256::new(10), false),
+            format!("{}", I256::MIN)
+        );
+        assert_eq!(
+            I256::MINUS_ONE.to_string_radix(U256::new(2), false),
+            "1".repeat(256)
+        );
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    #[should_panic(expected = "radix must be at most 36 to render digits")]
+    fn u256_write_ascii_digits_radix_37() {
+        ethnum::U256::ONE
+            .write_ascii_digits_radix(ethnum::U256::new(37), false, &mut [0; 8])
+            .ok();
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    #[should_panic(expected = "magnitude must fit in u128 for its digit weights to fit in u128")]
+    fn u256_weighted_digits_radix_beyond_u128() {
+        ethnum::U256::MAX
+            .weighted_digits_radix(ethnum::U256::new(10))
+            .count();
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_count_digits_sum_matches_sequential_sum() {
+        let slice = (0..64)
+            .flat_map(|shift| [1_u64 << shift, (1_u64 << shift) - 1, u64::MAX >> shift])
+            .chain(radix_boundaries!(u64, 10).flatten())
+            .collect::<Vec<_>>();
+        let sequential = slice.iter().map(|n| n.count_digits() as u128).sum::<u128>();
+        assert_eq!(par_count_digits_sum(&slice), sequential);
+        assert_eq!(par_count_digits_sum(&slice[..1]), 1);
+        assert_eq!(par_count_digits_sum(&[]), 0);
+    }
+
+    #[test]
+    fn total_count_digits() {
+        let array = [0_i32, -1, 9, -10, 99, -100, i32::MIN, i32::MAX];
+        let expected = array.iter().map(|n| n.count_digits()).sum::<usize>();
+        assert_eq!(expected, 1 + 1 + 1 + 2 + 2 + 3 + 10 + 10);
+        assert_eq!(array.total_count_digits(), expected);
+        assert_eq!(array[..].total_count_digits(), expected);
+        assert_eq!(array[2..4].total_count_digits(), 3);
+        assert_eq!(<[i8; 0]>::default().total_count_digits(), 0);
+        assert_eq!(<&[u64]>::default().total_count_digits(), 0);
+
+        let non_zero = [NonZeroI16::new(-300).unwrap(), NonZeroI16::new(5).unwrap()];
+        assert_eq!(non_zero.total_count_digits(), 4);
+        assert_eq!([&-12_i64, &345].total_count_digits(), 5);
+
+        assert_eq!(array.max_count_digits(), Some(10));
+        assert_eq!(array[2..4].max_count_digits(), Some(2));
+        assert_eq!(<[i8; 0]>::default().max_count_digits(), None);
+        assert_eq!(non_zero.max_count_digits(), Some(3));
+        assert_eq!(array.iter().total_count_digits(), expected);
+        assert_eq!(core::iter::empty::<u8>().total_count_digits(), 0);
+    }
+
+    #[test]
+    fn tuple_total_count_digits() {
+        assert_eq!((5_u8,).total_count_digits(), 1);
+        assert_eq!((-12_i8, 1337_u64).total_count_digits(), 6);
+        assert_eq!((-12_i8, 1337_u64).max_count_digits(), Some(4));
+        assert_eq!(
+            (0_u8, -100_i32, <NonZeroU16 as Bounded>::MAX).total_count_digits(),
+            1 + 3 + 5
+        );
+        assert_eq!(
+            (0_u8, -100_i32, <NonZeroU16 as Bounded>::MAX).max_count_digits(),
+            Some(5)
+        );
+        assert_eq!(
+            (i128::MIN, &-1_i16, Some(42_usize)).total_count_digits(),
+            39 + 1 + 2
+        );
+
+        let six = (1_u8, -22_i16, 333_u32, -4444_i64, 55555_u128, 666666_usize);
+        assert_eq!(six.total_count_digits(), 1 + 2 + 3 + 4 + 5 + 6);
+        assert_eq!(six.max_count_digits(), Some(6));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vec_total_count_digits() {
+        let vec = vec![0_u16, 9, 10, 999, 1000, u16::MAX];
+        assert_eq!(vec.total_count_digits(), 1 + 1 + 2 + 3 + 4 + 5);
+        assert_eq!(vec.max_count_digits(), Some(5));
+        assert_eq!(
+            CountDigitsAggregate::total_count_digits(&vec),
+            vec.iter().total_count_digits()
+        );
+        assert_eq!(
+            CountDigitsAggregate::max_count_digits(&vec),
+            vec.iter().max_count_digits()
+        );
+
+        let empty = Vec::<u16>::new();
+        assert_eq!(empty.total_count_digits(), 0);
+        assert_eq!(empty.max_count_digits(), None);
+    }
+
+    macro_rules! count_digits_radix_width {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_width>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            assert_eq!(
+                                n.count_digits_radix_width(radix, <$type>::BITS),
+                                n.count_digits_radix(radix)
+                            );
+                            assert_eq!(
+                                (&n).count_digits_radix_width(radix, <$type>::BITS),
+                                n.count_digits_radix(radix)
+                            );
+                        }
+                    }
+                    for bit_width in 1..=<$type>::BITS {
+                        assert_eq!((0 as $type).count_digits_radix_width(2, bit_width), 1);
+                    }
+                    let narrower = <$type>::BITS - 1;
+                    assert!(std::panic::catch_unwind(|| <$type as Bounded>::MAX.count_digits_radix_width(2, narrower)).is_err());
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_width>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(
+                                non_zero.count_digits_radix_width(radix, <$type>::BITS),
+                                n.count_digits_radix_width(radix, <$type>::BITS)
+                            );
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! count_digits_radix_ones_complement {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_ones_complement>]() {
+                    for radix in [2, 4, 8, 16, 32, 64] {
+                        for n in samples!($type, radix) {
+                            // Only the minimum value of a signed type is counted differently from twos complement.
+                            let expected = match n == <$type as Bounded>::MIN && n != 0 {
+                                true => <$type as Bounded>::MAX.count_digits_radix(radix),
+                                false => n.count_digits_radix(radix),
+                            };
+                            assert_eq!(n.count_digits_radix_ones_complement(radix), expected);
+                            assert_eq!((&n).count_digits_radix_ones_complement(radix), expected);
+                            assert_eq!(Some(n).count_digits_radix_ones_complement(radix), expected);
+                        }
+                    }
+                    assert_eq!(None::<$type>.count_digits_radix_ones_complement(2), 0);
+                    assert!(std::panic::catch_unwind(|| (1 as $type).count_digits_radix_ones_complement(10)).is_err());
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_ones_complement>]() {
+                    for radix in [2, 4, 8, 16, 32, 64] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(
+                                non_zero.count_digits_radix_ones_complement(radix),
+                                n.count_digits_radix_ones_complement(radix)
+                            );
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn count_digits_radix_ones_complement_hand_computed() {
+        // -1 is 0b1111_1110, -5 is 0b1111_1010, and -127 is 0b1000_0000 in 8-bit ones' complement.
+        for n in [-1_i8, -5, -127] {
+            assert_eq!(n.count_digits_radix_ones_complement(2), 8);
+            assert_eq!(n.count_digits_radix_ones_complement(8), 3);
+            assert_eq!(n.count_digits_radix_ones_complement(16), 2);
+        }
+        // The complement of the magnitude of -128 is 0b0111_1111.
+        assert_eq!(i8::MIN.count_digits_radix_ones_complement(2), 7);
+        assert_eq!(i8::MIN.count_digits_radix_ones_complement(8), 3);
+        assert_eq!(i8::MIN.count_digits_radix_ones_complement(16), 2);
+        // The complement of the magnitude of -32768 is 0x7FFF, which is 0o77777.
+        assert_eq!(i16::MIN.count_digits_radix_ones_complement(2), 15);
+        assert_eq!(i16::MIN.count_digits_radix_ones_complement(8), 5);
+        assert_eq!(i16::MIN.count_digits_radix_ones_complement(16), 4);
+        assert_eq!(i16::MIN.count_digits_radix(8), 6);
+        // -1024 is 0xFBFF in 16-bit ones' complement.
+        assert_eq!((-1024_i16).count_digits_radix_ones_complement(8), 6);
+        assert_eq!((-1024_i16).count_digits_radix_ones_complement(16), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be a power of two")]
+    fn count_digits_radix_ones_complement_radix_10() {
+        (-1_i32).count_digits_radix_ones_complement(10);
+    }
+
+    #[test]
+    fn count_digits_radix_width_12_bit_register() {
+        for n in -2048_i16..2048 {
+            let pattern = (n as u16) & 0x0FFF;
+            for radix in [2, 8, 16] {
+                assert_eq!(
+                    n.count_digits_radix_width(radix, 12),
+                    pattern.count_digits_radix(radix)
+                );
+            }
+            assert_eq!(n.count_digits_radix_width(10, 12), n.count_digits());
+            if n < 0 {
+                assert_eq!(n.count_digits_radix_width(2, 12), 12);
+                assert_eq!(n.count_digits_radix_width(16, 12), 3);
+                assert_eq!(n.count_digits_radix_width(8, 12), 4);
+            }
+        }
+        assert_eq!((-1_i128).count_digits_radix_width(2, 1), 1);
+        assert_eq!((-1_i128).count_digits_radix_width(2, 128), 128);
+        assert_eq!(0xFFF_u16.count_digits_radix_width(16, 12), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit in the given bit width")]
+    fn count_digits_radix_width_signed_overflow() {
+        2048_i16.count_digits_radix_width(2, 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit in the given bit width")]
+    fn count_digits_radix_width_signed_underflow() {
+        (-2049_i16).count_digits_radix_width(2, 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit in the given bit width")]
+    fn count_digits_radix_width_unsigned_overflow() {
+        0x1000_u16.count_digits_radix_width(16, 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit width must be between 1 and the width of the type")]
+    fn count_digits_radix_width_too_wide() {
+        1_i16.count_digits_radix_width(2, 17);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit width must be between 1 and the width of the type")]
+    fn count_digits_radix_width_zero() {
+        0_u16.count_digits_radix_width(2, 0);
+    }
+
+    /// Returns the count of separators in the string of decimal digits grouped by the given pattern.
+    fn grouped_string_separator_count(digits: &str, pattern: &[u32]) -> usize {
+        let mut grouped = String::new();
+        let mut sizes = pattern
+            .iter()
+            .chain(core::iter::repeat(pattern.last().unwrap()));
+        let mut size = *sizes.next().unwrap();
+        let mut in_group = 0;
+        for digit in digits.chars().rev() {
+            if in_group == size {
+                grouped.push(',');
+                size = *sizes.next().unwrap();
+                in_group = 0;
+            }
+            grouped.push(digit);
+            in_group += 1;
+        }
+        grouped.matches(',').count()
+    }
+
+    macro_rules! count_separators_pattern {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_separators_pattern>]() {
+                    let patterns: [&[u32]; 5] = [&[3], &[3, 2], &[1], &[4], &[2, 1, 3]];
+                    for n in samples!($type, 10) {
+                        let digits = n.abs_diff(0).to_string();
+                        for pattern in patterns {
+                            assert_eq!(
+                                n.count_separators_pattern(pattern),
+                                grouped_string_separator_count(&digits, pattern)
+                            );
+                        }
+                        assert_eq!((&n).count_separators_pattern(&[]), 0);
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn count_separators_pattern_western_and_indian() {
+        let cases: [(u64, usize, usize); 8] = [
+            (0, 0, 0),
+            (999, 0, 0),
+            (1_000, 1, 1),
+            (99_999, 1, 1),
+            (100_000, 1, 2),
+            (1_000_000, 2, 2),
+            (10_000_000, 2, 3),
+            (1_000_000_000, 3, 4),
+        ];
+        for (n, western, indian) in cases {
+            assert_eq!(n.count_separators_pattern(&[3]), western);
+            assert_eq!(n.count_separators_pattern(&[3, 2]), indian);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "group sizes must be at least 1")]
+    fn count_separators_pattern_zero_group() {
+        1_000_000_u32.count_separators_pattern(&[3, 0]);
+    }
+
+    /// Returns the decimal string of an integer with a comma between each group of `group_size` digits.
+    fn grouped_decimal_string(decimal: &str, group_size: usize) -> String {
+        let (sign, digits) = match decimal.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", decimal),
+        };
+        let mut grouped = String::from(sign);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % group_size == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    macro_rules! grouped_width_with_sign {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _grouped_width_with_sign>]() {
+                    for n in samples!($type, 10) {
+                        for group_size in 1..=5 {
+                            let grouped = grouped_decimal_string(&n.to_string(), group_size);
+                            assert_eq!(n.grouped_width_with_sign(group_size as u32), grouped.len());
+                            assert_eq!((&n).grouped_width_with_sign(group_size as u32), grouped.len());
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _grouped_width_with_sign>]() {
+                    for n in radix_boundaries!($type, 10).flatten() {
+                        let non_zero = $non_zero_type::new(n).unwrap();
+                        assert_eq!(non_zero.grouped_width_with_sign(3), n.grouped_width_with_sign(3));
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! debug_width {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _debug_width>]() {
+                    for radix in [2, 10, 16] {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.debug_width(), format!("{n:?}").len());
+                            assert_eq!((&n).debug_width(), format!("{:?}", &n).len());
+                            assert_eq!(Some(n).debug_width(), format!("{:?}", Some(n)).len());
+                            assert_eq!(Reverse(n).debug_width(), format!("{:?}", Reverse(n)).len());
+                            assert_eq!(Some(Reverse(n)).debug_width(), format!("{:?}", Some(Reverse(n))).len());
+                        }
+                    }
+                    assert_eq!(None::<$type>.debug_width(), format!("{:?}", None::<$type>).len());
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _debug_width>]() {
+                    for radix in [2, 10, 16] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.debug_width(), format!("{non_zero:?}").len());
+                            assert_eq!(Some(non_zero).debug_width(), format!("{:?}", Some(non_zero)).len());
+                        }
+                    }
+                    for non_zero in [<$non_zero_type as Bounded>::MIN, <$non_zero_type as Bounded>::MAX] {
+                        assert_eq!(non_zero.debug_width(), format!("{non_zero:?}").len());
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! count_digits_base {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_base>]() {
+                    for radix in [2, 8, 10, 16] {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.count_digits_base(CommonBase::Binary), n.count_bits() as usize);
+                            assert_eq!(n.count_digits_base(CommonBase::Octal), n.count_octal_digits() as usize);
+                            assert_eq!(n.count_digits_base(CommonBase::Decimal), n.count_digits());
+                            assert_eq!(n.count_digits_base(CommonBase::Hexadecimal), n.count_hex_digits() as usize);
+                            for (base, radix) in [(CommonBase::Binary, 2), (CommonBase::Octal, 8), (CommonBase::Decimal, 10), (CommonBase::Hexadecimal, 16)] {
+                                assert_eq!(n.count_digits_base(base), n.count_digits_radix(radix));
+                                assert_eq!((&n).count_digits_base(base), n.count_digits_radix(radix));
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_base>]() {
+                    for n in radix_boundaries!($type, 2).flatten() {
+                        let non_zero = $non_zero_type::new(n).unwrap();
+                        for base in [CommonBase::Binary, CommonBase::Octal, CommonBase::Decimal, CommonBase::Hexadecimal] {
+                            assert_eq!(non_zero.count_digits_base(base), n.count_digits_base(base));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn grouped_width_with_sign_negative_values() {
+        assert_eq!(grouped_decimal_string("-1234567", 3), "-1,234,567");
+        assert_eq!((-1_234_567_i32).grouped_width_with_sign(3), 10);
+        assert_eq!((-123_456_i32).grouped_width_with_sign(3), 8);
+        assert_eq!((-1_i8).grouped_width_with_sign(3), 2);
+        assert_eq!(i8::MIN.grouped_width_with_sign(1), 6);
+        assert_eq!(
+            i64::MIN.to_string().len() + 6,
+            i64::MIN.grouped_width_with_sign(3)
+        );
+        assert_eq!(0_i32.grouped_width_with_sign(3), 1);
+        assert_eq!(None::<i32>.grouped_width_with_sign(3), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "group sizes must be at least 1")]
+    fn grouped_width_with_sign_zero_group() {
+        (-1_i32).grouped_width_with_sign(0);
+    }
+
+    macro_rules! cmp_by_digit_width {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _cmp_by_digit_width>]() {
+                    for radix in 2..20 {
+                        let samples = samples!($type, radix).collect::<Vec<_>>();
+                        for &a in &samples {
+                            for &b in &samples {
+                                let expected = (a.count_digits_radix(radix), a)
+                                    .cmp(&(b.count_digits_radix(radix), b));
+                                assert_eq!(a.cmp_by_digit_width_radix(b, radix), expected);
+                                assert_eq!((&a).cmp_by_digit_width_radix(&b, radix), expected);
+                                assert_eq!(
+                                    a.cmp_by_digit_width(b),
+                                    (a.count_digits(), a).cmp(&(b.count_digits(), b))
+                                );
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _cmp_by_digit_width>]() {
+                    for radix in 2..20 {
+                        for a in radix_boundaries!($type, radix).flatten() {
+                            for b in radix_boundaries!($type, radix).flatten() {
+                                let (x, y) = ($non_zero_type::new(a).unwrap(), $non_zero_type::new(b).unwrap());
+                                assert_eq!(x.cmp_by_digit_width_radix(y, radix), a.cmp_by_digit_width_radix(b, radix));
+                                assert_eq!(x.cmp_by_digit_width(y), a.cmp_by_digit_width(b));
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn cmp_by_digit_width_ties_broken_by_value() {
+        assert_eq!(42_i32.cmp_by_digit_width(-42), Ordering::Greater);
+        assert_eq!(42_i32.cmp_by_digit_width(42), Ordering::Equal);
+        assert_eq!(9_u32.cmp_by_digit_width(10), Ordering::Less);
+        assert_eq!(1000_u32.cmp_by_digit_width(999), Ordering::Greater);
+        assert_eq!((-1000_i32).cmp_by_digit_width(999), Ordering::Greater);
+        assert_eq!((-1_i8).cmp_by_digit_width_radix(100, 2), Ordering::Greater);
+
+        let mut numbers = vec![1000_i64, -7, 55, 3, -55, 0, 999];
+        numbers.sort_by(|a, b| a.cmp_by_digit_width(b));
+        assert_eq!(numbers, [-7, 0, 3, -55, 55, 999, 1000]);
+    }
+
+    #[test]
+    fn joined_width() {
+        for sep_len in [0, 1, 2, 5] {
+            let separator = " ".repeat(sep_len);
+            assert_eq!(super::joined_width(Vec::<u32>::new(), sep_len), 0);
+            assert_eq!(super::joined_width([0_u8], sep_len), 1);
+            assert_eq!(super::joined_width([u64::MAX], sep_len), 20);
+
+            let numbers = [7_u32, 0, 42, 1000, u32::MAX];
+            let joined = numbers.map(|n| n.to_string()).join(&separator);
+            assert_eq!(super::joined_width(numbers, sep_len), joined.len());
+            assert_eq!(super::joined_width(numbers.iter(), sep_len), joined.len());
+
+            let signed = [-5_i16, 10, -100];
+            let expected = 1 + 2 + 3 + 2 * sep_len;
+            assert_eq!(super::joined_width(signed, sep_len), expected);
+        }
+    }
+
+    #[test]
+    fn alphabet_base62_and_base85() {
+        let base62 = Alphabet::new(62).unwrap();
+        let base85 = Alphabet::new(85).unwrap();
+        assert_eq!(base62.len(), 62);
+        assert_eq!(base85.len(), 85);
+        for radix in [62_u32, 85] {
+            let alphabet = Alphabet::new(radix).unwrap();
+            for n in samples!(u64, radix) {
+                assert_eq!(alphabet.count(n), n.count_digits_radix(radix as u64));
+                assert_eq!(alphabet.count(Some(n)), n.count_digits_radix(radix as u64));
+            }
+            for n in samples!(i32, radix) {
+                assert_eq!(alphabet.count(n), n.count_digits_radix(radix));
+            }
+            assert_eq!(
+                alphabet.count(<NonZeroU128 as Bounded>::MAX),
+                u128::MAX.count_digits_radix(radix as u128)
+            );
+        }
+        assert_eq!(base85.count(u32::MAX), 5);
+        assert_eq!(base62.count(u8::MAX), 2);
+        assert_eq!(base62.count(None::<u8>), 0);
+        assert_eq!(Alphabet::new(256).unwrap().count(u8::MAX), 1);
+        assert_eq!(Alphabet::new(300).unwrap().count(-1_i8), 1);
+        assert_eq!(Alphabet::new(0), None);
+        assert_eq!(Alphabet::new(1), None);
+    }
+
+    #[test]
+    fn digit_width_btree_set_order() {
+        use std::collections::BTreeSet;
+
+        let set = [1000_i32, -7, 55, 3, -55, 0, 999, 3, i32::MIN, i32::MAX]
+            .map(DigitWidth)
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        let values = set.iter().map(|n| n.0).collect::<Vec<_>>();
+        assert_eq!(values, [-7, 0, 3, -55, 55, 999, 1000, i32::MIN, i32::MAX]);
+
+        let mut sorted = values.clone();
+        sorted.sort_by(|a, b| a.cmp_by_digit_width(b));
+        assert_eq!(values, sorted);
+
+        assert!(DigitWidth(9_u8) < DigitWidth(10));
+        assert!(DigitWidth(-10_i8) > DigitWidth(9));
+        assert_eq!(DigitWidth(42).cmp(&DigitWidth(42)), Ordering::Equal);
+        assert_eq!(format!("{:>5}", DigitWidth(-42)), "  -42");
+    }
+
+    #[test]
+    fn max_count_digits() {
+        assert_eq!([5, 42, 7, 1000].into_iter().max_count_digits(), Some(4));
+        assert_eq!([-5_i64, i64::MIN, 7].iter().max_count_digits(), Some(19));
+        assert_eq!(core::iter::empty::<NonZeroU8>().max_count_digits(), None);
+        for radix in 2..20 {
+            let samples = samples!(i32, radix).collect::<Vec<_>>();
+            assert_eq!(
+                samples.iter().max_count_digits(),
+                samples.iter().scan_max_count_digits().last()
+            );
+        }
+    }
+
+    #[test]
+    fn count_decimal_digits_u64_matches_ilog10() {
+        let length_classes = U64_POWERS_OF_TEN
+            .iter()
+            .flat_map(|&power| [power - 1, power, power + 1])
+            .chain([0, 1, 2, u64::MAX - 1, u64::MAX])
+            .chain((0..u64::BITS).flat_map(|shift| [1 << shift, (1 << shift) - 1]));
+        for n in length_classes {
+            let expected = 1 + n.checked_ilog10().unwrap_or_default() as usize;
+            assert_eq!(count_decimal_digits_u64(n), expected);
+            assert_eq!(n.count_digits(), expected);
+            assert_eq!(
+                NonZeroU64::new(n).map_or(1, CountDigits::count_digits),
+                expected
+            );
+            if let Ok(signed) = i64::try_from(n) {
+                assert_eq!(signed.count_digits(), expected);
+                assert_eq!((-signed).count_digits(), expected);
+            }
+        }
+        assert_eq!(i64::MIN.count_digits(), 19);
+    }
+
+    macro_rules! digit_class_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _digit_class_radix>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.digit_class_radix(radix), n.count_digits_radix(radix) - 1);
+                            assert_eq!((&n).digit_class_radix(radix), n.count_digits_radix(radix) - 1);
+                        }
+                        for [max, next] in radix_boundaries!($type, radix) {
+                            if max >= 0 as $type {
+                                assert_eq!(next.digit_class_radix(radix), max.digit_class_radix(radix) + 1);
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _digit_class_radix>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.digit_class_radix(radix), non_zero.count_digits_radix(radix) - 1);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! option {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _option>]() {
+                    let none = None::<$type>;
+                    assert_eq!(none.count_bits(), 0);
+                    assert_eq!(none.count_octal_digits(), 0);
+                    assert_eq!(none.count_digits(), 0);
+                    assert_eq!(none.count_hex_digits(), 0);
+                    assert_eq!(none.count_balanced_ternary_digits(), 0);
+                    assert_eq!(none.bit_pattern_as_unsigned(), 0);
+                    assert_eq!(none.count_digits_nonempty(), 0);
+                    assert_eq!(none.count_bits_nonzero().get(), 1);
+                    assert_eq!(none.count_digits_of_abs_diff(Some(1)), 0);
+                    assert!(!none.is_single_digit());
+                    for radix in 2..20 {
+                        assert_eq!(none.count_digits_radix(radix), 0);
+                        assert_eq!(none.checked_count_digits_radix(radix), Some(0));
+                        assert_eq!(none.count_digits_radix_nonempty(radix), 0);
+                        assert!(!none.is_radix_boundary(radix));
+                        assert!(!none.fits_in_digits_radix(0, radix));
+                        assert!(!none.fits_in_digits_radix(u32::MAX, radix));
+                        assert!(!none.is_single_digit_radix(radix));
+                        assert_eq!(none.count_and_msd_radix(radix), (0, 0));
+                        assert_eq!(none.count_digits_radix_abs(radix), 0);
+                        assert_eq!(none.count_digits_radix_width(radix, <$type>::BITS), 0);
+                        assert_eq!(none.digit_class_radix(radix), 0);
+                    }
+                    assert_eq!(none.checked_count_digits_radix(0), None);
+                    assert_eq!(none.checked_count_digits_radix(1), None);
+                    assert!(std::panic::catch_unwind(|| none.count_digits_radix(1)).is_err());
+                    assert!(std::panic::catch_unwind(|| none.fits_in_digits_radix(0, 1)).is_err());
+                    for radix in 2..20 {
+                        for n in samples!($type, radix) {
+                            let some = Some(n);
+                            assert_eq!(some.count_bits(), n.count_bits());
+                            assert_eq!(some.count_octal_digits(), n.count_octal_digits());
+                            assert_eq!(some.count_digits(), n.count_digits());
+                            assert_eq!(some.count_hex_digits(), n.count_hex_digits());
+                            assert_eq!(some.count_balanced_ternary_digits(), n.count_balanced_ternary_digits());
+                            assert_eq!(some.bit_pattern_as_unsigned(), n.bit_pattern_as_unsigned());
+                            assert_eq!(some.count_digits_nonempty(), n.count_digits_nonempty());
+                            assert_eq!(some.count_digits_radix(radix), n.count_digits_radix(radix));
+                            assert_eq!(some.checked_count_digits_radix(radix), n.checked_count_digits_radix(radix));
+                            assert_eq!(some.count_digits_radix_nonempty(radix), n.count_digits_radix_nonempty(radix));
+                            assert_eq!(some.is_radix_boundary(radix), n.is_radix_boundary(radix));
+                            for digits in [0, 1, 8] {
+                                assert_eq!(some.fits_in_digits_radix(digits, radix), n.fits_in_digits_radix(digits, radix));
+                            }
+                            assert_eq!(some.count_and_msd_radix(radix), n.count_and_msd_radix(radix));
+                            assert_eq!(some.count_digits_of_abs_diff(Some(1)), n.count_digits_of_abs_diff(1));
+                            assert_eq!(some.count_digits_radix_abs(radix), n.count_digits_radix_abs(radix));
+                            assert_eq!(some.digit_class_radix(radix), n.digit_class_radix(radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _option>]() {
+                    assert_eq!(None::<$non_zero_type>.count_digits(), 0);
+                    for radix in 2..20 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n);
+                            assert_eq!(non_zero.count_digits_radix(radix), n.count_digits_radix(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! is_radix_width_floor {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _is_radix_width_floor>]() {
+                    for radix in [2, 3, 7, 8, 10, 16, 17, 36] {
+                        for [max, floor] in radix_boundaries!($type, radix) {
+                            assert!(floor.is_radix_width_floor(radix));
+                            assert!((&floor).is_radix_width_floor(radix));
+                            assert!(!(floor + 1).is_radix_width_floor(radix));
+                            if max > 1 {
+                                assert!(!max.is_radix_width_floor(radix));
+                            }
+                            assert_eq!(
+                                floor.count_digits_radix(radix),
+                                (floor - 1).count_digits_radix(radix) + 1
+                            );
+                        }
+                        assert!((0 as $type).is_radix_width_floor(radix));
+                        assert!((1 as $type).is_radix_width_floor(radix));
+                        for n in (2 as $type)..(radix as $type) {
+                            assert!(!n.is_radix_width_floor(radix));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _is_radix_width_floor>]() {
+                    for radix in [2, 3, 7, 8, 10, 16, 17, 36] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.is_radix_width_floor(radix), n.is_radix_width_floor(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn is_radix_width_floor_negative() {
+        for radix in [2, 3, 10, 16] {
+            for n in i8::MIN..0 {
+                assert!(!n.is_radix_width_floor(radix));
             }
+        }
+        assert!(i128::MIN.unsigned_abs().is_radix_width_floor(2));
+        assert!(!i128::MIN.is_radix_width_floor(2));
+        assert!(!None::<u8>.is_radix_width_floor(10));
+    }
 
-            #[inline(always)]
-            /// Returns the count of octal digits in an integer.
-            fn count_octal_digits(self) -> u32 {
-                1 + self.checked_ilog2().unwrap_or_default() / 3
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn is_radix_width_floor_invalid_radix() {
+        (-1_i32).is_radix_width_floor(1);
+    }
+
+    macro_rules! checked_named_counts {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _checked_named_counts>]() {
+                    for radix in [2, 8, 10, 16] {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.checked_count_bits(), Some(n.count_bits()));
+                            assert_eq!(n.checked_count_octal_digits(), Some(n.count_octal_digits()));
+                            assert_eq!(n.checked_count_hex_digits(), Some(n.count_hex_digits()));
+                            assert_eq!(n.checked_count_digits(), Some(n.count_digits()));
+                            assert_eq!((&n).checked_count_digits(), Some(n.count_digits()));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _checked_named_counts>]() {
+                    for radix in [2, 8, 10, 16] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let n = $non_zero_type::new(n).unwrap();
+                            assert_eq!(n.checked_count_bits(), Some(n.count_bits()));
+                            assert_eq!(n.checked_count_octal_digits(), Some(n.count_octal_digits()));
+                            assert_eq!(n.checked_count_hex_digits(), Some(n.count_hex_digits()));
+                            assert_eq!(n.checked_count_digits(), Some(n.count_digits()));
+                        }
+                    }
+                }
             }
+        };
+    }
 
-            #[inline(always)]
-            /// Returns the count of hexadecimal digits in an integer.
-            fn count_hex_digits(self) -> u32 {
-                1 + self.checked_ilog2().unwrap_or_default() / 4
+    macro_rules! count_digits_radix_u128 {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_u128>]() {
+                    for radix in (2..=36).filter(|&radix| radix != 10) {
+                        for n in samples!($type, radix) {
+                            let bit_pattern = n.bit_pattern_as_unsigned();
+                            let direct = 1 + bit_pattern.checked_ilog(radix as _).unwrap_or_default() as usize;
+                            assert_eq!(count_digits_radix_u128(bit_pattern as u128, radix as u128), direct);
+                            assert_eq!(n.count_digits_radix(radix as _), direct);
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_u128>]() {
+                    for radix in (2..=36).filter(|&radix| radix != 10) {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            let direct = 1 + non_zero.bit_pattern_as_unsigned().ilog(radix as _) as usize;
+                            assert_eq!(non_zero.count_digits_radix(radix as _), direct);
+                        }
+                    }
+                }
             }
+        };
+    }
 
-            #[inline(always)]
-            /// Returns the count of decimal digits in an integer.
-            fn count_digits(self) -> usize {
-                1 + self.checked_ilog10().unwrap_or_default() as usize
+    macro_rules! power_of_two_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _power_of_two_radix>]() {
+                    for radix in [4, 32, 64] {
+                        for n in samples!($type, radix) {
+                            let expected = count_digits_radix_u128(n.bit_pattern_as_unsigned() as u128, radix as u128);
+                            assert_eq!(n.count_digits_radix(radix as _), expected);
+                            assert_eq!(n.checked_count_digits_radix(radix as _), Some(expected));
+                            if let Some(non_zero) = <$non_zero_type>::new(n) {
+                                assert_eq!(non_zero.count_digits_radix(radix as _), expected);
+                            }
+                        }
+                    }
+                }
             }
+        };
+    }
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// [Panics](panic) if the provided radix is 0 or 1.
-            ///
-            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
-            fn count_digits_radix(self, radix: Self::Radix) -> usize {
-                match radix {
-                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
-                    02 => self.count_bits() as usize,
-                    08 => self.count_octal_digits() as usize,
-                    10 => self.count_digits(),
-                    16 => self.count_hex_digits() as usize,
-                    __ => 1 + self.checked_ilog(radix).unwrap_or_default() as usize,
+    macro_rules! radix_neighborhood {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _radix_neighborhood>]() {
+                    for radix in 2..=36 {
+                        let neighborhood = [radix - 1, radix, radix + 1].map(|n| n as $type);
+                        let counts = neighborhood.map(|n| n.count_digits_radix(radix as _));
+                        assert_eq!(counts, [1, 2, 2], "radix {radix}");
+                        let checked = neighborhood.map(|n| n.checked_count_digits_radix(radix as _));
+                        assert_eq!(checked, [Some(1), Some(2), Some(2)], "radix {radix}");
+                        let non_zero = neighborhood.map(|n| <$non_zero_type>::new(n).unwrap().count_digits_radix(radix as _));
+                        assert_eq!(non_zero, [1, 2, 2], "radix {radix}");
+                    }
                 }
             }
+        };
+    }
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// Returns [None] if the provided radix is 0 or 1.
-            ///
-            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
-            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
-                match radix {
-                    0 | 1 => None,
-                    radix => Some(self.count_digits_radix(radix)),
+    macro_rules! count_base36_digits {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_base36_digits>]() {
+                    for n in samples!($type, 36) {
+                        assert_eq!(n.count_base36_digits(), n.count_digits_radix(36));
+                        assert_eq!((&n).count_base36_digits(), n.count_digits_radix(36));
+                    }
+                    let bits = <$type>::BITS;
+                    let max_pattern = u128::MAX >> (u128::BITS - bits);
+                    let min = <$type as Bounded>::MIN.bit_pattern_as_unsigned() as u128;
+                    assert_eq!(<$type as Bounded>::MIN.count_base36_digits(), min.count_digits_radix(36));
+                    assert_eq!(<$type as Bounded>::MAX.count_base36_digits(), (<$type as Bounded>::MAX as u128).count_digits_radix(36));
+                    assert_eq!((-1_i128 as $type).count_base36_digits(), max_pattern.count_digits_radix(36));
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_base36_digits>]() {
+                    for n in radix_boundaries!($type, 36).flatten() {
+                        let non_zero = $non_zero_type::new(n).unwrap();
+                        assert_eq!(non_zero.count_base36_digits(), non_zero.count_digits_radix(36));
+                    }
                 }
             }
-        }
+        };
+    }
 
-        impl CountDigits for $non_zero_type {
-            type Radix = $primitive_type;
+    #[test]
+    fn count_base36_digits_min_and_max() {
+        assert_eq!(u8::MAX.count_base36_digits(), 2);
+        assert_eq!(u16::MAX.count_base36_digits(), 4);
+        assert_eq!(u32::MAX.count_base36_digits(), 7);
+        assert_eq!(u64::MAX.count_base36_digits(), 13);
+        assert_eq!(u128::MAX.count_base36_digits(), 25);
+        assert_eq!(i8::MIN.count_base36_digits(), 2);
+        assert_eq!(i128::MIN.count_base36_digits(), 25);
+        assert_eq!(i64::MAX.count_base36_digits(), 13);
+        assert_eq!(None::<u64>.count_base36_digits(), 0);
+    }
 
-            #[inline(always)]
-            /// Returns the count of bits in an integer.
-            fn count_bits(self) -> u32 {
-                1 + self.ilog2()
+    macro_rules! count_base85_digits {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_base85_digits>]() {
+                    for n in samples!($type, 85) {
+                        assert_eq!(n.count_base85_digits(), n.count_digits_radix(85));
+                        assert_eq!((&n).count_base85_digits(), n.count_digits_radix(85));
+                    }
+                    let bits = <$type>::BITS;
+                    let max_pattern = u128::MAX >> (u128::BITS - bits);
+                    let min = <$type as Bounded>::MIN.bit_pattern_as_unsigned() as u128;
+                    assert_eq!(<$type as Bounded>::MIN.count_base85_digits(), min.count_digits_radix(85));
+                    assert_eq!(<$type as Bounded>::MAX.count_base85_digits(), (<$type as Bounded>::MAX as u128).count_digits_radix(85));
+                    assert_eq!((-1_i128 as $type).count_base85_digits(), max_pattern.count_digits_radix(85));
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_base85_digits>]() {
+                    for n in radix_boundaries!($type, 85).flatten() {
+                        let non_zero = $non_zero_type::new(n).unwrap();
+                        assert_eq!(non_zero.count_base85_digits(), non_zero.count_digits_radix(85));
+                    }
+                }
             }
+        };
+    }
 
-            #[inline(always)]
-            /// Returns the count of octal digits in an integer.
-            fn count_octal_digits(self) -> u32 {
-                1 + self.get().ilog2() / 3
-            }
+    #[test]
+    fn count_base85_digits_min_and_max() {
+        assert_eq!(u8::MAX.count_base85_digits(), 2);
+        assert_eq!(u16::MAX.count_base85_digits(), 3);
+        assert_eq!(u32::MAX.count_base85_digits(), 5);
+        assert_eq!(u64::MAX.count_base85_digits(), 10);
+        assert_eq!(u128::MAX.count_base85_digits(), 20);
+        assert_eq!(i8::MIN.count_base85_digits(), 2);
+        assert_eq!(i128::MIN.count_base85_digits(), 20);
+        assert_eq!(i64::MAX.count_base85_digits(), 10);
+        assert_eq!(None::<u64>.count_base85_digits(), 0);
+    }
 
-            #[inline(always)]
-            /// Returns the count of hexadecimal digits in an integer.
-            fn count_hex_digits(self) -> u32 {
-                1 + self.get().ilog2() / 4
-            }
+    /// Returns the count of positions that differ between two digit strings aligned at the end,
+    /// padding the shorter one with leading zeros.
+    fn digit_string_diff_count(a: &str, b: &str) -> usize {
+        let width = a.len().max(b.len());
+        let a = format!("{a:0>width$}");
+        let b = format!("{b:0>width$}");
+        a.chars().zip(b.chars()).filter(|(x, y)| x != y).count()
+    }
 
-            #[inline(always)]
-            /// Returns the count of decimal digits in an integer.
-            fn count_digits(self) -> usize {
-                1 + self.ilog10() as usize
+    macro_rules! digit_diff_count_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _digit_diff_count_radix>]() {
+                    for radix in [2, 8, 10, 16] {
+                        let samples = samples!($type, radix).collect::<Vec<_>>();
+                        let to_string = |n: $type| match radix {
+                            2 => format!("{n:b}"),
+                            8 => format!("{n:o}"),
+                            10 => n.abs_diff(0).to_string(),
+                            _ => format!("{n:x}"),
+                        };
+                        for &a in &samples {
+                            assert_eq!(a.digit_diff_count_radix(a, radix), 0);
+                            for &b in &samples {
+                                let expected = digit_string_diff_count(&to_string(a), &to_string(b));
+                                assert_eq!(a.digit_diff_count_radix(b, radix), expected);
+                                assert_eq!((&a).digit_diff_count_radix(&b, radix), expected);
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _digit_diff_count_radix>]() {
+                    for radix in [2, 3, 10, 16] {
+                        for a in radix_boundaries!($type, radix).flatten() {
+                            for b in radix_boundaries!($type, radix).flatten() {
+                                let (x, y) = ($non_zero_type::new(a).unwrap(), $non_zero_type::new(b).unwrap());
+                                assert_eq!(x.digit_diff_count_radix(y, radix), a.digit_diff_count_radix(b, radix));
+                            }
+                        }
+                    }
+                }
             }
+        };
+    }
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// [Panics](panic) if the provided radix is 0 or 1.
-            ///
-            /// See [checked_count_digits_radix()](CountDigits::checked_count_digits_radix) for a non-panicking version of this function.
-            fn count_digits_radix(self, radix: Self::Radix) -> usize {
-                match radix {
-                    0 | 1 => panic!("base of integer logarithm must be at least 2"),
-                    02 => self.count_bits() as usize,
-                    08 => self.count_octal_digits() as usize,
-                    10 => self.count_digits(),
-                    16 => self.count_hex_digits() as usize,
-                    _ => 1 + self.get().ilog(radix) as usize,
+    #[test]
+    fn digit_diff_count_radix_pairs() {
+        assert_eq!(123_u32.digit_diff_count_radix(923, 10), 1);
+        assert_eq!(123_u32.digit_diff_count_radix(1123, 10), 1);
+        assert_eq!(123_u32.digit_diff_count_radix(12, 10), 3);
+        assert_eq!(100_u32.digit_diff_count_radix(0, 10), 1);
+        assert_eq!(0_u32.digit_diff_count_radix(0, 10), 0);
+        assert_eq!((-123_i32).digit_diff_count_radix(123, 10), 0);
+        assert_eq!((-1_i8).digit_diff_count_radix(0, 2), 8);
+        assert_eq!(None::<u8>.digit_diff_count_radix(Some(1), 10), 0);
+    }
+
+    /// Returns the count of leading characters that two digit strings share, or 0 if their lengths differ.
+    fn digit_string_common_prefix_len(a: &str, b: &str) -> usize {
+        match a.len() == b.len() {
+            true => a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count(),
+            false => 0,
+        }
+    }
+
+    macro_rules! common_prefix_len_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _common_prefix_len_radix>]() {
+                    for radix in [2, 8, 10, 16] {
+                        let samples = samples!($type, radix).collect::<Vec<_>>();
+                        let to_string = |n: $type| match radix {
+                            2 => format!("{n:b}"),
+                            8 => format!("{n:o}"),
+                            10 => n.abs_diff(0).to_string(),
+                            _ => format!("{n:x}"),
+                        };
+                        for &a in &samples {
+                            assert_eq!(a.common_prefix_len_radix(a, radix), a.count_digits_radix(radix));
+                            for &b in &samples {
+                                let expected = digit_string_common_prefix_len(&to_string(a), &to_string(b));
+                                assert_eq!(a.common_prefix_len_radix(b, radix), expected);
+                                assert_eq!((&a).common_prefix_len_radix(&b, radix), expected);
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _common_prefix_len_radix>]() {
+                    for radix in [2, 3, 10, 16] {
+                        for a in radix_boundaries!($type, radix).flatten() {
+                            for b in radix_boundaries!($type, radix).flatten() {
+                                let (x, y) = ($non_zero_type::new(a).unwrap(), $non_zero_type::new(b).unwrap());
+                                assert_eq!(x.common_prefix_len_radix(y, radix), a.common_prefix_len_radix(b, radix));
+                            }
+                        }
+                    }
                 }
             }
+        };
+    }
 
-            #[inline(always)]
-            /// Returns the count of digits in an integer as interpreted with the given [radix](https://en.wikipedia.org/wiki/Radix).
-            ///
-            /// Returns [None] if the provided radix is 0 or 1.
-            ///
-            /// See [count_digits_radix()](CountDigits::count_digits_radix) for a panicking version of this function.
-            fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
-                match radix {
-                    0 | 1 => None,
-                    radix => Some(self.count_digits_radix(radix)),
-                }
+    #[test]
+    fn common_prefix_len_radix_pairs() {
+        // Partially shared.
+        assert_eq!(12345_u32.common_prefix_len_radix(12399, 10), 3);
+        assert_eq!(12345_u32.common_prefix_len_radix(12346, 10), 4);
+        assert_eq!(0xABCD_u16.common_prefix_len_radix(0xAB00, 16), 2);
+        // Equal.
+        assert_eq!(12345_u32.common_prefix_len_radix(12345, 10), 5);
+        assert_eq!(0_u32.common_prefix_len_radix(0, 10), 1);
+        assert_eq!((-123_i32).common_prefix_len_radix(123, 10), 3);
+        // Differing widths.
+        assert_eq!(12345_u32.common_prefix_len_radix(1234, 10), 0);
+        assert_eq!(9_u32.common_prefix_len_radix(10, 10), 0);
+        assert_eq!((-1_i8).common_prefix_len_radix(1, 2), 0);
+        // No shared leading digit.
+        assert_eq!(12345_u32.common_prefix_len_radix(22345, 10), 0);
+        assert_eq!(None::<u8>.common_prefix_len_radix(Some(1), 10), 0);
+        assert_eq!(Reverse(120_u8).common_prefix_len_radix(Reverse(125), 10), 2);
+    }
+
+    #[test]
+    fn max_digit_value() {
+        for (radix, max) in [(2, 1), (10, 9), (16, 15), (36, 35)] {
+            assert_eq!(u8::max_digit_value(radix), max);
+            assert_eq!(i8::max_digit_value(radix), max);
+            assert_eq!(u32::max_digit_value(radix as u32), max);
+            assert_eq!(i128::max_digit_value(radix as u128), max);
+            assert_eq!(NonZeroUsize::max_digit_value(radix as usize), max);
+            assert_eq!(<Option<i64>>::max_digit_value(radix as u64), max);
+            assert_eq!(<&u16>::max_digit_value(radix as u16), max);
+            assert_eq!(<Reverse<i16>>::max_digit_value(radix as u16), max);
+        }
+        assert_eq!(u8::max_digit_value(u8::MAX), 254);
+        assert_eq!(u16::max_digit_value(256), 255);
+        for radix in 2..=256 {
+            let max = u32::max_digit_value(radix);
+            assert_eq!(max as u32 + 1, radix);
+            assert_eq!((max as u32).count_digits_radix(radix), 1);
+            assert_eq!((max as u32 + 1).count_digits_radix(radix), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be at most 256 for a digit to fit in u8")]
+    fn max_digit_value_radix_257() {
+        u16::max_digit_value(257);
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn max_digit_value_radix_1() {
+        i32::max_digit_value(1);
+    }
+
+    #[test]
+    fn count_digits_radix_nz8() {
+        let radix = |radix| NonZeroU8::new(radix).unwrap();
+        assert_eq!(0_u8.count_digits_radix_nz8(radix(1)), None);
+        assert_eq!((-1_i8).count_digits_radix_nz8(radix(1)), None);
+        assert_eq!(
+            <NonZeroU8 as Bounded>::MAX.count_digits_radix_nz8(radix(1)),
+            None
+        );
+        for r in 2..=u8::MAX {
+            for n in samples!(u8, r) {
+                assert_eq!(
+                    n.count_digits_radix_nz8(radix(r)),
+                    Some(n.count_digits_radix(r))
+                );
+            }
+            // The boundaries of radices that do not fit in an i8 are not sampled.
+            for n in samples!(i8, r.min(i8::MAX as u8)) {
+                assert_eq!(
+                    n.count_digits_radix_nz8(radix(r)),
+                    Some(n.count_digits_radix(r))
+                );
+                assert_eq!(
+                    (&n).count_digits_radix_nz8(radix(r)),
+                    Some(n.count_digits_radix(r))
+                );
             }
         }
-    };
-}
+        assert_eq!(255_u8.count_digits_radix_nz8(radix(16)), Some(2));
+        assert_eq!(i8::MIN.count_digits_radix_nz8(radix(10)), Some(3));
+        assert_eq!(i8::MIN.count_digits_radix_nz8(radix(2)), Some(8));
+        assert_eq!(
+            <NonZeroI8 as Bounded>::MIN.count_digits_radix_nz8(radix(8)),
+            Some(3)
+        );
+        assert_eq!(Some(100_u8).count_digits_radix_nz8(radix(255)), Some(1));
+    }
 
-impl<T: CountDigits> CountDigits for &T {
-    type Radix = <T as CountDigits>::Radix;
+    #[test]
+    fn dyn_count_digits() {
+        let values: Vec<Box<dyn DynCountDigits>> = vec![
+            Box::new(0_u8),
+            Box::new(-128_i8),
+            Box::new(u16::MAX),
+            Box::new(-1_i64),
+            Box::new(u128::MAX),
+            Box::new(NonZeroI32::new(-1000).unwrap()),
+            Box::new(Some(42_usize)),
+            Box::new(None::<u32>),
+            Box::new(&99_isize),
+        ];
+        let widths = values
+            .iter()
+            .map(|n| n.dyn_count_digits())
+            .collect::<Vec<_>>();
+        assert_eq!(widths, [1, 3, 5, 1, 39, 4, 2, 0, 2]);
 
-    #[inline(always)]
-    /// Calls [count_bits()][CountDigits::count_bits] on the inner value.
-    fn count_bits(self) -> u32 {
-        (*self).count_bits()
+        let hex_widths = values
+            .iter()
+            .map(|n| n.dyn_count_digits_radix(16))
+            .collect::<Vec<_>>();
+        let expected = [1, 2, 4, 16, 32, 8, 2, 0, 2].map(Some);
+        assert_eq!(hex_widths, expected);
+
+        for value in &values {
+            assert_eq!(value.dyn_count_digits_radix(0), None);
+            assert_eq!(value.dyn_count_digits_radix(1), None);
+        }
+
+        assert_eq!(u8::MAX.dyn_count_digits_radix(256), Some(1));
+        assert_eq!((-1_i8).dyn_count_digits_radix(u32::MAX), Some(1));
+        assert_eq!(
+            u64::MAX.dyn_count_digits_radix(u32::MAX),
+            u64::MAX.checked_count_digits_radix(u32::MAX as u64)
+        );
+        assert_eq!(None::<u8>.dyn_count_digits_radix(256), Some(0));
     }
 
-    #[inline(always)]
-    /// Calls [count_octal_digits()][CountDigits::count_octal_digits] on the inner value.
-    fn count_octal_digits(self) -> u32 {
-        (*self).count_octal_digits()
+    /// Returns the length of a digit string with its trailing zeros removed, keeping a lone `0`.
+    fn significant_digit_string_count(digits: &str) -> usize {
+        digits.trim_end_matches('0').len().max(1)
+    }
+
+    macro_rules! count_significant_digits_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_significant_digits_radix>]() {
+                    for radix in [2, 8, 10, 16] {
+                        let to_string = |n: $type| match radix {
+                            2 => format!("{n:b}"),
+                            8 => format!("{n:o}"),
+                            10 => n.abs_diff(0).to_string(),
+                            _ => format!("{n:x}"),
+                        };
+                        for n in samples!($type, radix) {
+                            let expected = significant_digit_string_count(&to_string(n));
+                            assert_eq!(n.count_significant_digits_radix(radix), expected);
+                            assert_eq!((&n).count_significant_digits_radix(radix), expected);
+                        }
+                    }
+                    for radix in 2..=36 {
+                        for [below, power] in radix_boundaries!($type, radix) {
+                            assert_eq!(power.count_significant_digits_radix(radix), 1);
+                            assert_eq!(below.count_significant_digits_radix(radix), below.count_digits_radix(radix));
+                        }
+                    }
+                    for n in samples!($type, 10) {
+                        assert_eq!(n.count_significant_digits(), n.count_significant_digits_radix(10));
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_significant_digits_radix>]() {
+                    for radix in [2, 3, 10, 16] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_significant_digits_radix(radix), n.count_significant_digits_radix(radix));
+                            assert_eq!(non_zero.count_significant_digits(), n.count_significant_digits());
+                        }
+                    }
+                }
+            }
+        };
     }
 
-    #[inline(always)]
-    /// Calls [count_digits()][CountDigits::count_digits] on the inner value.
-    fn count_digits(self) -> usize {
-        (*self).count_digits()
+    #[test]
+    fn count_significant_digits_trailing_zeros() {
+        assert_eq!(12345_u32.count_significant_digits(), 5);
+        assert_eq!(12340_u32.count_significant_digits(), 4);
+        assert_eq!(12300_u32.count_significant_digits(), 3);
+        assert_eq!(10200_u32.count_significant_digits(), 3);
+        assert_eq!(10000_u32.count_significant_digits(), 1);
+        assert_eq!(0_u32.count_significant_digits(), 1);
+        assert_eq!((-1200_i16).count_significant_digits(), 2);
+        assert_eq!(0x1200_u16.count_significant_digits_radix(16), 2);
+        assert_eq!((-256_i16).count_significant_digits_radix(2), 8);
+        assert_eq!(None::<u8>.count_significant_digits_radix(10), 0);
+        assert_eq!(Some(100_u8).count_significant_digits(), 1);
     }
 
-    #[inline(always)]
-    /// Calls [count_hex_digits()][CountDigits::count_hex_digits] on the inner value.
-    fn count_hex_digits(self) -> u32 {
-        (*self).count_hex_digits()
+    macro_rules! count_digits_of_reverse_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_of_reverse_radix>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            // The reverse of the digits, with the leading zeros that were trailing zeros removed.
+                            let mut digits = n.digits_radix_le(radix).skip_while(|&digit| digit == 0).peekable();
+                            let count = if digits.peek().is_some() { digits.count() } else { 1 };
+                            assert_eq!(n.count_digits_of_reverse_radix(radix), count);
+                            assert_eq!((&n).count_digits_of_reverse_radix(radix), count);
+                        }
+                        assert_eq!(None::<$type>.count_digits_of_reverse_radix(radix), 0);
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_of_reverse_radix>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(
+                                non_zero.count_digits_of_reverse_radix(radix),
+                                n.count_digits_of_reverse_radix(radix),
+                            );
+                        }
+                    }
+                }
+            }
+        };
     }
 
-    #[inline(always)]
-    /// Calls [count_digits_radix()][CountDigits::count_digits_radix] on the inner value.
-    fn count_digits_radix(self, radix: Self::Radix) -> usize {
-        (*self).count_digits_radix(radix)
+    #[test]
+    fn count_digits_of_reverse_radix_trailing_zeros_and_palindromes() {
+        // Trailing zeros become leading zeros of the reverse.
+        assert_eq!(100_u32.count_digits_of_reverse_radix(10), 1);
+        assert_eq!(120_u32.count_digits_of_reverse_radix(10), 2);
+        assert_eq!(1_000_000_u32.count_digits_of_reverse_radix(10), 1);
+        assert_eq!(0x1200_u32.count_digits_of_reverse_radix(16), 2);
+        assert_eq!(0b1000_u8.count_digits_of_reverse_radix(2), 1);
+        assert_eq!((-120_i32).count_digits_of_reverse_radix(10), 2);
+        // Palindromes have the same count of digits as their reverse.
+        for n in [0_u64, 7, 11, 121, 1221, 12321, 9_876_556_789] {
+            assert_eq!(n.count_digits_of_reverse_radix(10), n.count_digits());
+        }
+        assert_eq!(0b1001_u8.count_digits_of_reverse_radix(2), 4);
+        // Reverses that would overflow the type.
+        assert_eq!(u64::MAX.count_digits_of_reverse_radix(10), 20);
+        assert_eq!(199_u8.count_digits_of_reverse_radix(10), 3);
+        assert_eq!(i8::MIN.count_digits_of_reverse_radix(10), 3);
     }
 
-    #[inline(always)]
-    /// Calls [checked_count_digits_radix()][CountDigits::checked_count_digits_radix] on the inner value.
-    fn checked_count_digits_radix(self, radix: Self::Radix) -> Option<usize> {
-        (*self).checked_count_digits_radix(radix)
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn count_significant_digits_radix_invalid_radix() {
+        0_u32.count_significant_digits_radix(1);
     }
-}
-
-impl_count_digits! {
-    primitive_type = i8,
-    non_zero_type = NonZeroI8,
-    radix_type = u8,
-    min_value_bits = 8,
-    min_value_octal_digits = 3,
-    min_value_hex_digits = 2,
-}
-
-impl_count_digits! {
-    primitive_type = i16,
-    non_zero_type = NonZeroI16,
-    radix_type = u16,
-    min_value_bits = 16,
-    min_value_octal_digits = 6,
-    min_value_hex_digits = 4,
-}
-
-impl_count_digits! {
-    primitive_type = i32,
-    non_zero_type = NonZeroI32,
-    radix_type = u32,
-    min_value_bits = 32,
-    min_value_octal_digits = 11,
-    min_value_hex_digits = 8,
-}
-
-impl_count_digits! {
-    primitive_type = i64,
-    non_zero_type = NonZeroI64,
-    radix_type = u64,
-    min_value_bits = 64,
-    min_value_octal_digits = 22,
-    min_value_hex_digits = 16,
-}
-
-impl_count_digits! {
-    primitive_type = i128,
-    non_zero_type = NonZeroI128,
-    radix_type = u128,
-    min_value_bits = 128,
-    min_value_octal_digits = 43,
-    min_value_hex_digits = 32,
-}
 
-#[cfg(target_pointer_width = "64")]
-impl_count_digits! {
-    primitive_type = isize,
-    non_zero_type = NonZeroIsize,
-    radix_type = usize,
-    min_value_bits = 64,
-    min_value_octal_digits = 22,
-    min_value_hex_digits = 16,
-}
+    macro_rules! max_widths {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _max_widths>]() {
+                    let bits = <$type>::BITS;
+                    let samples = [<$type as Bounded>::MIN, <$type as Bounded>::MAX, 0, 1, (0 as $type).wrapping_sub(1)]
+                        .into_iter()
+                        .chain(radix_boundaries!($type, 3).flatten())
+                        .chain(radix_boundaries!($type, 7).flatten())
+                        .collect::<Vec<_>>();
+                    let max_decimal = decimal_string_count!(<$type as Bounded>::MIN).max(decimal_string_count!(<$type as Bounded>::MAX));
 
-#[cfg(target_pointer_width = "32")]
-impl_count_digits! {
-    primitive_type = isize,
-    non_zero_type = NonZeroIsize,
-    radix_type = usize,
-    min_value_bits = 32,
-    min_value_octal_digits = 11,
-    min_value_hex_digits = 8,
-}
+                    assert_eq!(samples.iter().map(|n| n.count_bits()).max(), Some(bits));
+                    assert_eq!(samples.iter().map(|n| n.count_octal_digits()).max(), Some((bits + 2) / 3));
+                    assert_eq!(samples.iter().map(|n| n.count_hex_digits()).max(), Some(bits / 4));
+                    assert_eq!(samples.iter().map(|n| n.count_digits()).max(), Some(max_decimal));
 
-#[cfg(target_pointer_width = "16")]
-impl_count_digits! {
-    primitive_type = isize,
-    non_zero_type = NonZeroIsize,
-    radix_type = usize,
-    min_value_bits = 16,
-    min_value_octal_digits = 6,
-    min_value_hex_digits = 4,
-}
+                    let non_zero = samples.iter().filter_map(|&n| <$non_zero_type>::new(n)).collect::<Vec<_>>();
+                    assert_eq!(non_zero.iter().map(|n| n.count_bits()).max(), Some(bits));
+                    assert_eq!(non_zero.iter().map(|n| n.count_octal_digits()).max(), Some((bits + 2) / 3));
+                    assert_eq!(non_zero.iter().map(|n| n.count_hex_digits()).max(), Some(bits / 4));
+                    assert_eq!(non_zero.iter().map(|n| n.count_digits()).max(), Some(max_decimal));
+                }
+            }
+        };
+    }
 
-#[cfg(target_pointer_width = "8")]
-impl_count_digits! {
-    primitive_type = isize,
-    non_zero_type = NonZeroIsize,
-    radix_type = usize,
-    min_value_bits = 8,
-    min_value_octal_digits = 3,
-    min_value_hex_digits = 2,
-}
+    #[cfg(feature = "alloc")]
+    macro_rules! count_digits_radices {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radices>]() {
+                    let radices = [0, 2, 1, 3, 10, 0, 16, 36, 1, <$type as Bounded>::MAX as _];
+                    for n in samples!($type, 10) {
+                        let counts = n.count_digits_radices(&radices);
+                        assert_eq!(counts.len(), radices.len());
+                        for (&radix, count) in radices.iter().zip(counts) {
+                            assert_eq!(count, n.checked_count_digits_radix(radix));
+                        }
+                        assert_eq!((&n).count_digits_radices(&radices), n.count_digits_radices(&radices));
+                        assert!(n.count_digits_radices(&[]).is_empty());
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radices>]() {
+                    let radices = [1, 2, 0, 7, 10, 16];
+                    for n in radix_boundaries!($type, 10).flatten() {
+                        let non_zero = $non_zero_type::new(n).unwrap();
+                        assert_eq!(non_zero.count_digits_radices(&radices), n.count_digits_radices(&radices));
+                    }
+                }
+            }
+        };
+    }
 
-impl_count_digits! {
-    primitive_type = u8,
-    non_zero_type = NonZeroU8,
-}
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn count_digits_radices_mixed_validity() {
+        assert_eq!(
+            (-1_i8).count_digits_radices(&[0, 1, 2, 3, 10, 255]),
+            [None, None, Some(8), Some(6), Some(1), Some(2)],
+        );
+        assert_eq!(None::<u32>.count_digits_radices(&[1, 10]), [None, Some(0)]);
+    }
 
-impl_count_digits! {
-    primitive_type = u16,
-    non_zero_type = NonZeroU16,
-}
+    macro_rules! count_digits_radix_u32 {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_u32>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            assert_eq!(n.count_digits_radix_u32(radix), n.count_digits_radix(radix) as u32);
+                            assert_eq!((&n).count_digits_radix_u32(radix), n.count_digits_radix(radix) as u32);
+                        }
+                    }
+                    assert_eq!(<$type as Bounded>::MAX.count_digits_radix_u32(2), <$type as Bounded>::MAX.count_bits());
+                    assert_eq!(<$type as Bounded>::MIN.count_digits_radix_u32(8), <$type as Bounded>::MIN.count_octal_digits());
+                    assert_eq!(<$type as Bounded>::MIN.count_digits_radix_u32(16), <$type as Bounded>::MIN.count_hex_digits());
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_u32>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_digits_radix_u32(radix), non_zero.count_digits_radix(radix) as u32);
+                        }
+                    }
+                }
+            }
+        };
+    }
 
-impl_count_digits! {
-    primitive_type = u32,
-    non_zero_type = NonZeroU32,
-}
+    macro_rules! count_digits_radix_usize {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_usize>]() {
+                    let max_radix = (0 as $type).wrapping_sub(1).bit_pattern_as_unsigned() as u128;
+                    for radix in [0_usize, 1, 2, 3, 10, 16, 36, 255, 256, 300, 65535, 65536, usize::MAX] {
+                        let fits = radix as u128 <= max_radix;
+                        for n in samples!($type, 10) {
+                            let expected = match fits {
+                                true => n.checked_count_digits_radix(radix as _),
+                                false => None,
+                            };
+                            assert_eq!(n.count_digits_radix_usize(radix), expected, "{n} radix {radix}");
+                            assert_eq!((&n).count_digits_radix_usize(radix), expected);
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_usize>]() {
+                    for radix in [0_usize, 1, 2, 10, 16, 300] {
+                        for n in radix_boundaries!($type, 10).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.count_digits_radix_usize(radix), n.count_digits_radix_usize(radix));
+                        }
+                    }
+                }
+            }
+        };
+    }
 
-impl_count_digits! {
-    primitive_type = u64,
-    non_zero_type = NonZeroU64,
-}
+    #[test]
+    fn count_digits_radix_usize_out_of_range() {
+        assert_eq!((-1_i8).count_digits_radix_usize(300), None);
+        assert_eq!(i8::MAX.count_digits_radix_usize(256), None);
+        assert_eq!(i8::MAX.count_digits_radix_usize(255), Some(1));
+        assert_eq!(
+            <NonZeroI8 as Bounded>::MIN.count_digits_radix_usize(300),
+            None
+        );
+        assert_eq!(u16::MAX.count_digits_radix_usize(65536), None);
+        assert_eq!(Some(5_u8).count_digits_radix_usize(300), None);
+        assert_eq!(None::<u8>.count_digits_radix_usize(10), Some(0));
+    }
 
-impl_count_digits! {
-    primitive_type = u128,
-    non_zero_type = NonZeroU128,
-}
+    macro_rules! count_decimal_digits_twos_complement {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_decimal_digits_twos_complement>]() {
+                    for n in samples!($type, 10) {
+                        let expected = n.bit_pattern_as_unsigned().to_string().len();
+                        assert_eq!(n.count_decimal_digits_twos_complement(), expected);
+                        assert_eq!((&n).count_decimal_digits_twos_complement(), expected);
+                        if n >= 0 as $type {
+                            assert_eq!(n.count_decimal_digits_twos_complement(), n.count_digits());
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_decimal_digits_twos_complement>]() {
+                    for non_zero in samples!($type, 10).filter_map($non_zero_type::new) {
+                        assert_eq!(non_zero.count_decimal_digits_twos_complement(), non_zero.get().count_decimal_digits_twos_complement());
+                    }
+                }
+            }
+        };
+    }
 
-impl_count_digits! {
-    primitive_type = usize,
-    non_zero_type = NonZeroUsize,
-}
+    #[test]
+    fn count_decimal_digits_twos_complement_negative() {
+        assert_eq!((-1_i8).count_decimal_digits_twos_complement(), 3);
+        assert_eq!((-1_i8).count_digits(), 1);
+        assert_eq!(i8::MIN.count_decimal_digits_twos_complement(), 3);
+        assert_eq!((-1_i32).count_decimal_digits_twos_complement(), 10);
+        assert_eq!((-1_i32).count_digits(), 1);
+        assert_eq!((-1_i64).count_decimal_digits_twos_complement(), 20);
+        assert_eq!((-1_i128).count_decimal_digits_twos_complement(), 39);
+        assert_eq!((-1_i128).count_digits(), 1);
+        assert_eq!(
+            NonZeroI16::new(-1)
+                .unwrap()
+                .count_decimal_digits_twos_complement(),
+            5
+        );
+        assert_eq!(None::<i8>.count_decimal_digits_twos_complement(), 0);
+        for n in i8::MIN..0 {
+            assert_eq!(
+                n.count_decimal_digits_twos_complement(),
+                (n as u8).count_digits()
+            );
+        }
+    }
 
-#[cfg(test)]
-mod count_digits {
-    use super::*;
-    use paste::paste;
+    macro_rules! count_digits_radix_shifted {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_shifted>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let count = n.count_digits_radix(radix);
+                            for shift in 0..8 {
+                                let expected = match n {
+                                    0 => 1,
+                                    _ => count + shift as usize,
+                                };
+                                assert_eq!(n.count_digits_radix_shifted(radix, shift), expected);
+                                assert_eq!((&n).count_digits_radix_shifted(radix, shift), expected);
 
-    macro_rules! binary_string_count {
-        ($n:expr) => {
-            format!("{:b}", $n).len() as u32
+                                // Compare with the product when it does not overflow.
+                                let magnitude = match radix {
+                                    10 => n.abs_diff(0) as u128,
+                                    __ => n.bit_pattern_as_unsigned() as u128,
+                                };
+                                let product = (radix as u128)
+                                    .checked_pow(shift)
+                                    .and_then(|power| magnitude.checked_mul(power));
+                                if let Some(product) = product {
+                                    assert_eq!(n.count_digits_radix_shifted(radix, shift), product.count_digits_radix(radix as u128));
+                                }
+                            }
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_shifted>]() {
+                    for radix in [2, 3, 10, 16] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            for shift in [0, 1, 5, u8::MAX as u32] {
+                                assert_eq!(non_zero.count_digits_radix_shifted(radix, shift), n.count_digits_radix_shifted(radix, shift));
+                            }
+                        }
+                    }
+                }
+            }
         };
     }
 
-    macro_rules! octal_string_count {
-        ($n:expr) => {
-            format!("{:o}", $n).len() as u32
+    #[test]
+    fn count_digits_radix_shifted_zero() {
+        assert_eq!(0_u64.count_digits_radix_shifted(10, 0), 1);
+        assert_eq!(0_u64.count_digits_radix_shifted(10, 100), 1);
+        assert_eq!(u64::MAX.count_digits_radix_shifted(10, 100), 120);
+        assert_eq!((-1_i8).count_digits_radix_shifted(2, 4), 12);
+        assert_eq!(None::<u8>.count_digits_radix_shifted(10, 3), 0);
+        assert_eq!(Some(0_u8).count_digits_radix_shifted(10, 3), 1);
+        assert_eq!(0_u8.count_digits_radix_shifted(10, u32::MAX), 1);
+        assert_eq!(
+            1_u8.count_digits_radix_shifted(10, u32::MAX),
+            usize::try_from(u32::MAX).map_or(usize::MAX, |shift| shift.saturating_add(1))
+        );
+    }
+
+    macro_rules! count_digits_radix_truncated {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_truncated>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let count = n.count_digits_radix(radix);
+                            // Compare with the quotient of the magnitude or bit pattern that is counted.
+                            let magnitude = match radix {
+                                10 => n.abs_diff(0) as u128,
+                                _ => n.bit_pattern_as_unsigned() as u128,
+                            };
+                            for drop in 0..=count as u32 + 2 {
+                                let quotient = (radix as u128)
+                                    .checked_pow(drop)
+                                    .map_or(0, |power| magnitude / power);
+                                let expected = quotient.count_digits_radix(radix as u128);
+                                assert_eq!(n.count_digits_radix_truncated(radix, drop), expected);
+                                assert_eq!((&n).count_digits_radix_truncated(radix, drop), expected);
+                            }
+                            assert_eq!(n.count_digits_radix_truncated(radix, u32::MAX), 1);
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_truncated>]() {
+                    for radix in [2, 3, 10, 16] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            for drop in [0, 1, 5, u32::MAX] {
+                                assert_eq!(
+                                    non_zero.count_digits_radix_truncated(radix, drop),
+                                    n.count_digits_radix_truncated(radix, drop)
+                                );
+                            }
+                        }
+                    }
+                }
+            }
         };
     }
 
-    macro_rules! decimal_string_count {
-        ($n:expr) => {{
-            let string = format!("{}", $n);
-            string.strip_prefix('-').unwrap_or(&string).len()
-        }};
+    #[test]
+    fn count_digits_radix_truncated_widths() {
+        // Fewer digits are dropped than the width.
+        assert_eq!(12345_u32.count_digits_radix_truncated(10, 2), 3);
+        assert_eq!((-12345_i32).count_digits_radix_truncated(10, 4), 1);
+        assert_eq!(u64::MAX.count_digits_radix_truncated(16, 15), 1);
+        // As many digits are dropped as the width.
+        assert_eq!(12345_u32.count_digits_radix_truncated(10, 5), 1);
+        assert_eq!(0_u32.count_digits_radix_truncated(10, 1), 1);
+        // More digits are dropped than the width.
+        assert_eq!(12345_u32.count_digits_radix_truncated(10, 6), 1);
+        assert_eq!(0_u32.count_digits_radix_truncated(10, 100), 1);
+        assert_eq!(None::<u8>.count_digits_radix_truncated(10, 1), 0);
+        assert_eq!(Some(0_u8).count_digits_radix_truncated(10, 1), 1);
     }
 
-    macro_rules! hex_string_count {
-        ($n:expr) => {
-            format!("{:x}", $n).len() as u32
+    macro_rules! try_count_digits_radix_u32 {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _try_count_digits_radix_u32>]() {
+                    let max_radix = (0 as $type).wrapping_sub(1).bit_pattern_as_unsigned() as u128;
+                    for radix in [2_u32, 3, 10, 16, 36, 255, 256, 65535, 65536, u32::MAX] {
+                        for n in samples!($type, 10) {
+                            let expected = match radix as u128 <= max_radix {
+                                true => Ok(n.count_digits_radix(radix as _)),
+                                false => Err(RadixError::TooLargeForType),
+                            };
+                            assert_eq!(n.try_count_digits_radix_u32(radix), expected, "{n} radix {radix}");
+                            assert_eq!((&n).try_count_digits_radix_u32(radix), expected);
+                            assert_eq!(n.try_count_digits_radix_u32(radix).ok(), n.count_digits_radix_usize(radix as usize));
+                        }
+                    }
+                    assert_eq!((1 as $type).try_count_digits_radix_u32(0), Err(RadixError::Zero));
+                    assert_eq!((1 as $type).try_count_digits_radix_u32(1), Err(RadixError::One));
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _try_count_digits_radix_u32>]() {
+                    for radix in [0, 1, 2, 10, 16, 300] {
+                        for n in radix_boundaries!($type, 10).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.try_count_digits_radix_u32(radix), n.try_count_digits_radix_u32(radix));
+                        }
+                    }
+                }
+            }
         };
     }
 
-    macro_rules! assert_min {
-        ($type:ty, count_bits) => {
-            assert_eq!(
-                <$type>::MIN.count_bits(),
-                binary_string_count!(<$type>::MIN)
-            );
-            assert_eq!(
-                <$type>::MIN.count_digits_radix(2),
-                binary_string_count!(<$type>::MIN) as usize,
-            );
-        };
-        ($type:ty, count_octal_digits) => {
-            assert_eq!(
-                <$type>::MIN.count_octal_digits(),
-                octal_string_count!(<$type>::MIN),
-            );
-            assert_eq!(
-                <$type>::MIN.count_digits_radix(8),
-                octal_string_count!(<$type>::MIN) as usize,
-            );
-        };
-        ($type:ty, count_digits) => {
-            assert_eq!(
-                <$type>::MIN.count_digits(),
-                decimal_string_count!(<$type>::MIN)
-            );
-            assert_eq!(
-                <$type>::MIN.count_digits_radix(10),
-                decimal_string_count!(<$type>::MIN),
-            );
-        };
-        ($type:ty, count_hex_digits) => {
-            assert_eq!(
-                <$type>::MIN.count_hex_digits(),
-                hex_string_count!(<$type>::MIN),
-            );
-            assert_eq!(
-                <$type>::MIN.count_digits_radix(16),
-                hex_string_count!(<$type>::MIN) as usize,
-            );
-        };
+    #[test]
+    fn try_count_digits_radix_u32_i8_errors() {
+        assert_eq!(5_i8.try_count_digits_radix_u32(0), Err(RadixError::Zero));
+        assert_eq!(5_i8.try_count_digits_radix_u32(1), Err(RadixError::One));
+        assert_eq!(
+            5_i8.try_count_digits_radix_u32(256),
+            Err(RadixError::TooLargeForType)
+        );
+        assert_eq!(
+            5_i8.try_count_digits_radix_u32(u32::MAX),
+            Err(RadixError::TooLargeForType)
+        );
+        assert_eq!((-1_i8).try_count_digits_radix_u32(255), Ok(2));
+        assert_eq!(
+            None::<i8>.try_count_digits_radix_u32(1),
+            Err(RadixError::One)
+        );
+        assert_eq!(None::<i8>.try_count_digits_radix_u32(10), Ok(0));
+        assert_eq!(RadixError::Zero.to_string(), "radix must not be 0");
+        assert_eq!(RadixError::One.to_string(), "radix must not be 1");
     }
 
-    macro_rules! assert_max {
-        ($type:ty, count_bits) => {
-            assert_eq!(
-                <$type>::MAX.count_bits(),
-                binary_string_count!(<$type>::MAX)
-            );
-            assert_eq!(
-                <$type>::MAX.count_digits_radix(2),
-                binary_string_count!(<$type>::MAX) as usize,
-            );
-        };
-        ($type:ty, count_octal_digits) => {
-            assert_eq!(
-                <$type>::MAX.count_octal_digits(),
-                octal_string_count!(<$type>::MAX),
-            );
-            assert_eq!(
-                <$type>::MAX.count_digits_radix(8),
-                octal_string_count!(<$type>::MAX) as usize,
-            );
-        };
-        ($type:ty, count_digits) => {
-            assert_eq!(
-                <$type>::MAX.count_digits(),
-                decimal_string_count!(<$type>::MAX)
-            );
-            assert_eq!(
-                <$type>::MAX.count_digits_radix(10),
-                decimal_string_count!(<$type>::MAX),
-            );
-        };
-        ($type:ty, count_hex_digits) => {
-            assert_eq!(
-                <$type>::MAX.count_hex_digits(),
-                hex_string_count!(<$type>::MAX),
-            );
-            assert_eq!(
-                <$type>::MAX.count_digits_radix(16),
-                hex_string_count!(<$type>::MAX) as usize,
-            );
-        };
-    }
+    macro_rules! write_ascii_digits_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _write_ascii_digits_radix>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let mut buf = [0_u8; 129];
+                            let len = n.write_ascii_digits_radix(radix, false, &mut buf).unwrap();
+                            let digits = std::str::from_utf8(&buf[..len]).unwrap().to_string();
+                            match radix {
+                                02 => assert_eq!(digits, format!("{n:b}")),
+                                08 => assert_eq!(digits, format!("{n:o}")),
+                                10 => assert_eq!(digits, format!("{n}")),
+                                16 => assert_eq!(digits, format!("{n:x}")),
+                                __ => assert_eq!(len, n.count_digits_radix(radix)),
+                            }
+                            let sign = (radix == 10 && digits.starts_with('-')) as usize;
+                            assert_eq!(len, n.count_digits_radix(radix) + sign);
 
-    macro_rules! assert_representations {
-        ($n:expr, count_bits) => {
-            assert_eq!($n.count_bits(), binary_string_count!($n));
-            assert_eq!($n.count_digits_radix(2), binary_string_count!($n) as usize);
-        };
-        ($n:expr, count_octal_digits) => {
-            assert_eq!($n.count_octal_digits(), octal_string_count!($n));
-            assert_eq!($n.count_digits_radix(8), octal_string_count!($n) as usize);
-        };
-        ($n:expr, count_digits) => {
-            assert_eq!($n.count_digits(), decimal_string_count!($n));
-            assert_eq!($n.count_digits_radix(10), decimal_string_count!($n));
-        };
-        ($n:expr, count_hex_digits) => {
-            assert_eq!($n.count_hex_digits(), hex_string_count!($n));
-            assert_eq!($n.count_digits_radix(16), hex_string_count!($n) as usize);
-        };
-        ($n:expr, count_digits_radix_ordering) => {
-            assert!([
-                $n.count_digits_radix(02),
-                $n.count_digits_radix(03),
-                $n.count_digits_radix(04),
-                $n.count_digits_radix(05),
-                $n.count_digits_radix(06),
-                $n.count_digits_radix(07),
-                $n.count_digits_radix(08),
-                $n.count_digits_radix(09),
-                $n.count_digits_radix(11),
-                $n.count_digits_radix(12),
-                $n.count_digits_radix(13),
-                $n.count_digits_radix(14),
-                $n.count_digits_radix(15),
-                $n.count_digits_radix(16),
-            ]
-            .windows(2)
-            .all(|window| window[0] >= window[1]));
-        };
-        ($n:expr, checked_count_digits_radix_ordering) => {
-            assert!([
-                $n.checked_count_digits_radix(02).unwrap(),
-                $n.checked_count_digits_radix(03).unwrap(),
-                $n.checked_count_digits_radix(04).unwrap(),
-                $n.checked_count_digits_radix(05).unwrap(),
-                $n.checked_count_digits_radix(06).unwrap(),
-                $n.checked_count_digits_radix(07).unwrap(),
-                $n.checked_count_digits_radix(08).unwrap(),
-                $n.checked_count_digits_radix(09).unwrap(),
-                $n.checked_count_digits_radix(11).unwrap(),
-                $n.checked_count_digits_radix(12).unwrap(),
-                $n.checked_count_digits_radix(13).unwrap(),
-                $n.checked_count_digits_radix(14).unwrap(),
-                $n.checked_count_digits_radix(15).unwrap(),
-                $n.checked_count_digits_radix(16).unwrap(),
-            ]
-            .windows(2)
-            .all(|window| window[0] >= window[1]));
+                            let mut upper = [0_u8; 129];
+                            assert_eq!(n.write_ascii_digits_radix(radix, true, &mut upper), Ok(len));
+                            assert_eq!(upper[..len], *digits.to_uppercase().as_bytes());
+                            assert_eq!((&n).write_ascii_digits_radix(radix, true, &mut upper), Ok(len));
+
+                            let mut short = vec![b'?'; len - 1];
+                            assert_eq!(n.write_ascii_digits_radix(radix, false, &mut short), Err(len));
+                            assert!(short.iter().all(|&byte| byte == b'?'));
+
+                            let mut exact = vec![0_u8; len];
+                            assert_eq!(n.write_ascii_digits_radix(radix, false, &mut exact), Ok(len));
+                            assert_eq!(exact, buf[..len]);
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _write_ascii_digits_radix>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            let (mut a, mut b) = ([0_u8; 129], [0_u8; 129]);
+                            assert_eq!(non_zero.write_ascii_digits_radix(radix, false, &mut a), n.write_ascii_digits_radix(radix, false, &mut b));
+                            assert_eq!(a, b);
+                        }
+                    }
+                }
+            }
         };
     }
 
-    macro_rules! lower_bound {
-        ($type:ty) => {
-            -100_000 as $type
-        };
+    #[test]
+    fn write_ascii_digits_radix_cases() {
+        let mut buf = [0_u8; 16];
+        assert_eq!(
+            0xBEEF_u16.write_ascii_digits_radix(16, false, &mut buf),
+            Ok(4)
+        );
+        assert_eq!(&buf[..4], b"beef");
+        assert_eq!(
+            0xBEEF_u16.write_ascii_digits_radix(16, true, &mut buf),
+            Ok(4)
+        );
+        assert_eq!(&buf[..4], b"BEEF");
+        assert_eq!(
+            (-42_i8).write_ascii_digits_radix(10, false, &mut buf),
+            Ok(3)
+        );
+        assert_eq!(&buf[..3], b"-42");
+        assert_eq!(i8::MIN.write_ascii_digits_radix(10, false, &mut buf), Ok(4));
+        assert_eq!(&buf[..4], b"-128");
+        assert_eq!((-1_i8).write_ascii_digits_radix(16, false, &mut buf), Ok(2));
+        assert_eq!(&buf[..2], b"ff");
+        assert_eq!(
+            (-42_i8).write_ascii_digits_radix(10, false, &mut buf[..2]),
+            Err(3)
+        );
+        assert_eq!(0_u8.write_ascii_digits_radix(10, false, &mut []), Err(1));
+        assert_eq!(
+            None::<u8>.write_ascii_digits_radix(10, false, &mut []),
+            Ok(0)
+        );
     }
 
-    macro_rules! upper_bound {
-        ($type:ty) => {
-            100_000 as $type
-        };
+    #[test]
+    #[should_panic(expected = "radix must be at most 36 to render digits")]
+    fn write_ascii_digits_radix_37() {
+        1_u32.write_ascii_digits_radix(37, false, &mut [0; 8]).ok();
     }
 
-    macro_rules! min_or_lower_bound {
-        (i8) => {
-            i8::MIN
-        };
-        (i16) => {
-            i16::MIN
-        };
-        (isize) => {{
-            #[cfg(any(target_pointer_width = "64", target_pointer_width = "32"))]
-            const fn min_or_lower_bound() -> isize {
-                lower_bound!(isize)
-            }
-            #[cfg(any(target_pointer_width = "16", target_pointer_width = "8"))]
-            const fn min_or_lower_bound() -> isize {
-                isize::MIN
+    macro_rules! zero_policy {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _zero_policy>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let (one, zero) = (ZeroPolicy::OneDigit, ZeroPolicy::ZeroDigits);
+                            assert_eq!(n.count_digits_radix_with_zero_policy(radix, one), n.count_digits_radix(radix));
+                            assert_eq!(n.count_digits_radix_with_zero_policy(radix, zero), match n {
+                                0 => 0,
+                                _ => n.count_digits_radix(radix),
+                            });
+                            assert_eq!(n.count_digits_with_zero_policy(one), n.count_digits());
+                            assert_eq!(n.count_digits_with_zero_policy(zero), match n {
+                                0 => 0,
+                                _ => n.count_digits(),
+                            });
+                            assert_eq!(Some(n).count_digits_with_zero_policy(zero), n.count_digits_with_zero_policy(zero));
+                        }
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _zero_policy>]() {
+                    for n in samples!($type, 10).filter_map($non_zero_type::new) {
+                        for policy in [ZeroPolicy::OneDigit, ZeroPolicy::ZeroDigits] {
+                            assert_eq!(n.count_digits_with_zero_policy(policy), n.count_digits());
+                            assert_eq!(n.count_digits_radix_with_zero_policy(16, policy), n.count_digits_radix(16));
+                        }
+                    }
+                }
             }
-            min_or_lower_bound()
-        }};
-        ($type:ty) => {
-            lower_bound!($type)
         };
     }
 
-    macro_rules! max_or_upper_bound {
-        (i8) => {
-            i8::MAX
-        };
-        (i16) => {
-            i16::MAX
-        };
-        (u8) => {
-            u8::MAX
-        };
-        (u16) => {
-            u16::MAX
-        };
-        (isize) => {{
-            #[cfg(any(target_pointer_width = "64", target_pointer_width = "32"))]
-            const fn max_or_upper_bound() -> isize {
-                upper_bound!(isize)
+    #[test]
+    fn count_digits_saturating_radix() {
+        for radix in 2..=36 {
+            for n in samples!(u128, radix) {
+                assert_eq!(
+                    n.count_digits_saturating_radix(radix),
+                    n.count_digits_radix(radix)
+                );
             }
-            #[cfg(any(target_pointer_width = "16", target_pointer_width = "8"))]
-            const fn max_or_upper_bound() -> isize {
-                isize::MAX
+            for n in samples!(i128, radix) {
+                assert_eq!(
+                    n.count_digits_saturating_radix(radix),
+                    n.count_digits_radix(radix)
+                );
             }
-            max_or_upper_bound()
-        }};
-        (usize) => {{
-            #[cfg(any(target_pointer_width = "64", target_pointer_width = "32"))]
-            const fn max_or_upper_bound() -> usize {
-                upper_bound!(usize)
+        }
+        assert_eq!(None::<u128>.count_digits_saturating_radix(2), 0);
+        assert_eq!(
+            <NonZeroU128 as Bounded>::MAX.count_digits_saturating_radix(2),
+            128
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "16")]
+    fn count_digits_saturating_radix_16_bit_usize() {
+        assert_eq!(u128::MAX.count_digits_saturating_radix(2), 128);
+        assert_eq!(i128::MIN.count_digits_saturating_radix(2), 128);
+        assert!(u128::MAX.count_digits_saturating_radix(2) < usize::MAX);
+    }
+
+    #[test]
+    #[cfg(all(target_pointer_width = "16", feature = "ethnum"))]
+    fn count_digits_saturating_radix_16_bit_usize_u256() {
+        use ethnum::{I256, U256};
+
+        assert_eq!(U256::MAX.count_digits_saturating_radix(U256::new(2)), 256);
+        assert_eq!(I256::MIN.count_digits_saturating_radix(U256::new(2)), 256);
+    }
+
+    #[test]
+    fn fits_in_digits_radix() {
+        assert!(0xFFF_u32.fits_in_digits_radix(4, 16));
+        assert!(0xFFFF_u32.fits_in_digits_radix(4, 16));
+        assert!(!0x1_0000_u32.fits_in_digits_radix(4, 16));
+        assert!(999_u16.fits_in_digits_radix(3, 10));
+        assert!(!1000_u16.fits_in_digits_radix(3, 10));
+        assert!((-999_i16).fits_in_digits_radix(3, 10));
+        assert!(!(-1000_i16).fits_in_digits_radix(3, 10));
+        assert!(!(-1_i16).fits_in_digits_radix(3, 16));
+        assert!((-1_i16).fits_in_digits_radix(4, 16));
+        assert!(!0_u8.fits_in_digits_radix(0, 10));
+        for radix in 2..=36 {
+            for n in samples!(u64, radix) {
+                let count = n.count_digits_radix(radix) as u32;
+                assert!(n.fits_in_digits_radix(count, radix));
+                assert!(n.fits_in_digits_radix(count + 1, radix));
+                assert!(!n.fits_in_digits_radix(count - 1, radix));
             }
-            #[cfg(any(target_pointer_width = "16", target_pointer_width = "8"))]
-            const fn max_or_upper_bound() -> usize {
-                isize::MAX
+        }
+    }
+
+    macro_rules! is_single_digit {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _is_single_digit>]() {
+                    for radix in 2..=36 {
+                        for n in 0..radix as $type {
+                            assert!(n.is_single_digit_radix(radix));
+                        }
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            assert_eq!(n.is_single_digit_radix(radix), n < radix as $type);
+                            assert_eq!((&n).is_single_digit_radix(radix), n.count_digits_radix(radix) == 1);
+                        }
+                        assert!(!<$type as Bounded>::MAX.is_single_digit_radix(radix));
+                        assert!(!None::<$type>.is_single_digit_radix(radix));
+                    }
+                    for n in samples!($type, 10) {
+                        assert_eq!(n.is_single_digit(), n.is_single_digit_radix(10));
+                    }
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _is_single_digit>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.is_single_digit_radix(radix), n.is_single_digit_radix(radix));
+                            assert_eq!(non_zero.is_single_digit(), n.is_single_digit());
+                        }
+                    }
+                }
             }
-            max_or_upper_bound()
-        }};
-        ($type:ty) => {
-            upper_bound!($type)
         };
     }
 
-    /// Returns an iterator over the pairs boundaries [n, m] for a given radix
-    /// where n is the largest number of its digit-size group and m is the smallest
-    /// number of its digit-size group.
-    macro_rules! radix_boundaries {
-        ($type:ty, $radix:expr) => {
-            std::iter::successors(Some($radix as $type), move |n| {
-                n.checked_mul($radix as $type)
-            })
-            .map(|n| [n - 1, n])
+    #[test]
+    fn is_single_digit_negative_values() {
+        for n in -9..=9_i8 {
+            assert!(n.is_single_digit());
+        }
+        assert!(!(-10_i8).is_single_digit());
+        assert!(!10_i8.is_single_digit());
+        // Negative values are never a single digit in their twos-complement representation.
+        for radix in [2_u8, 3, 8, 16, 36] {
+            assert!(!(-1_i8).is_single_digit_radix(radix));
+            assert!(!i8::MIN.is_single_digit_radix(radix));
+        }
+        assert!(!(-1_i64).is_single_digit_radix(16));
+        assert!((-7_i64).is_single_digit_radix(10));
+    }
+
+    macro_rules! count_digits_radix_rounded_to_power {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _count_digits_radix_rounded_to_power>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let count = n.count_digits_radix(radix);
+                            let rounded = n.count_digits_radix_rounded_to_power(radix);
+                            assert!(rounded == count || rounded == count + 1);
+                            let magnitude = if radix == 10 {
+                                n.to_string().trim_start_matches('-').parse::<u128>().unwrap()
+                            } else {
+                                n.bit_pattern_as_unsigned() as u128
+                            };
+                            // Squares of magnitudes that fit in 64 bits can be compared exactly.
+                            if magnitude <= u64::MAX as u128 {
+                                let power = (radix as u128).pow(count as u32 - 1);
+                                let rounds_up = power
+                                    .checked_mul(power)
+                                    .and_then(|square| square.checked_mul(radix as u128))
+                                    .map_or(false, |threshold| magnitude * magnitude >= threshold);
+                                assert_eq!(rounded, count + rounds_up as usize);
+                            }
+                            assert_eq!((&n).count_digits_radix_rounded_to_power(radix), rounded);
+                        }
+                    }
+                    assert_eq!(None::<$type>.count_digits_radix_rounded_to_power(10), 0);
+                }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _count_digits_radix_rounded_to_power>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(
+                                non_zero.count_digits_radix_rounded_to_power(radix),
+                                n.count_digits_radix_rounded_to_power(radix),
+                            );
+                        }
+                    }
+                }
+            }
         };
     }
 
     #[test]
-    fn helper_radix_boundaries() {
-        assert_eq!(
-            radix_boundaries!(i8, 2).collect::<Vec<_>>(),
-            [[1, 2], [3, 4], [7, 8], [15, 16], [31, 32], [63, 64]],
-        );
+    fn count_digits_radix_rounded_to_power_midpoints() {
+        // Radices that are perfect squares have integer midpoints, which round up.
+        for (radix, root) in [(4_u128, 2_u128), (9, 3), (16, 4), (100, 10)] {
+            let mut power = 1_u128;
+            while let Some(midpoint) = power.checked_mul(root) {
+                let count = power.count_digits_radix(radix);
+                assert_eq!(
+                    (midpoint - 1).count_digits_radix_rounded_to_power(radix),
+                    count
+                );
+                assert_eq!(
+                    midpoint.count_digits_radix_rounded_to_power(radix),
+                    count + 1
+                );
+                power = match power.checked_mul(radix) {
+                    Some(power) => power,
+                    None => break,
+                };
+            }
+        }
+
+        // The midpoints between powers of 10 are powers of 10 multiplied by 3.16227766...
+        for (below, count) in [
+            (3_u128, 1),
+            (31, 2),
+            (316, 3),
+            (3162, 4),
+            (31622, 5),
+            (316227, 6),
+            (3162277, 7),
+            (31622776601683793319988935444327185337, 38),
+            (316227766016837933199889354443271853371, 39),
+        ] {
+            assert_eq!(below.count_digits_radix_rounded_to_power(10), count);
+            assert_eq!(
+                (below + 1).count_digits_radix_rounded_to_power(10),
+                count + 1
+            );
+        }
+        assert_eq!(1234_u32.count_digits_radix_rounded_to_power(10), 4);
+        assert_eq!(7600_u32.count_digits_radix_rounded_to_power(10), 5);
+        assert_eq!((-3162_i16).count_digits_radix_rounded_to_power(10), 4);
+        assert_eq!((-3163_i16).count_digits_radix_rounded_to_power(10), 5);
+        assert_eq!(0_u8.count_digits_radix_rounded_to_power(10), 1);
+        assert_eq!(1_u8.count_digits_radix_rounded_to_power(10), 1);
+
+        // The midpoint between 2^127 and 2^128 is 2^127.5.
+        let below = 240615969168004511545033772477625056927_u128;
+        assert_eq!(below.count_digits_radix_rounded_to_power(2), 128);
+        assert_eq!((below + 1).count_digits_radix_rounded_to_power(2), 129);
+        assert_eq!(u128::MAX.count_digits_radix_rounded_to_power(2), 129);
+        assert_eq!(u128::MAX.count_digits_radix_rounded_to_power(u128::MAX), 2);
         assert_eq!(
-            radix_boundaries!(i16, 10).collect::<Vec<_>>(),
-            [[9, 10], [99, 100], [999, 1000], [9999, 10000]],
+            (u64::MAX as u128).count_digits_radix_rounded_to_power(u128::MAX),
+            1
         );
         assert_eq!(
-            radix_boundaries!(i16, 16).collect::<Vec<_>>(),
-            [[0xF, 0x10], [0xFF, 0x100], [0xFFF, 0x1000]],
+            (1_u128 << 64).count_digits_radix_rounded_to_power(u128::MAX),
+            2
         );
+        // Negative integers round their twos-complement representation in radices other than 10.
+        assert_eq!((-1_i8).count_digits_radix_rounded_to_power(2), 9);
+        assert_eq!((-1_i8).count_digits_radix_rounded_to_power(10), 1);
     }
 
-    /// Returns an iterator of increasing pairs staring with (1, 2).
-    fn increasing_pairs() -> impl Iterator<Item = (usize, usize)> {
-        std::iter::successors(Some(1usize), move |n| n.checked_add(1))
-            .zip(std::iter::successors(Some(2usize), move |n| {
-                n.checked_add(1)
-            }))
+    #[test]
+    fn count_digits_geomean() {
+        let ranges = [
+            (0, 0),
+            (0, u64::MAX),
+            (1, 1),
+            (1, 99),
+            (1, 100),
+            (9, 11),
+            (10, 10_000),
+            (999, 1_000_001),
+            (u32::MAX as u64, u32::MAX as u64 + 2),
+            (1, u64::MAX),
+            (u64::MAX, u64::MAX),
+        ];
+        for (a, b) in ranges {
+            let product = a as u128 * b as u128;
+            let root = isqrt_u128(product);
+            assert!(root * root <= product);
+            assert!((root + 1)
+                .checked_mul(root + 1)
+                .map_or(true, |square| square > product));
+            assert_eq!(crate::count_digits_geomean(a, b), root.count_digits());
+            assert_eq!(crate::count_digits_geomean(b, a), root.count_digits());
+        }
+        assert_eq!(crate::count_digits_geomean(1, 99), 1);
+        assert_eq!(crate::count_digits_geomean(1, 100), 2);
+        assert_eq!(crate::count_digits_geomean(u64::MAX, u64::MAX), 20);
+        assert_eq!(crate::count_digits_geomean(1, u64::MAX), 10);
     }
 
     #[test]
-    fn helper_increasing_pairs() {
+    fn reverse() {
+        assert_eq!(Reverse(123_u32).count_digits(), 3);
+        assert_eq!(Reverse(-0xFF_i16).count_digits_radix(16), 4);
+        assert_eq!(Reverse(0_u8).count_digits_nonempty(), 0);
+        assert_eq!(Reverse(99_u8).count_digits_of_sum(Reverse(1)), 3);
+        assert_eq!(Reverse(<NonZeroU64 as Bounded>::MAX).count_digits(), 20);
+        for n in samples!(i32, 10) {
+            assert_eq!(Reverse(n).count_digits(), n.count_digits());
+            assert_eq!(Reverse(n).count_bits(), n.count_bits());
+            assert_eq!(
+                Reverse(n).bit_pattern_as_unsigned(),
+                n.bit_pattern_as_unsigned()
+            );
+            assert_eq!(
+                Reverse(n).count_digits_of_abs_diff(Reverse(n.wrapping_neg())),
+                n.count_digits_of_abs_diff(n.wrapping_neg())
+            );
+        }
+    }
+
+    #[test]
+    fn zero_policy_default() {
+        assert_eq!(ZeroPolicy::default(), ZeroPolicy::OneDigit);
         assert_eq!(
-            increasing_pairs().take(5).collect::<Vec<_>>(),
-            [(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)],
+            None::<u8>.count_digits_with_zero_policy(ZeroPolicy::OneDigit),
+            0
         );
+        assert_eq!(0_u8.count_digits_with_zero_policy(ZeroPolicy::default()), 1);
     }
 
-    macro_rules! min_and_max {
+    macro_rules! palindromic_rotation_count_radix {
         ($type:ty, $non_zero_type:ty) => {
-            min_and_max!($type, $non_zero_type, count_bits);
-            min_and_max!($type, $non_zero_type, count_octal_digits);
-            min_and_max!($type, $non_zero_type, count_digits);
-            min_and_max!($type, $non_zero_type, count_hex_digits);
-        };
-        ($type:ty, $non_zero_type:ty, $function:ident) => {
             paste! {
                 #[test]
-                fn [<$type _min_ $function>]() {
-                    assert_min!($type, $function);
+                fn [<$type _palindromic_rotation_count_radix>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let mut buf = [0_u8; 129];
+                            let len = n.write_ascii_digits_radix(radix, false, &mut buf).unwrap();
+                            let digits = buf[..len].iter().copied().filter(|&byte| byte != b'-').collect::<Vec<_>>();
+                            let expected = (0..digits.len())
+                                .filter(|&shift| {
+                                    let mut rotation = digits.clone();
+                                    rotation.rotate_left(shift);
+                                    rotation.iter().eq(rotation.iter().rev())
+                                })
+                                .count() as u32;
+                            assert_eq!(n.palindromic_rotation_count_radix(radix), expected, "{n} radix {radix}");
+                            assert_eq!(Some(n).palindromic_rotation_count_radix(radix), expected);
+                        }
+                    }
                 }
                 #[test]
-                fn [<$type _max_ $function>]() {
-                    assert_max!($type, $function);
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _palindromic_rotation_count_radix>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(non_zero.palindromic_rotation_count_radix(radix), n.palindromic_rotation_count_radix(radix));
+                        }
+                    }
                 }
+            }
+        };
+    }
 
+    #[test]
+    fn palindromic_rotation_count_radix_cases() {
+        assert_eq!(121_u32.palindromic_rotation_count_radix(10), 1);
+        assert_eq!(1212_u32.palindromic_rotation_count_radix(10), 0);
+        assert_eq!(1221_u32.palindromic_rotation_count_radix(10), 2);
+        assert_eq!(7919_u32.palindromic_rotation_count_radix(10), 0);
+        assert_eq!(1111_u32.palindromic_rotation_count_radix(10), 4);
+        assert_eq!(101_u32.palindromic_rotation_count_radix(10), 1);
+        assert_eq!(0_u8.palindromic_rotation_count_radix(10), 1);
+        assert_eq!((-121_i32).palindromic_rotation_count_radix(10), 1);
+        assert_eq!(u128::MAX.palindromic_rotation_count_radix(2), 128);
+        assert_eq!(u128::MAX.palindromic_rotation_count_radix(10), 0);
+        assert_eq!(None::<u8>.palindromic_rotation_count_radix(10), 0);
+        assert_eq!(Reverse(121_u8).palindromic_rotation_count_radix(10), 1);
+    }
+
+    macro_rules! digits_radix_le {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
                 #[test]
-                #[allow(non_snake_case)]
-                fn [<$non_zero_type _min_ $function>]() {
-                    assert_min!($non_zero_type, $function);
+                fn [<$type _digits_radix_le>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let mut buf = [0_u8; 129];
+                            let len = n.write_ascii_digits_radix(radix, false, &mut buf).unwrap();
+                            let mut digits = n.digits_radix_le(radix).collect::<Vec<_>>();
+                            assert_eq!(digits.len(), n.count_digits_radix(radix));
+                            digits.reverse();
+                            let ascii = buf[..len]
+                                .iter()
+                                .filter(|&&byte| byte != b'-')
+                                .map(|&byte| (byte as char).to_digit(36).unwrap() as u8)
+                                .collect::<Vec<_>>();
+                            assert_eq!(digits, ascii);
+                            assert!(Some(n).digits_radix_le(radix).eq(n.digits_radix_le(radix)));
+                        }
+                    }
                 }
                 #[test]
                 #[allow(non_snake_case)]
-                fn [<$non_zero_type _max_ $function>]() {
-                    assert_max!($non_zero_type, $function);
+                fn [<$non_zero_type _digits_radix_le>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert!(non_zero.digits_radix_le(radix).eq(n.digits_radix_le(radix)));
+                        }
+                    }
                 }
             }
         };
     }
 
-    macro_rules! iteration {
-        ($signage:ident, $type:ty, $non_zero_type:ty) => {
-            iteration!($signage, $type, $non_zero_type, count_bits);
-            iteration!($signage, $type, $non_zero_type, count_octal_digits);
-            iteration!($signage, $type, $non_zero_type, count_digits);
-            iteration!($signage, $type, $non_zero_type, count_hex_digits);
-            iteration!($signage, $type, $non_zero_type, count_digits_radix_ordering);
-            iteration!(
-                $signage,
-                $type,
-                $non_zero_type,
-                checked_count_digits_radix_ordering
-            );
-        };
-        (signed, $type:ty, $non_zero_type:ty, $function:ident) => {
+    macro_rules! weighted_digits_radix {
+        ($type:ty, $non_zero_type:ty) => {
             paste! {
                 #[test]
-                fn [<$type _iteration_ $function>]() {
-                    let max = max_or_upper_bound!($type);
-                    let min = min_or_lower_bound!($type);
-                    for n in min..=max {
-                        assert_representations!(n, $function);
+                fn [<$type _weighted_digits_radix>]() {
+                    for radix in [2, 3, 7, 8, 10, 16, 36] {
+                        for n in samples!($type, radix) {
+                            let magnitude = n
+                                .digits_radix_le(radix)
+                                .enumerate()
+                                .fold(0_u128, |sum, (position, digit)| {
+                                    sum + digit as u128 * (radix as u128).pow(position as u32)
+                                });
+                            let weighted = n.weighted_digits_radix(radix);
+                            assert_eq!(weighted.len(), n.count_digits_radix(radix));
+                            let sum = weighted.clone().fold(0_u128, |sum, (digit, weight)| {
+                                sum + digit as u128 * weight
+                            });
+                            assert_eq!(sum, magnitude);
+                            let mut digits = n.digits_radix_le(radix).collect::<Vec<_>>();
+                            digits.reverse();
+                            assert!(weighted.clone().map(|(digit, _)| digit).eq(digits));
+                            let positions = 0..n.count_digits_radix(radix) as u32;
+                            let weights = positions.rev().map(|position| (radix as u128).pow(position));
+                            assert!(weighted.map(|(_, weight)| weight).eq(weights));
+                        }
                     }
                 }
-
                 #[test]
                 #[allow(non_snake_case)]
-                fn [<$non_zero_type _iteration_ $function>]() {
-                    let max = max_or_upper_bound!($type);
-                    let min = min_or_lower_bound!($type);
-                    for n in min..=max {
-                        if n == 0 { continue; }
-                        let n = $non_zero_type::new(n).unwrap();
-                        assert_representations!(n, $function);
+                fn [<$non_zero_type _weighted_digits_radix>]() {
+                    for radix in [2, 3, 7, 8, 10, 16, 36] {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert!(non_zero.weighted_digits_radix(radix).eq(n.weighted_digits_radix(radix)));
+                        }
                     }
                 }
             }
         };
-        (unsigned, $type:ty, $non_zero_type:ty, $function:ident) => {
+    }
+
+    macro_rules! leading_repeat_count_radix {
+        ($type:ty, $non_zero_type:ty) => {
             paste! {
                 #[test]
-                fn [<$type _iteration_ $function>]() {
-                    let max = max_or_upper_bound!($type);
-                    for n in $type::MIN..=max {
-                        assert_representations!(n, $function);
+                fn [<$type _leading_repeat_count_radix>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix) {
+                            let mut digits = n.weighted_digits_radix(radix).map(|(digit, _)| digit);
+                            let msd = digits.next().unwrap();
+                            let run = 1 + digits.take_while(|&digit| digit == msd).count() as u32;
+                            assert_eq!(n.leading_repeat_count_radix(radix), run);
+                            assert_eq!((&n).leading_repeat_count_radix(radix), run);
+                        }
+                        assert_eq!(None::<$type>.leading_repeat_count_radix(radix), 0);
                     }
                 }
+                #[test]
+                #[allow(non_snake_case)]
+                fn [<$non_zero_type _leading_repeat_count_radix>]() {
+                    for radix in 2..=36 {
+                        for n in radix_boundaries!($type, radix).flatten() {
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert_eq!(
+                                non_zero.leading_repeat_count_radix(radix),
+                                n.leading_repeat_count_radix(radix),
+                            );
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn leading_repeat_count_radix_runs() {
+        // Every digit is the same.
+        assert_eq!(333_u32.leading_repeat_count_radix(10), 3);
+        assert_eq!(7_u32.leading_repeat_count_radix(10), 1);
+        assert_eq!(u32::MAX.leading_repeat_count_radix(2), 32);
+        assert_eq!(u64::MAX.leading_repeat_count_radix(16), 16);
+        assert_eq!(0o777_u16.leading_repeat_count_radix(8), 3);
+        // No digit repeats the most-significant digit.
+        assert_eq!(1234_u32.leading_repeat_count_radix(10), 1);
+        assert_eq!(1000_u32.leading_repeat_count_radix(10), 1);
+        assert_eq!(0b10_u8.leading_repeat_count_radix(2), 1);
+        // A leading run followed by other digits, including the same digit after the run.
+        assert_eq!(7789_u32.leading_repeat_count_radix(10), 2);
+        assert_eq!(5550555_u32.leading_repeat_count_radix(10), 3);
+        assert_eq!(0xFFF0_u32.leading_repeat_count_radix(16), 3);
+        assert_eq!((-7789_i32).leading_repeat_count_radix(10), 2);
+        assert_eq!((-2_i8).leading_repeat_count_radix(2), 7);
+        assert_eq!(200_u8.leading_repeat_count_radix(200), 1);
+        assert_eq!(u8::MAX.leading_repeat_count_radix(16), 2);
+    }
+
+    #[test]
+    fn weighted_digits_radix_large_radix_and_wrappers() {
+        for radix in [37_u32, 62, 85, 200, 256] {
+            for n in samples!(u32, radix) {
+                let sum: u128 = n
+                    .weighted_digits_radix(radix)
+                    .map(|(digit, weight)| digit as u128 * weight)
+                    .sum();
+                assert_eq!(sum, n as u128);
+            }
+        }
+        assert!(u128::MAX
+            .weighted_digits_radix(2)
+            .all(|(digit, _)| digit == 1));
+        assert_eq!(
+            u128::MAX.weighted_digits_radix(2).next(),
+            Some((1, 1 << 127))
+        );
+        assert!(Some(1337_u32)
+            .weighted_digits_radix(10)
+            .eq(1337_u32.weighted_digits_radix(10)));
+        assert_eq!(None::<u32>.weighted_digits_radix(10).next(), None);
+        assert!(Reverse(255_u8)
+            .weighted_digits_radix(16)
+            .eq([(15, 16), (15, 1)]));
+    }
+
+    #[test]
+    fn digits_radix_le_large_radix() {
+        for radix in [37_u32, 62, 85, 200, 256] {
+            for n in samples!(u32, radix) {
+                let digits = n.digits_radix_le(radix).collect::<Vec<_>>();
+                assert_eq!(digits.len(), n.count_digits_radix(radix));
+                let value = digits
+                    .iter()
+                    .rev()
+                    .fold(0_u64, |value, &digit| value * radix as u64 + digit as u64);
+                assert_eq!(value, n as u64);
+            }
+        }
+        assert!(u128::MAX.digits_radix_le(256).eq([0xFF; 16]));
+        assert!((-2_i16).digits_radix_le(256).eq([0xFE, 0xFF]));
+        assert!(0_u64.digits_radix_le(62).eq([0]));
+        assert!(None::<u8>.digits_radix_le(10).eq([]));
+    }
 
+    #[test]
+    #[should_panic(expected = "radix must be at most 256 to yield digits as u8")]
+    fn digits_radix_le_257() {
+        1_u32.digits_radix_le(257).for_each(drop);
+    }
+
+    macro_rules! max_digits_radix {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _max_digits_radix>]() {
+                    let max_pattern = (0 as $type).wrapping_sub(1);
+                    for radix in 2..=36_u32 {
+                        let max_digits = max_digits_radix(<$type>::BITS, radix);
+                        for n in samples!($type, radix) {
+                            assert!(max_digits >= n.count_digits_radix(radix as _), "{n} radix {radix}");
+                        }
+                        assert_eq!(max_digits, max_pattern.bit_pattern_as_unsigned().count_digits_radix(radix as _));
+                        let expected = (<$type>::BITS as f64 / (radix as f64).log2()).ceil() as usize;
+                        assert_eq!(max_digits, expected, "radix {radix}");
+                    }
+                }
                 #[test]
                 #[allow(non_snake_case)]
-                fn [<$non_zero_type _iteration_ $function>]() {
-                    let max = max_or_upper_bound!($type);
-                    for n in $non_zero_type::MIN.get()..=max {
-                        let n = $non_zero_type::new(n).unwrap();
-                        assert_representations!(n, $function);
+                fn [<$non_zero_type _max_digits_radix>]() {
+                    for radix in 2..=36_u32 {
+                        let max_digits = max_digits_radix(<$type>::BITS, radix);
+                        for n in samples!($type, radix).filter_map($non_zero_type::new) {
+                            assert!(max_digits >= n.count_digits_radix(radix as _));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn max_digits_radix_wide_and_narrow() {
+        const HEX_U64: usize = max_digits_radix(u64::BITS, 16);
+        assert_eq!([0_u8; HEX_U64].len(), 16);
+        assert_eq!(max_digits_radix(0, 2), 1);
+        assert_eq!(max_digits_radix(1, 10), 1);
+        assert_eq!(max_digits_radix(4, 10), 2);
+        assert_eq!(max_digits_radix(12, 16), 3);
+        assert_eq!(max_digits_radix(256, 2), 256);
+        assert_eq!(max_digits_radix(256, 16), 64);
+        assert_eq!(max_digits_radix(256, 10), 86);
+        for bits in 1..=128 {
+            for radix in 2..=36 {
+                let max = u128::MAX >> (128 - bits);
+                assert_eq!(
+                    max_digits_radix(bits, radix),
+                    max.count_digits_radix(radix as u128)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn base_prefix_len_alternate_format() {
+        for n in [0_u64, 1, 7, 8, 15, 16, 1337, u64::MAX] {
+            let width = |radix| base_prefix_len(radix) + n.count_digits_radix(radix as u64);
+            assert_eq!(format!("{n:#b}").len(), width(2));
+            assert_eq!(format!("{n:#o}").len(), width(8));
+            assert_eq!(format!("{n:#x}").len(), width(16));
+            assert_eq!(format!("{n:#}").len(), width(10));
+        }
+        const PREFIXES: [usize; 5] = [
+            base_prefix_len(2),
+            base_prefix_len(8),
+            base_prefix_len(16),
+            base_prefix_len(10),
+            base_prefix_len(36),
+        ];
+        assert_eq!(PREFIXES, [2, 2, 2, 0, 0]);
+        assert_eq!(base_prefix_len(0), 0);
+        assert_eq!(base_prefix_len(1), 0);
+    }
+
+    #[test]
+    fn radix_log2() {
+        for k in 1..u32::BITS {
+            assert_eq!(crate::radix_log2(1 << k), Some(k));
+        }
+        for radix in [0, 1, 3, 6, 10, 12, 36, 100, (1 << 31) + 1, u32::MAX] {
+            assert_eq!(crate::radix_log2(radix), None);
+        }
+        const HEX: Option<u32> = crate::radix_log2(16);
+        assert_eq!(HEX, Some(4));
+    }
+
+    /// Evaluates the `const fn` helpers in a `const` context for a fixed set of values of a type,
+    /// and checks that the `const`-computed arrays are equal to the results of the trait.
+    macro_rules! const_evaluation {
+        ($type:ty, $non_zero_type:ty) => {
+            paste! {
+                #[test]
+                fn [<$type _const_evaluation>]() {
+                    const VALUES: [$type; 7] = [
+                        <$type as Bounded>::MIN,
+                        <$type as Bounded>::MAX,
+                        0,
+                        1,
+                        (0 as $type).wrapping_sub(1),
+                        <$type as Bounded>::MIN / 3,
+                        <$type as Bounded>::MAX / 10 + 1,
+                    ];
+                    const MAGNITUDES: [u128; 7] = {
+                        let mut magnitudes = [0; 7];
+                        let mut index = 0;
+                        while index < VALUES.len() {
+                            magnitudes[index] = VALUES[index].abs_diff(0) as u128;
+                            index += 1;
+                        }
+                        magnitudes
+                    };
+                    const IS_NEGATIVE: [bool; 7] = {
+                        let mut is_negative = [false; 7];
+                        let mut index = 0;
+                        while index < VALUES.len() {
+                            is_negative[index] = VALUES[index] < 0 as $type;
+                            index += 1;
+                        }
+                        is_negative
+                    };
+                    const DECIMAL_DIGITS: [Option<usize>; 7] = {
+                        let mut counts = [None; 7];
+                        let mut index = 0;
+                        while index < VALUES.len() {
+                            if MAGNITUDES[index] <= u64::MAX as u128 {
+                                counts[index] = Some(count_decimal_digits_u64(MAGNITUDES[index] as u64));
+                            }
+                            index += 1;
+                        }
+                        counts
+                    };
+                    const BALANCED_TERNARY_DIGITS: [u32; 7] = {
+                        let mut counts = [0; 7];
+                        let mut index = 0;
+                        while index < VALUES.len() {
+                            counts[index] = count_balanced_ternary_digits_of_magnitude(MAGNITUDES[index]);
+                            index += 1;
+                        }
+                        counts
+                    };
+                    const IS_DECIMAL_BOUNDARY: [bool; 7] = {
+                        let mut is_boundary = [false; 7];
+                        let mut index = 0;
+                        while index < VALUES.len() {
+                            is_boundary[index] = !IS_NEGATIVE[index]
+                                && is_radix_boundary_of_magnitude(MAGNITUDES[index], 10);
+                            index += 1;
+                        }
+                        is_boundary
+                    };
+                    const IS_DECIMAL_WIDTH_FLOOR: [bool; 7] = {
+                        let mut is_floor = [false; 7];
+                        let mut index = 0;
+                        while index < VALUES.len() {
+                            is_floor[index] = !IS_NEGATIVE[index]
+                                && is_radix_width_floor_of_magnitude(MAGNITUDES[index], 10);
+                            index += 1;
+                        }
+                        is_floor
+                    };
+                    const DECIMAL_DIFFS_FROM_MAX: [usize; 7] = {
+                        let mut counts = [0; 7];
+                        let mut index = 0;
+                        while index < VALUES.len() {
+                            counts[index] = digit_diff_count_of_magnitudes(MAGNITUDES[index], MAGNITUDES[1], 10);
+                            index += 1;
+                        }
+                        counts
+                    };
+                    const MAX_DIGITS: [usize; 35] = {
+                        let mut counts = [0; 35];
+                        let mut radix = 2;
+                        while radix <= 36 {
+                            counts[radix as usize - 2] = max_digits_radix(<$type>::BITS, radix);
+                            radix += 1;
+                        }
+                        counts
+                    };
+
+                    for (index, n) in VALUES.into_iter().enumerate() {
+                        if let Some(count) = DECIMAL_DIGITS[index] {
+                            assert_eq!(count, n.count_digits());
+                        }
+                        assert_eq!(BALANCED_TERNARY_DIGITS[index], n.count_balanced_ternary_digits());
+                        assert_eq!(IS_DECIMAL_BOUNDARY[index], n.is_radix_boundary(10));
+                        assert_eq!(IS_DECIMAL_WIDTH_FLOOR[index], n.is_radix_width_floor(10));
+                        assert_eq!(DECIMAL_DIFFS_FROM_MAX[index], n.digit_diff_count_radix(<$type as Bounded>::MAX, 10));
+                    }
+                    let all_ones = (0 as $type).wrapping_sub(1).bit_pattern_as_unsigned();
+                    for radix in 2..=36 {
+                        assert_eq!(MAX_DIGITS[radix as usize - 2], all_ones.count_digits_radix(radix));
                     }
                 }
             }
-        };
+        };
+    }
+
+    #[test]
+    fn count_digits_radix_be_bytes() {
+        for radix in (2..=36).chain([64, 100, 255, 256, 1 << 16, 1_000_000_007, u32::MAX]) {
+            for n in samples!(u8, radix.min(u8::MAX as u32)) {
+                assert_eq!(
+                    crate::count_digits_radix_be_bytes(&n.to_be_bytes(), radix),
+                    (n as u32).count_digits_radix(radix)
+                );
+            }
+            for n in samples!(u16, radix.min(u16::MAX as u32)) {
+                assert_eq!(
+                    crate::count_digits_radix_be_bytes(&n.to_be_bytes(), radix),
+                    (n as u32).count_digits_radix(radix)
+                );
+            }
+            for n in samples!(u32, radix) {
+                assert_eq!(
+                    crate::count_digits_radix_be_bytes(&n.to_be_bytes(), radix),
+                    n.count_digits_radix(radix)
+                );
+            }
+            for n in samples!(u64, radix) {
+                assert_eq!(
+                    crate::count_digits_radix_be_bytes(&n.to_be_bytes(), radix),
+                    n.count_digits_radix(radix as u64)
+                );
+            }
+            for n in samples!(u128, radix) {
+                assert_eq!(
+                    crate::count_digits_radix_be_bytes(&n.to_be_bytes(), radix),
+                    n.count_digits_radix(radix as u128)
+                );
+            }
+        }
+        assert_eq!(crate::count_digits_radix_be_bytes(&[], 10), 1);
+        assert_eq!(crate::count_digits_radix_be_bytes(&[0; 40], 10), 1);
+        // 2^256 - 1 has 78 decimal digits, and 2^256 has 78 as well.
+        assert_eq!(crate::count_digits_radix_be_bytes(&[0xFF; 32], 10), 78);
+        let mut two_to_the_256 = [0; 33];
+        two_to_the_256[0] = 1;
+        assert_eq!(crate::count_digits_radix_be_bytes(&two_to_the_256, 10), 78);
+        assert_eq!(crate::count_digits_radix_be_bytes(&two_to_the_256, 2), 257);
+        assert_eq!(crate::count_digits_radix_be_bytes(&two_to_the_256, 16), 65);
+    }
+
+    #[test]
+    fn count_hex_digits_be_bytes() {
+        for n in samples!(u128, 16) {
+            assert_eq!(
+                crate::count_hex_digits_be_bytes(&n.to_be_bytes()),
+                n.count_hex_digits() as usize
+            );
+        }
+        // Leading zero bytes.
+        assert_eq!(crate::count_hex_digits_be_bytes(&[0, 0, 0xAB]), 2);
+        assert_eq!(crate::count_hex_digits_be_bytes(&[0, 0xAB, 0]), 4);
+        // Leading zero nibbles.
+        assert_eq!(crate::count_hex_digits_be_bytes(&[0x0A, 0xBC]), 3);
+        assert_eq!(crate::count_hex_digits_be_bytes(&[0, 0x01, 0, 0]), 5);
+        assert_eq!(crate::count_hex_digits_be_bytes(&[0x10, 0, 0]), 6);
+        // Zero.
+        assert_eq!(crate::count_hex_digits_be_bytes(&[]), 1);
+        assert_eq!(crate::count_hex_digits_be_bytes(&[0; 32]), 1);
+        // Integers that are wider than any primitive type.
+        assert_eq!(crate::count_hex_digits_be_bytes(&[0xFF; 40]), 80);
+        for radix_bytes in [[0x01; 33], [0x10; 33]] {
+            assert_eq!(
+                crate::count_hex_digits_be_bytes(&radix_bytes),
+                crate::count_digits_radix_be_bytes(&radix_bytes, 16)
+            );
+        }
+        const WIDTH: usize = crate::count_hex_digits_be_bytes(&[0x00, 0x0F, 0xFF]);
+        assert_eq!(WIDTH, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn max_digits_radix_invalid_radix() {
+        max_digits_radix(8, 1);
+    }
+
+    /// Returns the decimal digits of the product of a slice, multiplied digit by digit so that the product cannot overflow.
+    fn decimal_string_product(slice: &[u64]) -> String {
+        let mut digits = vec![1_u8];
+        for &n in slice {
+            let mut carry = 0_u128;
+            for digit in digits.iter_mut() {
+                let product = *digit as u128 * n as u128 + carry;
+                *digit = (product % 10) as u8;
+                carry = product / 10;
+            }
+            while carry != 0 {
+                digits.push((carry % 10) as u8);
+                carry /= 10;
+            }
+            while digits.len() > 1 && digits.last() == Some(&0) {
+                digits.pop();
+            }
+        }
+        digits
+            .iter()
+            .rev()
+            .map(|&digit| (b'0' + digit) as char)
+            .collect()
+    }
+
+    #[test]
+    fn helper_decimal_string_product() {
+        assert_eq!(decimal_string_product(&[]), "1");
+        assert_eq!(decimal_string_product(&[12, 0, 7]), "0");
+        assert_eq!(decimal_string_product(&[25, 4, 10]), "1000");
+        assert_eq!(
+            decimal_string_product(&[u64::MAX, u64::MAX]),
+            (u64::MAX as u128 * u64::MAX as u128).to_string()
+        );
+    }
+
+    /// Returns the digits of a non-negative decimal string in the given radix, from the most-significant digit,
+    /// dividing the decimal digits by the radix one digit at a time so that any length of string can be converted.
+    fn digits_of_decimal_string(decimal: &str, radix: u32) -> Vec<u8> {
+        let mut quotient = decimal
+            .bytes()
+            .map(|byte| (byte - b'0') as u32)
+            .collect::<Vec<_>>();
+        let mut digits = Vec::new();
+        loop {
+            let mut remainder = 0;
+            for digit in quotient.iter_mut() {
+                let dividend = remainder * 10 + *digit;
+                *digit = dividend / radix;
+                remainder = dividend % radix;
+            }
+            digits.push(remainder as u8);
+            let leading_zeros = quotient.iter().take_while(|&&digit| digit == 0).count();
+            quotient.drain(..leading_zeros);
+            if quotient.is_empty() {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
     }
 
-    macro_rules! pass_by_reference {
+    #[test]
+    fn helper_digits_of_decimal_string() {
+        assert_eq!(digits_of_decimal_string("0", 10), [0]);
+        assert_eq!(digits_of_decimal_string("1234", 10), [1, 2, 3, 4]);
+        assert_eq!(digits_of_decimal_string("255", 16), [15, 15]);
+        assert_eq!(digits_of_decimal_string("8", 2), [1, 0, 0, 0]);
+        assert_eq!(digits_of_decimal_string("1296", 36), [1, 0, 0]);
+    }
+
+    macro_rules! digit_iteration_order {
         ($type:ty, $non_zero_type:ty) => {
-            pass_by_reference!($type, $non_zero_type, count_bits);
-            pass_by_reference!($type, $non_zero_type, count_octal_digits);
-            pass_by_reference!($type, $non_zero_type, count_hex_digits);
-            pass_by_reference!($type, $non_zero_type, count_digits);
-            pass_by_reference!($type, $non_zero_type, count_digits_radix);
-            pass_by_reference!($type, $non_zero_type, checked_count_digits_radix);
-        };
-        ($type:ty, $non_zero_type:ty, count_digits_radix) => {
             paste! {
                 #[test]
-                fn [<$type _pass_by_reference_count_digits_radix>]() {
-                    for radix in 2..20 {
-                        for n in radix_boundaries!($type, radix).flatten() {
-                            assert_eq!(CountDigits::count_digits_radix(n, radix), CountDigits::count_digits_radix(&n, radix));
+                fn [<$type _digit_iteration_order>]() {
+                    for radix in 2..=36 {
+                        for n in samples!($type, radix).into_iter().chain([0, <$type as Bounded>::MIN, <$type as Bounded>::MAX]) {
+                            let written = match radix {
+                                2 => format!("{:b}", n),
+                                8 => format!("{:o}", n),
+                                16 => format!("{:x}", n),
+                                _ => String::new(),
+                            };
+                            let expected = if written.is_empty() {
+                                let magnitude = if radix == 10 {
+                                    n.to_string().trim_start_matches('-').to_string()
+                                } else {
+                                    n.bit_pattern_as_unsigned().to_string()
+                                };
+                                digits_of_decimal_string(&magnitude, radix as u32)
+                            } else {
+                                written.chars().map(|c| c.to_digit(radix as u32).unwrap() as u8).collect()
+                            };
+                            let mut le = n.digits_radix_le(radix).collect::<Vec<_>>();
+                            le.reverse();
+                            assert_eq!(le, expected);
+                            let weighted = n.weighted_digits_radix(radix).map(|(digit, _)| digit).collect::<Vec<_>>();
+                            assert_eq!(weighted, expected);
                         }
+                        assert!((0 as $type).digits_radix_le(radix).eq([0]));
+                        assert!((0 as $type).weighted_digits_radix(radix).eq([(0, 1)]));
                     }
                 }
                 #[test]
                 #[allow(non_snake_case)]
-                fn [<$non_zero_type _pass_by_reference_count_digits_radix>]() {
-                    for radix in 2..20 {
+                fn [<$non_zero_type _digit_iteration_order>]() {
+                    for radix in 2..=36 {
                         for n in radix_boundaries!($type, radix).flatten() {
-                            let n = $non_zero_type::new(n).unwrap();
-                            assert_eq!(CountDigits::count_digits_radix(n, radix), CountDigits::count_digits_radix(&n, radix));
+                            let non_zero = $non_zero_type::new(n).unwrap();
+                            assert!(non_zero.digits_radix_le(radix).eq(n.digits_radix_le(radix)));
+                            assert!(non_zero.weighted_digits_radix(radix).eq(n.weighted_digits_radix(radix)));
                         }
                     }
                 }
             }
         };
-        ($type:ty, $non_zero_type:ty, checked_count_digits_radix) => {
-            paste! {
-                #[test]
-                fn [<$type _pass_by_reference_checked_count_digits_radix>]() {
-                    for radix in 2..20 {
-                        for n in radix_boundaries!($type, radix).flatten() {
-                            assert_eq!(CountDigits::checked_count_digits_radix(n, radix), CountDigits::checked_count_digits_radix(&n, radix));
-                        }
-                    }
-                }
-                #[test]
-                #[allow(non_snake_case)]
-                fn [<$non_zero_type _pass_by_reference_checked_count_digits_radix>]() {
-                    for radix in 2..20 {
-                        for n in radix_boundaries!($type, radix).flatten() {
-                            let n = $non_zero_type::new(n).unwrap();
-                            assert_eq!(CountDigits::checked_count_digits_radix(n, radix), CountDigits::checked_count_digits_radix(&n, radix));
-                        }
-                    }
-                }
+    }
+
+    #[test]
+    fn digit_iteration_order_of_negative_values() {
+        // Radix 10 yields the digits of the magnitude, and every other radix the twos-complement digits.
+        assert!((-1337_i16).digits_radix_le(10).eq([7, 3, 3, 1]));
+        assert!((-1_i16).digits_radix_le(16).eq([0xF; 4]));
+        assert!((-2_i8).digits_radix_le(2).eq([0, 1, 1, 1, 1, 1, 1, 1]));
+        assert!((-2_i8)
+            .weighted_digits_radix(2)
+            .map(|(digit, _)| digit)
+            .eq([1, 1, 1, 1, 1, 1, 1, 0]));
+        assert!(i8::MIN
+            .digits_radix_le(3)
+            .eq(digits_of_decimal_string("128", 3).into_iter().rev()));
+    }
+
+    #[test]
+    fn count_digits_of_product() {
+        let slices: [&[u64]; 10] = [
+            &[],
+            &[0],
+            &[7],
+            &[9, 11, 101],
+            &[2; 127],
+            &[2; 128],
+            &[u64::MAX, u64::MAX],
+            &[u64::MAX, u64::MAX, u64::MAX],
+            &[1_000_000_007; 9],
+            &[10; 60],
+        ];
+        for slice in slices {
+            assert_eq!(
+                crate::count_digits_of_product(slice),
+                decimal_string_product(slice).len() as u128,
+                "{slice:?}"
+            );
+        }
+
+        for len in 0..40 {
+            let factorial = (1..=len).collect::<Vec<u64>>();
+            let expected = decimal_string_product(&factorial).len() as u128;
+            assert_eq!(crate::count_digits_of_product(&factorial), expected);
+            if let Some(product) = factorial
+                .iter()
+                .try_fold(1_u128, |product, &n| product.checked_mul(n as u128))
+            {
+                assert_eq!(
+                    crate::count_digits_of_product(&factorial),
+                    product.count_digits() as u128
+                );
             }
-        };
-        ($type:ty, $non_zero_type:ty, $function:ident) => {
+        }
+
+        let mut powers_of_ten = vec![5_u64; 30];
+        powers_of_ten.extend([2_u64; 30]);
+        assert_eq!(crate::count_digits_of_product(&powers_of_ten), 31);
+        powers_of_ten.push(0);
+        assert_eq!(crate::count_digits_of_product(&powers_of_ten), 1);
+    }
+
+    /// Returns the decimal digits of the sum of two magnitudes, added digit by digit so that the sum cannot overflow.
+    fn decimal_string_sum(a: u128, b: u128) -> String {
+        let (a, b) = (a.to_string(), b.to_string());
+        let width = a.len().max(b.len());
+        let (a, b) = (format!("{a:0>width$}"), format!("{b:0>width$}"));
+        let mut digits = Vec::new();
+        let mut carry = 0;
+        for (x, y) in a.bytes().rev().zip(b.bytes().rev()) {
+            let digit = (x - b'0') + (y - b'0') + carry;
+            digits.push(b'0' + digit % 10);
+            carry = digit / 10;
+        }
+        if carry != 0 {
+            digits.push(b'0' + carry);
+        }
+        digits.iter().rev().map(|&digit| digit as char).collect()
+    }
+
+    #[test]
+    fn helper_decimal_string_sum() {
+        assert_eq!(decimal_string_sum(0, 0), "0");
+        assert_eq!(decimal_string_sum(999, 1), "1000");
+        assert_eq!(
+            decimal_string_sum(u128::MAX, u128::MAX),
+            "680564733841876926926749214863536422910"
+        );
+    }
+
+    macro_rules! count_digits_of_sum {
+        ($type:ty, $non_zero_type:ty) => {
             paste! {
                 #[test]
-                fn [<$type _pass_by_reference_ $function>]() {
-                    for radix in 2..20 {
-                        for n in radix_boundaries!($type, radix).flatten() {
-                            assert_eq!(CountDigits::$function(n), CountDigits::$function(&n));
+                fn [<$type _count_digits_of_sum>]() {
+                    let samples = samples!($type, 10).chain(samples!($type, 7)).collect::<Vec<_>>();
+                    for &a in &samples {
+                        for &b in &samples {
+                            let (ma, mb) = (a.abs_diff(0) as u128, b.abs_diff(0) as u128);
+                            let expected = match (a < 0 as $type) == (b < 0 as $type) {
+                                true => decimal_string_sum(ma, mb).len(),
+                                false => ma.abs_diff(mb).to_string().len(),
+                            };
+                            assert_eq!(a.count_digits_of_sum(b), expected, "{a} + {b}");
+                            assert_eq!((&a).count_digits_of_sum(&b), expected);
+                            assert_eq!(a.count_digits_of_sum(b), b.count_digits_of_sum(a));
                         }
                     }
                 }
                 #[test]
                 #[allow(non_snake_case)]
-                fn [<$non_zero_type _pass_by_reference_ $function>]() {
-                    for radix in 2..20 {
-                        for n in radix_boundaries!($type, radix).flatten() {
-                            let n = $non_zero_type::new(n).unwrap();
-                            assert_eq!(CountDigits::$function(n), CountDigits::$function(&n));
+                fn [<$non_zero_type _count_digits_of_sum>]() {
+                    for a in samples!($type, 10).filter_map($non_zero_type::new) {
+                        for b in samples!($type, 10).filter_map($non_zero_type::new) {
+                            assert_eq!(a.count_digits_of_sum(b), a.get().count_digits_of_sum(b.get()));
                         }
                     }
                 }
@@ -1649,114 +13154,71 @@ mod count_digits {
         };
     }
 
-    macro_rules! invalid_radix {
-        ($type:ty, $non_zero_type:ty) => {
-            invalid_radix!(0, $type, $non_zero_type);
-            invalid_radix!(1, $type, $non_zero_type);
-        };
-        ($radix:expr, $type:ty, $non_zero_type:ty) => {
-            paste! {
-                #[test]
-                #[should_panic(expected = "base of integer logarithm must be at least 2")]
-                fn [<$type _invalid_radix_ $radix>]() {
-                    (1 as $type).count_digits_radix($radix);
-                }
-                #[test]
-                fn [<$type _invalid_radix_ $radix _checked>]() {
-                    assert!((1 as $type).checked_count_digits_radix($radix).is_none());
-                }
-
-
-                #[test]
-                #[allow(non_snake_case)]
-                #[should_panic(expected = "base of integer logarithm must be at least 2")]
-                fn [<$non_zero_type _invalid_radix_ $radix>]() {
-                    $non_zero_type::new(1).unwrap().count_digits_radix($radix);
-                }
-                #[test]
-                #[allow(non_snake_case)]
-                fn [<$non_zero_type _invalid_radix_ $radix _checked>]() {
-                    assert!($non_zero_type::new(1).unwrap().checked_count_digits_radix($radix).is_none());
-                }
-            }
-        };
+    #[test]
+    fn count_digits_of_sum_overflow() {
+        assert_eq!(u32::MAX.count_digits_of_sum(u32::MAX), 10);
+        assert_eq!(u32::MAX.count_digits_of_sum(1), 10);
+        assert_eq!(u64::MAX.count_digits_of_sum(1), 20);
+        assert_eq!(999_999_999_u32.count_digits_of_sum(1), 10);
+        assert_eq!(i8::MAX.count_digits_of_sum(i8::MAX), 3);
+        assert_eq!(i64::MIN.count_digits_of_sum(i64::MIN), 20);
+        assert_eq!(u128::MAX.count_digits_of_sum(u128::MAX), 39);
+        assert_eq!(i128::MIN.count_digits_of_sum(i128::MIN), 39);
+        assert_eq!(i128::MIN.count_digits_of_sum(i128::MAX), 1);
+        assert_eq!(None::<u8>.count_digits_of_sum(Some(1)), 0);
+        assert_eq!(Some(200_u8).count_digits_of_sum(Some(200)), 3);
     }
 
-    macro_rules! boundaries_for_radix {
+    macro_rules! count_digits_fixed_point {
         ($type:ty, $non_zero_type:ty) => {
-            boundaries_for_radix!(02, $type, $non_zero_type);
-            boundaries_for_radix!(03, $type, $non_zero_type);
-            boundaries_for_radix!(04, $type, $non_zero_type);
-            boundaries_for_radix!(05, $type, $non_zero_type);
-            boundaries_for_radix!(06, $type, $non_zero_type);
-            boundaries_for_radix!(07, $type, $non_zero_type);
-            boundaries_for_radix!(08, $type, $non_zero_type);
-            boundaries_for_radix!(09, $type, $non_zero_type);
-            boundaries_for_radix!(10, $type, $non_zero_type);
-            boundaries_for_radix!(11, $type, $non_zero_type);
-            boundaries_for_radix!(12, $type, $non_zero_type);
-            boundaries_for_radix!(13, $type, $non_zero_type);
-            boundaries_for_radix!(14, $type, $non_zero_type);
-            boundaries_for_radix!(15, $type, $non_zero_type);
-            boundaries_for_radix!(16, $type, $non_zero_type);
-            boundaries_for_radix!(17, $type, $non_zero_type);
-            boundaries_for_radix!(18, $type, $non_zero_type);
-            boundaries_for_radix!(19, $type, $non_zero_type);
-        };
-        ($radix:expr, $type:ty, $non_zero_type:ty) => {
             paste! {
                 #[test]
-                fn [<$type _boundaries_for_radix_ $radix>]() {
-                    assert!(
-                        radix_boundaries!($type, $radix)
-                            .map(|[n, m]| (n.count_digits_radix($radix), m.count_digits_radix($radix)))
-                            .zip(increasing_pairs())
-                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
-                                lhs_expected == lhs_actual && rhs_expected == rhs_actual
-                            })
-                    )
-                }
-                #[test]
-                fn [<$type _boundaries_for_radix_ $radix _checked>]() {
-                    assert!(
-                        radix_boundaries!($type, $radix)
-                            .map(|[n, m]| (n.checked_count_digits_radix($radix), m.checked_count_digits_radix($radix)))
-                            .zip(increasing_pairs())
-                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
-                                lhs_expected == lhs_actual.unwrap() && rhs_expected == rhs_actual.unwrap()
-                            })
-                    )
-                }
-                #[test]
-                #[allow(non_snake_case)]
-                fn [<$non_zero_type _boundaries_for_radix_ $radix>]() {
-                    assert!(
-                        radix_boundaries!($type, $radix)
-                            .map(|[n, m]| (<$non_zero_type>::new(n).unwrap(), <$non_zero_type>::new(m).unwrap()))
-                            .map(|(n, m)| (n.count_digits_radix($radix), m.count_digits_radix($radix)))
-                            .zip(increasing_pairs())
-                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
-                                lhs_expected == lhs_actual && rhs_expected == rhs_actual
-                            })
-                    )
+                fn [<$type _count_digits_fixed_point>]() {
+                    for n in samples!($type, 10) {
+                        let magnitude = n.abs_diff(0);
+                        for scale in [0_u32, 1, 2, 3, 10, 40] {
+                            let divisor = 10_u128.checked_pow(scale);
+                            let (integer, fraction) = match divisor {
+                                Some(divisor) => (magnitude as u128 / divisor, magnitude as u128 % divisor),
+                                None => (0, magnitude as u128),
+                            };
+                            let fraction = match scale {
+                                0 => String::new(),
+                                _ => format!("{fraction:0>width$}", width = scale as usize),
+                            };
+                            let expected = (integer.to_string().len(), fraction.len());
+                            assert_eq!(n.count_digits_fixed_point(scale), expected, "{n} scale {scale}");
+                            assert_eq!((&n).count_digits_fixed_point(scale), expected);
+                        }
+                    }
                 }
                 #[test]
                 #[allow(non_snake_case)]
-                fn [<$non_zero_type _boundaries_for_radix_ $radix _checked>]() {
-                    assert!(
-                        radix_boundaries!($type, $radix)
-                            .map(|[n, m]| (<$non_zero_type>::new(n).unwrap(), <$non_zero_type>::new(m).unwrap()))
-                            .map(|(n, m)| (n.checked_count_digits_radix($radix), m.checked_count_digits_radix($radix)))
-                            .zip(increasing_pairs())
-                            .all(|((lhs_actual, rhs_actual), (lhs_expected, rhs_expected))| {
-                                lhs_expected == lhs_actual.unwrap() && rhs_expected == rhs_actual.unwrap()
-                            })
-                    )
+                fn [<$non_zero_type _count_digits_fixed_point>]() {
+                    for n in samples!($type, 10).filter_map($non_zero_type::new) {
+                        for scale in [0, 2, 5] {
+                            assert_eq!(n.count_digits_fixed_point(scale), n.get().count_digits_fixed_point(scale));
+                        }
+                    }
                 }
             }
         };
     }
 
+    #[test]
+    fn count_digits_fixed_point_scales() {
+        assert_eq!(12345_u32.count_digits_fixed_point(0), (5, 0));
+        assert_eq!(12345_u32.count_digits_fixed_point(2), (3, 2));
+        assert_eq!(12345_u32.count_digits_fixed_point(4), (1, 4));
+        assert_eq!(12345_u32.count_digits_fixed_point(5), (1, 5));
+        assert_eq!(12345_u32.count_digits_fixed_point(8), (1, 8));
+        assert_eq!(0_u32.count_digits_fixed_point(0), (1, 0));
+        assert_eq!(0_u32.count_digits_fixed_point(2), (1, 2));
+        assert_eq!((-99_i8).count_digits_fixed_point(2), (1, 2));
+        assert_eq!(i64::MIN.count_digits_fixed_point(2), (17, 2));
+        assert_eq!(None::<u32>.count_digits_fixed_point(2), (0, 0));
+    }
+
     macro_rules! add_test {
         ($name:ident, $($args:tt)+) => {
             $name!($($args)*);
@@ -1829,4 +13291,939 @@ mod count_digits {
     add_test!(pass_by_reference, u64, NonZeroU64);
     add_test!(pass_by_reference, u128, NonZeroU128);
     add_test!(pass_by_reference, usize, NonZeroUsize);
+
+    add_test!(free_functions, i8, NonZeroI8);
+    add_test!(free_functions, i16, NonZeroI16);
+    add_test!(free_functions, i32, NonZeroI32);
+    add_test!(free_functions, i64, NonZeroI64);
+    add_test!(free_functions, i128, NonZeroI128);
+    add_test!(free_functions, isize, NonZeroIsize);
+    add_test!(free_functions, u8, NonZeroU8);
+    add_test!(free_functions, u16, NonZeroU16);
+    add_test!(free_functions, u32, NonZeroU32);
+    add_test!(free_functions, u64, NonZeroU64);
+    add_test!(free_functions, u128, NonZeroU128);
+    add_test!(free_functions, usize, NonZeroUsize);
+
+    add_test!(digit_width_delta, i8, NonZeroI8);
+    add_test!(digit_width_delta, i16, NonZeroI16);
+    add_test!(digit_width_delta, i32, NonZeroI32);
+    add_test!(digit_width_delta, i64, NonZeroI64);
+    add_test!(digit_width_delta, i128, NonZeroI128);
+    add_test!(digit_width_delta, isize, NonZeroIsize);
+    add_test!(digit_width_delta, u8, NonZeroU8);
+    add_test!(digit_width_delta, u16, NonZeroU16);
+    add_test!(digit_width_delta, u32, NonZeroU32);
+    add_test!(digit_width_delta, u64, NonZeroU64);
+    add_test!(digit_width_delta, u128, NonZeroU128);
+    add_test!(digit_width_delta, usize, NonZeroUsize);
+
+    add_test!(balanced_ternary, i8, NonZeroI8);
+    add_test!(balanced_ternary, i16, NonZeroI16);
+    add_test!(balanced_ternary, i32, NonZeroI32);
+    add_test!(balanced_ternary, i64, NonZeroI64);
+    add_test!(balanced_ternary, i128, NonZeroI128);
+    add_test!(balanced_ternary, isize, NonZeroIsize);
+    add_test!(balanced_ternary, u8, NonZeroU8);
+    add_test!(balanced_ternary, u16, NonZeroU16);
+    add_test!(balanced_ternary, u32, NonZeroU32);
+    add_test!(balanced_ternary, u64, NonZeroU64);
+    add_test!(balanced_ternary, u128, NonZeroU128);
+    add_test!(balanced_ternary, usize, NonZeroUsize);
+
+    add_test!(gray_code, i8, NonZeroI8);
+    add_test!(gray_code, i16, NonZeroI16);
+    add_test!(gray_code, i32, NonZeroI32);
+    add_test!(gray_code, i64, NonZeroI64);
+    add_test!(gray_code, i128, NonZeroI128);
+    add_test!(gray_code, isize, NonZeroIsize);
+    add_test!(gray_code, u8, NonZeroU8);
+    add_test!(gray_code, u16, NonZeroU16);
+    add_test!(gray_code, u32, NonZeroU32);
+    add_test!(gray_code, u64, NonZeroU64);
+    add_test!(gray_code, u128, NonZeroU128);
+    add_test!(gray_code, usize, NonZeroUsize);
+
+    add_test!(nonzero_counts, i8, NonZeroI8);
+    add_test!(nonzero_counts, i16, NonZeroI16);
+    add_test!(nonzero_counts, i32, NonZeroI32);
+    add_test!(nonzero_counts, i64, NonZeroI64);
+    add_test!(nonzero_counts, i128, NonZeroI128);
+    add_test!(nonzero_counts, isize, NonZeroIsize);
+    add_test!(nonzero_counts, u8, NonZeroU8);
+    add_test!(nonzero_counts, u16, NonZeroU16);
+    add_test!(nonzero_counts, u32, NonZeroU32);
+    add_test!(nonzero_counts, u64, NonZeroU64);
+    add_test!(nonzero_counts, u128, NonZeroU128);
+    add_test!(nonzero_counts, usize, NonZeroUsize);
+
+    add_test!(bit_pattern_as_unsigned, i8, NonZeroI8);
+    add_test!(bit_pattern_as_unsigned, i16, NonZeroI16);
+    add_test!(bit_pattern_as_unsigned, i32, NonZeroI32);
+    add_test!(bit_pattern_as_unsigned, i64, NonZeroI64);
+    add_test!(bit_pattern_as_unsigned, i128, NonZeroI128);
+    add_test!(bit_pattern_as_unsigned, isize, NonZeroIsize);
+    add_test!(bit_pattern_as_unsigned, u8, NonZeroU8);
+    add_test!(bit_pattern_as_unsigned, u16, NonZeroU16);
+    add_test!(bit_pattern_as_unsigned, u32, NonZeroU32);
+    add_test!(bit_pattern_as_unsigned, u64, NonZeroU64);
+    add_test!(bit_pattern_as_unsigned, u128, NonZeroU128);
+    add_test!(bit_pattern_as_unsigned, usize, NonZeroUsize);
+
+    add_test!(count_digits_of_complement, i8, NonZeroI8);
+    add_test!(count_digits_of_complement, i16, NonZeroI16);
+    add_test!(count_digits_of_complement, i32, NonZeroI32);
+    add_test!(count_digits_of_complement, i64, NonZeroI64);
+    add_test!(count_digits_of_complement, i128, NonZeroI128);
+    add_test!(count_digits_of_complement, isize, NonZeroIsize);
+    add_test!(count_digits_of_complement, u8, NonZeroU8);
+    add_test!(count_digits_of_complement, u16, NonZeroU16);
+    add_test!(count_digits_of_complement, u32, NonZeroU32);
+    add_test!(count_digits_of_complement, u64, NonZeroU64);
+    add_test!(count_digits_of_complement, u128, NonZeroU128);
+    add_test!(count_digits_of_complement, usize, NonZeroUsize);
+
+    add_test!(min_twos_complement_bits, i8, NonZeroI8);
+    add_test!(min_twos_complement_bits, i16, NonZeroI16);
+    add_test!(min_twos_complement_bits, i32, NonZeroI32);
+    add_test!(min_twos_complement_bits, i64, NonZeroI64);
+    add_test!(min_twos_complement_bits, i128, NonZeroI128);
+    add_test!(min_twos_complement_bits, isize, NonZeroIsize);
+    add_test!(min_twos_complement_bits, u8, NonZeroU8);
+    add_test!(min_twos_complement_bits, u16, NonZeroU16);
+    add_test!(min_twos_complement_bits, u32, NonZeroU32);
+    add_test!(min_twos_complement_bits, u64, NonZeroU64);
+    add_test!(min_twos_complement_bits, u128, NonZeroU128);
+    add_test!(min_twos_complement_bits, usize, NonZeroUsize);
+
+    add_test!(per_byte_decimal_widths, i8, NonZeroI8);
+    add_test!(per_byte_decimal_widths, i16, NonZeroI16);
+    add_test!(per_byte_decimal_widths, i32, NonZeroI32);
+    add_test!(per_byte_decimal_widths, i64, NonZeroI64);
+    add_test!(per_byte_decimal_widths, i128, NonZeroI128);
+    add_test!(per_byte_decimal_widths, isize, NonZeroIsize);
+    add_test!(per_byte_decimal_widths, u8, NonZeroU8);
+    add_test!(per_byte_decimal_widths, u16, NonZeroU16);
+    add_test!(per_byte_decimal_widths, u32, NonZeroU32);
+    add_test!(per_byte_decimal_widths, u64, NonZeroU64);
+    add_test!(per_byte_decimal_widths, u128, NonZeroU128);
+    add_test!(per_byte_decimal_widths, usize, NonZeroUsize);
+
+    add_test!(ilog_fallback, i8, NonZeroI8);
+    add_test!(ilog_fallback, i16, NonZeroI16);
+    add_test!(ilog_fallback, i32, NonZeroI32);
+    add_test!(ilog_fallback, i64, NonZeroI64);
+    add_test!(ilog_fallback, i128, NonZeroI128);
+    add_test!(ilog_fallback, isize, NonZeroIsize);
+    add_test!(ilog_fallback, u8, NonZeroU8);
+    add_test!(ilog_fallback, u16, NonZeroU16);
+    add_test!(ilog_fallback, u32, NonZeroU32);
+    add_test!(ilog_fallback, u64, NonZeroU64);
+    add_test!(ilog_fallback, u128, NonZeroU128);
+    add_test!(ilog_fallback, usize, NonZeroUsize);
+
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, i8, NonZeroI8);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, i16, NonZeroI16);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, i32, NonZeroI32);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, i64, NonZeroI64);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, i128, NonZeroI128);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, isize, NonZeroIsize);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, u8, NonZeroU8);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, u16, NonZeroU16);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, u32, NonZeroU32);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, u64, NonZeroU64);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, u128, NonZeroU128);
+    #[cfg(feature = "alloc")]
+    add_test!(to_string_radix, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_rounded_to_power, i8, NonZeroI8);
+    add_test!(count_digits_radix_rounded_to_power, i16, NonZeroI16);
+    add_test!(count_digits_radix_rounded_to_power, i32, NonZeroI32);
+    add_test!(count_digits_radix_rounded_to_power, i64, NonZeroI64);
+    add_test!(count_digits_radix_rounded_to_power, i128, NonZeroI128);
+    add_test!(count_digits_radix_rounded_to_power, isize, NonZeroIsize);
+    add_test!(count_digits_radix_rounded_to_power, u8, NonZeroU8);
+    add_test!(count_digits_radix_rounded_to_power, u16, NonZeroU16);
+    add_test!(count_digits_radix_rounded_to_power, u32, NonZeroU32);
+    add_test!(count_digits_radix_rounded_to_power, u64, NonZeroU64);
+    add_test!(count_digits_radix_rounded_to_power, u128, NonZeroU128);
+    add_test!(count_digits_radix_rounded_to_power, usize, NonZeroUsize);
+
+    add_test!(is_single_digit, i8, NonZeroI8);
+    add_test!(is_single_digit, i16, NonZeroI16);
+    add_test!(is_single_digit, i32, NonZeroI32);
+    add_test!(is_single_digit, i64, NonZeroI64);
+    add_test!(is_single_digit, i128, NonZeroI128);
+    add_test!(is_single_digit, isize, NonZeroIsize);
+    add_test!(is_single_digit, u8, NonZeroU8);
+    add_test!(is_single_digit, u16, NonZeroU16);
+    add_test!(is_single_digit, u32, NonZeroU32);
+    add_test!(is_single_digit, u64, NonZeroU64);
+    add_test!(is_single_digit, u128, NonZeroU128);
+    add_test!(is_single_digit, usize, NonZeroUsize);
+
+    add_test!(count_digits_base, i8, NonZeroI8);
+    add_test!(count_digits_base, i16, NonZeroI16);
+    add_test!(count_digits_base, i32, NonZeroI32);
+    add_test!(count_digits_base, i64, NonZeroI64);
+    add_test!(count_digits_base, i128, NonZeroI128);
+    add_test!(count_digits_base, isize, NonZeroIsize);
+    add_test!(count_digits_base, u8, NonZeroU8);
+    add_test!(count_digits_base, u16, NonZeroU16);
+    add_test!(count_digits_base, u32, NonZeroU32);
+    add_test!(count_digits_base, u64, NonZeroU64);
+    add_test!(count_digits_base, u128, NonZeroU128);
+    add_test!(count_digits_base, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_aligned, i8, NonZeroI8);
+    add_test!(count_digits_radix_aligned, i16, NonZeroI16);
+    add_test!(count_digits_radix_aligned, i32, NonZeroI32);
+    add_test!(count_digits_radix_aligned, i64, NonZeroI64);
+    add_test!(count_digits_radix_aligned, i128, NonZeroI128);
+    add_test!(count_digits_radix_aligned, isize, NonZeroIsize);
+    add_test!(count_digits_radix_aligned, u8, NonZeroU8);
+    add_test!(count_digits_radix_aligned, u16, NonZeroU16);
+    add_test!(count_digits_radix_aligned, u32, NonZeroU32);
+    add_test!(count_digits_radix_aligned, u64, NonZeroU64);
+    add_test!(count_digits_radix_aligned, u128, NonZeroU128);
+    add_test!(count_digits_radix_aligned, usize, NonZeroUsize);
+
+    add_test!(count_digit_groups, i8, NonZeroI8);
+    add_test!(count_digit_groups, i16, NonZeroI16);
+    add_test!(count_digit_groups, i32, NonZeroI32);
+    add_test!(count_digit_groups, i64, NonZeroI64);
+    add_test!(count_digit_groups, i128, NonZeroI128);
+    add_test!(count_digit_groups, isize, NonZeroIsize);
+    add_test!(count_digit_groups, u8, NonZeroU8);
+    add_test!(count_digit_groups, u16, NonZeroU16);
+    add_test!(count_digit_groups, u32, NonZeroU32);
+    add_test!(count_digit_groups, u64, NonZeroU64);
+    add_test!(count_digit_groups, u128, NonZeroU128);
+    add_test!(count_digit_groups, usize, NonZeroUsize);
+
+    add_test!(magnitude_tier, i8, NonZeroI8);
+    add_test!(magnitude_tier, i16, NonZeroI16);
+    add_test!(magnitude_tier, i32, NonZeroI32);
+    add_test!(magnitude_tier, i64, NonZeroI64);
+    add_test!(magnitude_tier, i128, NonZeroI128);
+    add_test!(magnitude_tier, isize, NonZeroIsize);
+    add_test!(magnitude_tier, u8, NonZeroU8);
+    add_test!(magnitude_tier, u16, NonZeroU16);
+    add_test!(magnitude_tier, u32, NonZeroU32);
+    add_test!(magnitude_tier, u64, NonZeroU64);
+    add_test!(magnitude_tier, u128, NonZeroU128);
+    add_test!(magnitude_tier, usize, NonZeroUsize);
+
+    add_test!(count_digits_nonempty, i8, NonZeroI8);
+    add_test!(count_digits_nonempty, i16, NonZeroI16);
+    add_test!(count_digits_nonempty, i32, NonZeroI32);
+    add_test!(count_digits_nonempty, i64, NonZeroI64);
+    add_test!(count_digits_nonempty, i128, NonZeroI128);
+    add_test!(count_digits_nonempty, isize, NonZeroIsize);
+    add_test!(count_digits_nonempty, u8, NonZeroU8);
+    add_test!(count_digits_nonempty, u16, NonZeroU16);
+    add_test!(count_digits_nonempty, u32, NonZeroU32);
+    add_test!(count_digits_nonempty, u64, NonZeroU64);
+    add_test!(count_digits_nonempty, u128, NonZeroU128);
+    add_test!(count_digits_nonempty, usize, NonZeroUsize);
+
+    add_test!(digit_counter, i8, NonZeroI8);
+    add_test!(digit_counter, i16, NonZeroI16);
+    add_test!(digit_counter, i32, NonZeroI32);
+    add_test!(digit_counter, i64, NonZeroI64);
+    add_test!(digit_counter, i128, NonZeroI128);
+    add_test!(digit_counter, isize, NonZeroIsize);
+    add_test!(digit_counter, u8, NonZeroU8);
+    add_test!(digit_counter, u16, NonZeroU16);
+    add_test!(digit_counter, u32, NonZeroU32);
+    add_test!(digit_counter, u64, NonZeroU64);
+    add_test!(digit_counter, u128, NonZeroU128);
+    add_test!(digit_counter, usize, NonZeroUsize);
+
+    add_test!(hex_formatted_width, i8, NonZeroI8);
+    add_test!(hex_formatted_width, i16, NonZeroI16);
+    add_test!(hex_formatted_width, i32, NonZeroI32);
+    add_test!(hex_formatted_width, i64, NonZeroI64);
+    add_test!(hex_formatted_width, i128, NonZeroI128);
+    add_test!(hex_formatted_width, isize, NonZeroIsize);
+    add_test!(hex_formatted_width, u8, NonZeroU8);
+    add_test!(hex_formatted_width, u16, NonZeroU16);
+    add_test!(hex_formatted_width, u32, NonZeroU32);
+    add_test!(hex_formatted_width, u64, NonZeroU64);
+    add_test!(hex_formatted_width, u128, NonZeroU128);
+    add_test!(hex_formatted_width, usize, NonZeroUsize);
+
+    add_test!(non_zero_parity, i8, NonZeroI8);
+    add_test!(non_zero_parity, i16, NonZeroI16);
+    add_test!(non_zero_parity, i32, NonZeroI32);
+    add_test!(non_zero_parity, i64, NonZeroI64);
+    add_test!(non_zero_parity, i128, NonZeroI128);
+    add_test!(non_zero_parity, isize, NonZeroIsize);
+    add_test!(non_zero_parity, u8, NonZeroU8);
+    add_test!(non_zero_parity, u16, NonZeroU16);
+    add_test!(non_zero_parity, u32, NonZeroU32);
+    add_test!(non_zero_parity, u64, NonZeroU64);
+    add_test!(non_zero_parity, u128, NonZeroU128);
+    add_test!(non_zero_parity, usize, NonZeroUsize);
+
+    add_test!(by_ref, i8, NonZeroI8);
+    add_test!(by_ref, i16, NonZeroI16);
+    add_test!(by_ref, i32, NonZeroI32);
+    add_test!(by_ref, i64, NonZeroI64);
+    add_test!(by_ref, i128, NonZeroI128);
+    add_test!(by_ref, isize, NonZeroIsize);
+    add_test!(by_ref, u8, NonZeroU8);
+    add_test!(by_ref, u16, NonZeroU16);
+    add_test!(by_ref, u32, NonZeroU32);
+    add_test!(by_ref, u64, NonZeroU64);
+    add_test!(by_ref, u128, NonZeroU128);
+    add_test!(by_ref, usize, NonZeroUsize);
+
+    add_test!(signed_min, i8, NonZeroI8);
+    add_test!(signed_min, i16, NonZeroI16);
+    add_test!(signed_min, i32, NonZeroI32);
+    add_test!(signed_min, i64, NonZeroI64);
+    add_test!(signed_min, i128, NonZeroI128);
+    add_test!(signed_min, isize, NonZeroIsize);
+
+    add_test!(count_digits_in_first_valid_radix, i8, NonZeroI8);
+    add_test!(count_digits_in_first_valid_radix, i16, NonZeroI16);
+    add_test!(count_digits_in_first_valid_radix, i32, NonZeroI32);
+    add_test!(count_digits_in_first_valid_radix, i64, NonZeroI64);
+    add_test!(count_digits_in_first_valid_radix, i128, NonZeroI128);
+    add_test!(count_digits_in_first_valid_radix, isize, NonZeroIsize);
+    add_test!(count_digits_in_first_valid_radix, u8, NonZeroU8);
+    add_test!(count_digits_in_first_valid_radix, u16, NonZeroU16);
+    add_test!(count_digits_in_first_valid_radix, u32, NonZeroU32);
+    add_test!(count_digits_in_first_valid_radix, u64, NonZeroU64);
+    add_test!(count_digits_in_first_valid_radix, u128, NonZeroU128);
+    add_test!(count_digits_in_first_valid_radix, usize, NonZeroUsize);
+
+    add_test!(is_radix_boundary, i8, NonZeroI8);
+    add_test!(is_radix_boundary, i16, NonZeroI16);
+    add_test!(is_radix_boundary, i32, NonZeroI32);
+    add_test!(is_radix_boundary, i64, NonZeroI64);
+    add_test!(is_radix_boundary, i128, NonZeroI128);
+    add_test!(is_radix_boundary, isize, NonZeroIsize);
+    add_test!(is_radix_boundary, u8, NonZeroU8);
+    add_test!(is_radix_boundary, u16, NonZeroU16);
+    add_test!(is_radix_boundary, u32, NonZeroU32);
+    add_test!(is_radix_boundary, u64, NonZeroU64);
+    add_test!(is_radix_boundary, u128, NonZeroU128);
+    add_test!(is_radix_boundary, usize, NonZeroUsize);
+
+    add_test!(count_and_msd_radix, i8, NonZeroI8);
+    add_test!(count_and_msd_radix, i16, NonZeroI16);
+    add_test!(count_and_msd_radix, i32, NonZeroI32);
+    add_test!(count_and_msd_radix, i64, NonZeroI64);
+    add_test!(count_and_msd_radix, i128, NonZeroI128);
+    add_test!(count_and_msd_radix, isize, NonZeroIsize);
+    add_test!(count_and_msd_radix, u8, NonZeroU8);
+    add_test!(count_and_msd_radix, u16, NonZeroU16);
+    add_test!(count_and_msd_radix, u32, NonZeroU32);
+    add_test!(count_and_msd_radix, u64, NonZeroU64);
+    add_test!(count_and_msd_radix, u128, NonZeroU128);
+    add_test!(count_and_msd_radix, usize, NonZeroUsize);
+
+    add_test!(count_bits_leading_zeros, i8, NonZeroI8);
+    add_test!(count_bits_leading_zeros, i16, NonZeroI16);
+    add_test!(count_bits_leading_zeros, i32, NonZeroI32);
+    add_test!(count_bits_leading_zeros, i64, NonZeroI64);
+    add_test!(count_bits_leading_zeros, i128, NonZeroI128);
+    add_test!(count_bits_leading_zeros, isize, NonZeroIsize);
+    add_test!(count_bits_leading_zeros, u8, NonZeroU8);
+    add_test!(count_bits_leading_zeros, u16, NonZeroU16);
+    add_test!(count_bits_leading_zeros, u32, NonZeroU32);
+    add_test!(count_bits_leading_zeros, u64, NonZeroU64);
+    add_test!(count_bits_leading_zeros, u128, NonZeroU128);
+    add_test!(count_bits_leading_zeros, usize, NonZeroUsize);
+
+    add_test!(value_with_digits_radix, i8, NonZeroI8);
+    add_test!(value_with_digits_radix, i16, NonZeroI16);
+    add_test!(value_with_digits_radix, i32, NonZeroI32);
+    add_test!(value_with_digits_radix, i64, NonZeroI64);
+    add_test!(value_with_digits_radix, i128, NonZeroI128);
+    add_test!(value_with_digits_radix, isize, NonZeroIsize);
+    add_test!(value_with_digits_radix, u8, NonZeroU8);
+    add_test!(value_with_digits_radix, u16, NonZeroU16);
+    add_test!(value_with_digits_radix, u32, NonZeroU32);
+    add_test!(value_with_digits_radix, u64, NonZeroU64);
+    add_test!(value_with_digits_radix, u128, NonZeroU128);
+    add_test!(value_with_digits_radix, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_min, i8, NonZeroI8);
+    add_test!(count_digits_radix_min, i16, NonZeroI16);
+    add_test!(count_digits_radix_min, i32, NonZeroI32);
+    add_test!(count_digits_radix_min, i64, NonZeroI64);
+    add_test!(count_digits_radix_min, i128, NonZeroI128);
+    add_test!(count_digits_radix_min, isize, NonZeroIsize);
+    add_test!(count_digits_radix_min, u8, NonZeroU8);
+    add_test!(count_digits_radix_min, u16, NonZeroU16);
+    add_test!(count_digits_radix_min, u32, NonZeroU32);
+    add_test!(count_digits_radix_min, u64, NonZeroU64);
+    add_test!(count_digits_radix_min, u128, NonZeroU128);
+    add_test!(count_digits_radix_min, usize, NonZeroUsize);
+
+    add_test!(count_digits_of_abs_diff, i8, NonZeroI8);
+    add_test!(count_digits_of_abs_diff, i16, NonZeroI16);
+    add_test!(count_digits_of_abs_diff, i32, NonZeroI32);
+    add_test!(count_digits_of_abs_diff, i64, NonZeroI64);
+    add_test!(count_digits_of_abs_diff, i128, NonZeroI128);
+    add_test!(count_digits_of_abs_diff, isize, NonZeroIsize);
+    add_test!(count_digits_of_abs_diff, u8, NonZeroU8);
+    add_test!(count_digits_of_abs_diff, u16, NonZeroU16);
+    add_test!(count_digits_of_abs_diff, u32, NonZeroU32);
+    add_test!(count_digits_of_abs_diff, u64, NonZeroU64);
+    add_test!(count_digits_of_abs_diff, u128, NonZeroU128);
+    add_test!(count_digits_of_abs_diff, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_abs, i8, NonZeroI8);
+    add_test!(count_digits_radix_abs, i16, NonZeroI16);
+    add_test!(count_digits_radix_abs, i32, NonZeroI32);
+    add_test!(count_digits_radix_abs, i64, NonZeroI64);
+    add_test!(count_digits_radix_abs, i128, NonZeroI128);
+    add_test!(count_digits_radix_abs, isize, NonZeroIsize);
+    add_test!(count_digits_radix_abs, u8, NonZeroU8);
+    add_test!(count_digits_radix_abs, u16, NonZeroU16);
+    add_test!(count_digits_radix_abs, u32, NonZeroU32);
+    add_test!(count_digits_radix_abs, u64, NonZeroU64);
+    add_test!(count_digits_radix_abs, u128, NonZeroU128);
+    add_test!(count_digits_radix_abs, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_width, i8, NonZeroI8);
+    add_test!(count_digits_radix_width, i16, NonZeroI16);
+    add_test!(count_digits_radix_width, i32, NonZeroI32);
+    add_test!(count_digits_radix_width, i64, NonZeroI64);
+    add_test!(count_digits_radix_width, i128, NonZeroI128);
+    add_test!(count_digits_radix_width, isize, NonZeroIsize);
+    add_test!(count_digits_radix_width, u8, NonZeroU8);
+    add_test!(count_digits_radix_width, u16, NonZeroU16);
+    add_test!(count_digits_radix_width, u32, NonZeroU32);
+    add_test!(count_digits_radix_width, u64, NonZeroU64);
+    add_test!(count_digits_radix_width, u128, NonZeroU128);
+    add_test!(count_digits_radix_width, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_ones_complement, i8, NonZeroI8);
+    add_test!(count_digits_radix_ones_complement, i16, NonZeroI16);
+    add_test!(count_digits_radix_ones_complement, i32, NonZeroI32);
+    add_test!(count_digits_radix_ones_complement, i64, NonZeroI64);
+    add_test!(count_digits_radix_ones_complement, i128, NonZeroI128);
+    add_test!(count_digits_radix_ones_complement, isize, NonZeroIsize);
+    add_test!(count_digits_radix_ones_complement, u8, NonZeroU8);
+    add_test!(count_digits_radix_ones_complement, u16, NonZeroU16);
+    add_test!(count_digits_radix_ones_complement, u32, NonZeroU32);
+    add_test!(count_digits_radix_ones_complement, u64, NonZeroU64);
+    add_test!(count_digits_radix_ones_complement, u128, NonZeroU128);
+    add_test!(count_digits_radix_ones_complement, usize, NonZeroUsize);
+
+    add_test!(const_evaluation, i8, NonZeroI8);
+    add_test!(const_evaluation, i16, NonZeroI16);
+    add_test!(const_evaluation, i32, NonZeroI32);
+    add_test!(const_evaluation, i64, NonZeroI64);
+    add_test!(const_evaluation, i128, NonZeroI128);
+    add_test!(const_evaluation, isize, NonZeroIsize);
+    add_test!(const_evaluation, u8, NonZeroU8);
+    add_test!(const_evaluation, u16, NonZeroU16);
+    add_test!(const_evaluation, u32, NonZeroU32);
+    add_test!(const_evaluation, u64, NonZeroU64);
+    add_test!(const_evaluation, u128, NonZeroU128);
+    add_test!(const_evaluation, usize, NonZeroUsize);
+
+    add_test!(count_separators_pattern, i8, NonZeroI8);
+    add_test!(count_separators_pattern, i16, NonZeroI16);
+    add_test!(count_separators_pattern, i32, NonZeroI32);
+    add_test!(count_separators_pattern, i64, NonZeroI64);
+    add_test!(count_separators_pattern, i128, NonZeroI128);
+    add_test!(count_separators_pattern, isize, NonZeroIsize);
+    add_test!(count_separators_pattern, u8, NonZeroU8);
+    add_test!(count_separators_pattern, u16, NonZeroU16);
+    add_test!(count_separators_pattern, u32, NonZeroU32);
+    add_test!(count_separators_pattern, u64, NonZeroU64);
+    add_test!(count_separators_pattern, u128, NonZeroU128);
+    add_test!(count_separators_pattern, usize, NonZeroUsize);
+
+    add_test!(grouped_width_with_sign, i8, NonZeroI8);
+    add_test!(grouped_width_with_sign, i16, NonZeroI16);
+    add_test!(grouped_width_with_sign, i32, NonZeroI32);
+    add_test!(grouped_width_with_sign, i64, NonZeroI64);
+    add_test!(grouped_width_with_sign, i128, NonZeroI128);
+    add_test!(grouped_width_with_sign, isize, NonZeroIsize);
+    add_test!(grouped_width_with_sign, u8, NonZeroU8);
+    add_test!(grouped_width_with_sign, u16, NonZeroU16);
+    add_test!(grouped_width_with_sign, u32, NonZeroU32);
+    add_test!(grouped_width_with_sign, u64, NonZeroU64);
+    add_test!(grouped_width_with_sign, u128, NonZeroU128);
+    add_test!(grouped_width_with_sign, usize, NonZeroUsize);
+
+    add_test!(debug_width, i8, NonZeroI8);
+    add_test!(debug_width, i16, NonZeroI16);
+    add_test!(debug_width, i32, NonZeroI32);
+    add_test!(debug_width, i64, NonZeroI64);
+    add_test!(debug_width, i128, NonZeroI128);
+    add_test!(debug_width, isize, NonZeroIsize);
+    add_test!(debug_width, u8, NonZeroU8);
+    add_test!(debug_width, u16, NonZeroU16);
+    add_test!(debug_width, u32, NonZeroU32);
+    add_test!(debug_width, u64, NonZeroU64);
+    add_test!(debug_width, u128, NonZeroU128);
+    add_test!(debug_width, usize, NonZeroUsize);
+
+    add_test!(cmp_by_digit_width, i8, NonZeroI8);
+    add_test!(cmp_by_digit_width, i16, NonZeroI16);
+    add_test!(cmp_by_digit_width, i32, NonZeroI32);
+    add_test!(cmp_by_digit_width, i64, NonZeroI64);
+    add_test!(cmp_by_digit_width, i128, NonZeroI128);
+    add_test!(cmp_by_digit_width, isize, NonZeroIsize);
+    add_test!(cmp_by_digit_width, u8, NonZeroU8);
+    add_test!(cmp_by_digit_width, u16, NonZeroU16);
+    add_test!(cmp_by_digit_width, u32, NonZeroU32);
+    add_test!(cmp_by_digit_width, u64, NonZeroU64);
+    add_test!(cmp_by_digit_width, u128, NonZeroU128);
+    add_test!(cmp_by_digit_width, usize, NonZeroUsize);
+
+    add_test!(digit_class_radix, i8, NonZeroI8);
+    add_test!(digit_class_radix, i16, NonZeroI16);
+    add_test!(digit_class_radix, i32, NonZeroI32);
+    add_test!(digit_class_radix, i64, NonZeroI64);
+    add_test!(digit_class_radix, i128, NonZeroI128);
+    add_test!(digit_class_radix, isize, NonZeroIsize);
+    add_test!(digit_class_radix, u8, NonZeroU8);
+    add_test!(digit_class_radix, u16, NonZeroU16);
+    add_test!(digit_class_radix, u32, NonZeroU32);
+    add_test!(digit_class_radix, u64, NonZeroU64);
+    add_test!(digit_class_radix, u128, NonZeroU128);
+    add_test!(digit_class_radix, usize, NonZeroUsize);
+
+    add_test!(option, i8, NonZeroI8);
+    add_test!(option, i16, NonZeroI16);
+    add_test!(option, i32, NonZeroI32);
+    add_test!(option, i64, NonZeroI64);
+    add_test!(option, i128, NonZeroI128);
+    add_test!(option, isize, NonZeroIsize);
+    add_test!(option, u8, NonZeroU8);
+    add_test!(option, u16, NonZeroU16);
+    add_test!(option, u32, NonZeroU32);
+    add_test!(option, u64, NonZeroU64);
+    add_test!(option, u128, NonZeroU128);
+    add_test!(option, usize, NonZeroUsize);
+
+    add_test!(is_radix_width_floor, i8, NonZeroI8);
+    add_test!(is_radix_width_floor, i16, NonZeroI16);
+    add_test!(is_radix_width_floor, i32, NonZeroI32);
+    add_test!(is_radix_width_floor, i64, NonZeroI64);
+    add_test!(is_radix_width_floor, i128, NonZeroI128);
+    add_test!(is_radix_width_floor, isize, NonZeroIsize);
+    add_test!(is_radix_width_floor, u8, NonZeroU8);
+    add_test!(is_radix_width_floor, u16, NonZeroU16);
+    add_test!(is_radix_width_floor, u32, NonZeroU32);
+    add_test!(is_radix_width_floor, u64, NonZeroU64);
+    add_test!(is_radix_width_floor, u128, NonZeroU128);
+    add_test!(is_radix_width_floor, usize, NonZeroUsize);
+
+    add_test!(checked_named_counts, i8, NonZeroI8);
+    add_test!(checked_named_counts, i16, NonZeroI16);
+    add_test!(checked_named_counts, i32, NonZeroI32);
+    add_test!(checked_named_counts, i64, NonZeroI64);
+    add_test!(checked_named_counts, i128, NonZeroI128);
+    add_test!(checked_named_counts, isize, NonZeroIsize);
+    add_test!(checked_named_counts, u8, NonZeroU8);
+    add_test!(checked_named_counts, u16, NonZeroU16);
+    add_test!(checked_named_counts, u32, NonZeroU32);
+    add_test!(checked_named_counts, u64, NonZeroU64);
+    add_test!(checked_named_counts, u128, NonZeroU128);
+    add_test!(checked_named_counts, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_u128, i8, NonZeroI8);
+    add_test!(count_digits_radix_u128, i16, NonZeroI16);
+    add_test!(count_digits_radix_u128, i32, NonZeroI32);
+    add_test!(count_digits_radix_u128, i64, NonZeroI64);
+    add_test!(count_digits_radix_u128, i128, NonZeroI128);
+    add_test!(count_digits_radix_u128, isize, NonZeroIsize);
+    add_test!(count_digits_radix_u128, u8, NonZeroU8);
+    add_test!(count_digits_radix_u128, u16, NonZeroU16);
+    add_test!(count_digits_radix_u128, u32, NonZeroU32);
+    add_test!(count_digits_radix_u128, u64, NonZeroU64);
+    add_test!(count_digits_radix_u128, u128, NonZeroU128);
+    add_test!(count_digits_radix_u128, usize, NonZeroUsize);
+
+    add_test!(power_of_two_radix, i8, NonZeroI8);
+    add_test!(power_of_two_radix, i16, NonZeroI16);
+    add_test!(power_of_two_radix, i32, NonZeroI32);
+    add_test!(power_of_two_radix, i64, NonZeroI64);
+    add_test!(power_of_two_radix, i128, NonZeroI128);
+    add_test!(power_of_two_radix, isize, NonZeroIsize);
+    add_test!(power_of_two_radix, u8, NonZeroU8);
+    add_test!(power_of_two_radix, u16, NonZeroU16);
+    add_test!(power_of_two_radix, u32, NonZeroU32);
+    add_test!(power_of_two_radix, u64, NonZeroU64);
+    add_test!(power_of_two_radix, u128, NonZeroU128);
+    add_test!(power_of_two_radix, usize, NonZeroUsize);
+
+    add_test!(radix_neighborhood, i8, NonZeroI8);
+    add_test!(radix_neighborhood, i16, NonZeroI16);
+    add_test!(radix_neighborhood, i32, NonZeroI32);
+    add_test!(radix_neighborhood, i64, NonZeroI64);
+    add_test!(radix_neighborhood, i128, NonZeroI128);
+    add_test!(radix_neighborhood, isize, NonZeroIsize);
+    add_test!(radix_neighborhood, u8, NonZeroU8);
+    add_test!(radix_neighborhood, u16, NonZeroU16);
+    add_test!(radix_neighborhood, u32, NonZeroU32);
+    add_test!(radix_neighborhood, u64, NonZeroU64);
+    add_test!(radix_neighborhood, u128, NonZeroU128);
+    add_test!(radix_neighborhood, usize, NonZeroUsize);
+
+    add_test!(count_base36_digits, i8, NonZeroI8);
+    add_test!(count_base36_digits, i16, NonZeroI16);
+    add_test!(count_base36_digits, i32, NonZeroI32);
+    add_test!(count_base36_digits, i64, NonZeroI64);
+    add_test!(count_base36_digits, i128, NonZeroI128);
+    add_test!(count_base36_digits, isize, NonZeroIsize);
+    add_test!(count_base36_digits, u8, NonZeroU8);
+    add_test!(count_base36_digits, u16, NonZeroU16);
+    add_test!(count_base36_digits, u32, NonZeroU32);
+    add_test!(count_base36_digits, u64, NonZeroU64);
+    add_test!(count_base36_digits, u128, NonZeroU128);
+    add_test!(count_base36_digits, usize, NonZeroUsize);
+
+    add_test!(count_and_is_power_radix, i8, NonZeroI8);
+    add_test!(count_and_is_power_radix, i16, NonZeroI16);
+    add_test!(count_and_is_power_radix, i32, NonZeroI32);
+    add_test!(count_and_is_power_radix, i64, NonZeroI64);
+    add_test!(count_and_is_power_radix, i128, NonZeroI128);
+    add_test!(count_and_is_power_radix, isize, NonZeroIsize);
+    add_test!(count_and_is_power_radix, u8, NonZeroU8);
+    add_test!(count_and_is_power_radix, u16, NonZeroU16);
+    add_test!(count_and_is_power_radix, u32, NonZeroU32);
+    add_test!(count_and_is_power_radix, u64, NonZeroU64);
+    add_test!(count_and_is_power_radix, u128, NonZeroU128);
+    add_test!(count_and_is_power_radix, usize, NonZeroUsize);
+
+    add_test!(count_base85_digits, i8, NonZeroI8);
+    add_test!(count_base85_digits, i16, NonZeroI16);
+    add_test!(count_base85_digits, i32, NonZeroI32);
+    add_test!(count_base85_digits, i64, NonZeroI64);
+    add_test!(count_base85_digits, i128, NonZeroI128);
+    add_test!(count_base85_digits, isize, NonZeroIsize);
+    add_test!(count_base85_digits, u8, NonZeroU8);
+    add_test!(count_base85_digits, u16, NonZeroU16);
+    add_test!(count_base85_digits, u32, NonZeroU32);
+    add_test!(count_base85_digits, u64, NonZeroU64);
+    add_test!(count_base85_digits, u128, NonZeroU128);
+    add_test!(count_base85_digits, usize, NonZeroUsize);
+
+    add_test!(digit_diff_count_radix, i8, NonZeroI8);
+    add_test!(digit_diff_count_radix, i16, NonZeroI16);
+    add_test!(digit_diff_count_radix, i32, NonZeroI32);
+    add_test!(digit_diff_count_radix, i64, NonZeroI64);
+    add_test!(digit_diff_count_radix, i128, NonZeroI128);
+    add_test!(digit_diff_count_radix, isize, NonZeroIsize);
+    add_test!(digit_diff_count_radix, u8, NonZeroU8);
+    add_test!(digit_diff_count_radix, u16, NonZeroU16);
+    add_test!(digit_diff_count_radix, u32, NonZeroU32);
+    add_test!(digit_diff_count_radix, u64, NonZeroU64);
+    add_test!(digit_diff_count_radix, u128, NonZeroU128);
+    add_test!(digit_diff_count_radix, usize, NonZeroUsize);
+
+    add_test!(common_prefix_len_radix, i8, NonZeroI8);
+    add_test!(common_prefix_len_radix, i16, NonZeroI16);
+    add_test!(common_prefix_len_radix, i32, NonZeroI32);
+    add_test!(common_prefix_len_radix, i64, NonZeroI64);
+    add_test!(common_prefix_len_radix, i128, NonZeroI128);
+    add_test!(common_prefix_len_radix, isize, NonZeroIsize);
+    add_test!(common_prefix_len_radix, u8, NonZeroU8);
+    add_test!(common_prefix_len_radix, u16, NonZeroU16);
+    add_test!(common_prefix_len_radix, u32, NonZeroU32);
+    add_test!(common_prefix_len_radix, u64, NonZeroU64);
+    add_test!(common_prefix_len_radix, u128, NonZeroU128);
+    add_test!(common_prefix_len_radix, usize, NonZeroUsize);
+
+    add_test!(count_significant_digits_radix, i8, NonZeroI8);
+    add_test!(count_significant_digits_radix, i16, NonZeroI16);
+    add_test!(count_significant_digits_radix, i32, NonZeroI32);
+    add_test!(count_significant_digits_radix, i64, NonZeroI64);
+    add_test!(count_significant_digits_radix, i128, NonZeroI128);
+    add_test!(count_significant_digits_radix, isize, NonZeroIsize);
+    add_test!(count_significant_digits_radix, u8, NonZeroU8);
+    add_test!(count_significant_digits_radix, u16, NonZeroU16);
+    add_test!(count_significant_digits_radix, u32, NonZeroU32);
+    add_test!(count_significant_digits_radix, u64, NonZeroU64);
+    add_test!(count_significant_digits_radix, u128, NonZeroU128);
+    add_test!(count_significant_digits_radix, usize, NonZeroUsize);
+
+    add_test!(count_digits_of_reverse_radix, i8, NonZeroI8);
+    add_test!(count_digits_of_reverse_radix, i16, NonZeroI16);
+    add_test!(count_digits_of_reverse_radix, i32, NonZeroI32);
+    add_test!(count_digits_of_reverse_radix, i64, NonZeroI64);
+    add_test!(count_digits_of_reverse_radix, i128, NonZeroI128);
+    add_test!(count_digits_of_reverse_radix, isize, NonZeroIsize);
+    add_test!(count_digits_of_reverse_radix, u8, NonZeroU8);
+    add_test!(count_digits_of_reverse_radix, u16, NonZeroU16);
+    add_test!(count_digits_of_reverse_radix, u32, NonZeroU32);
+    add_test!(count_digits_of_reverse_radix, u64, NonZeroU64);
+    add_test!(count_digits_of_reverse_radix, u128, NonZeroU128);
+    add_test!(count_digits_of_reverse_radix, usize, NonZeroUsize);
+
+    add_test!(max_widths, i8, NonZeroI8);
+    add_test!(max_widths, i16, NonZeroI16);
+    add_test!(max_widths, i32, NonZeroI32);
+    add_test!(max_widths, i64, NonZeroI64);
+    add_test!(max_widths, i128, NonZeroI128);
+    add_test!(max_widths, isize, NonZeroIsize);
+    add_test!(max_widths, u8, NonZeroU8);
+    add_test!(max_widths, u16, NonZeroU16);
+    add_test!(max_widths, u32, NonZeroU32);
+    add_test!(max_widths, u64, NonZeroU64);
+    add_test!(max_widths, u128, NonZeroU128);
+    add_test!(max_widths, usize, NonZeroUsize);
+
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, i8, NonZeroI8);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, i16, NonZeroI16);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, i32, NonZeroI32);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, i64, NonZeroI64);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, i128, NonZeroI128);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, isize, NonZeroIsize);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, u8, NonZeroU8);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, u16, NonZeroU16);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, u32, NonZeroU32);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, u64, NonZeroU64);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, u128, NonZeroU128);
+    #[cfg(feature = "alloc")]
+    add_test!(count_digits_radices, usize, NonZeroUsize);
+
+    add_test!(negative_non_zero_parity, i8, NonZeroI8, u8);
+    add_test!(negative_non_zero_parity, i16, NonZeroI16, u16);
+    add_test!(negative_non_zero_parity, i32, NonZeroI32, u32);
+    add_test!(negative_non_zero_parity, i64, NonZeroI64, u64);
+    add_test!(negative_non_zero_parity, i128, NonZeroI128, u128);
+    add_test!(negative_non_zero_parity, isize, NonZeroIsize, usize);
+
+    add_test!(count_digits_radix_u32, i8, NonZeroI8);
+    add_test!(count_digits_radix_u32, i16, NonZeroI16);
+    add_test!(count_digits_radix_u32, i32, NonZeroI32);
+    add_test!(count_digits_radix_u32, i64, NonZeroI64);
+    add_test!(count_digits_radix_u32, i128, NonZeroI128);
+    add_test!(count_digits_radix_u32, isize, NonZeroIsize);
+    add_test!(count_digits_radix_u32, u8, NonZeroU8);
+    add_test!(count_digits_radix_u32, u16, NonZeroU16);
+    add_test!(count_digits_radix_u32, u32, NonZeroU32);
+    add_test!(count_digits_radix_u32, u64, NonZeroU64);
+    add_test!(count_digits_radix_u32, u128, NonZeroU128);
+    add_test!(count_digits_radix_u32, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_usize, i8, NonZeroI8);
+    add_test!(count_digits_radix_usize, i16, NonZeroI16);
+    add_test!(count_digits_radix_usize, i32, NonZeroI32);
+    add_test!(count_digits_radix_usize, i64, NonZeroI64);
+    add_test!(count_digits_radix_usize, i128, NonZeroI128);
+    add_test!(count_digits_radix_usize, isize, NonZeroIsize);
+    add_test!(count_digits_radix_usize, u8, NonZeroU8);
+    add_test!(count_digits_radix_usize, u16, NonZeroU16);
+    add_test!(count_digits_radix_usize, u32, NonZeroU32);
+    add_test!(count_digits_radix_usize, u64, NonZeroU64);
+    add_test!(count_digits_radix_usize, u128, NonZeroU128);
+    add_test!(count_digits_radix_usize, usize, NonZeroUsize);
+
+    add_test!(count_decimal_digits_twos_complement, i8, NonZeroI8);
+    add_test!(count_decimal_digits_twos_complement, i16, NonZeroI16);
+    add_test!(count_decimal_digits_twos_complement, i32, NonZeroI32);
+    add_test!(count_decimal_digits_twos_complement, i64, NonZeroI64);
+    add_test!(count_decimal_digits_twos_complement, i128, NonZeroI128);
+    add_test!(count_decimal_digits_twos_complement, isize, NonZeroIsize);
+    add_test!(count_decimal_digits_twos_complement, u8, NonZeroU8);
+    add_test!(count_decimal_digits_twos_complement, u16, NonZeroU16);
+    add_test!(count_decimal_digits_twos_complement, u32, NonZeroU32);
+    add_test!(count_decimal_digits_twos_complement, u64, NonZeroU64);
+    add_test!(count_decimal_digits_twos_complement, u128, NonZeroU128);
+    add_test!(count_decimal_digits_twos_complement, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_shifted, i8, NonZeroI8);
+    add_test!(count_digits_radix_shifted, i16, NonZeroI16);
+    add_test!(count_digits_radix_shifted, i32, NonZeroI32);
+    add_test!(count_digits_radix_shifted, i64, NonZeroI64);
+    add_test!(count_digits_radix_shifted, i128, NonZeroI128);
+    add_test!(count_digits_radix_shifted, isize, NonZeroIsize);
+    add_test!(count_digits_radix_shifted, u8, NonZeroU8);
+    add_test!(count_digits_radix_shifted, u16, NonZeroU16);
+    add_test!(count_digits_radix_shifted, u32, NonZeroU32);
+    add_test!(count_digits_radix_shifted, u64, NonZeroU64);
+    add_test!(count_digits_radix_shifted, u128, NonZeroU128);
+    add_test!(count_digits_radix_shifted, usize, NonZeroUsize);
+
+    add_test!(count_digits_radix_truncated, i8, NonZeroI8);
+    add_test!(count_digits_radix_truncated, i16, NonZeroI16);
+    add_test!(count_digits_radix_truncated, i32, NonZeroI32);
+    add_test!(count_digits_radix_truncated, i64, NonZeroI64);
+    add_test!(count_digits_radix_truncated, i128, NonZeroI128);
+    add_test!(count_digits_radix_truncated, isize, NonZeroIsize);
+    add_test!(count_digits_radix_truncated, u8, NonZeroU8);
+    add_test!(count_digits_radix_truncated, u16, NonZeroU16);
+    add_test!(count_digits_radix_truncated, u32, NonZeroU32);
+    add_test!(count_digits_radix_truncated, u64, NonZeroU64);
+    add_test!(count_digits_radix_truncated, u128, NonZeroU128);
+    add_test!(count_digits_radix_truncated, usize, NonZeroUsize);
+
+    add_test!(try_count_digits_radix_u32, i8, NonZeroI8);
+    add_test!(try_count_digits_radix_u32, i16, NonZeroI16);
+    add_test!(try_count_digits_radix_u32, i32, NonZeroI32);
+    add_test!(try_count_digits_radix_u32, i64, NonZeroI64);
+    add_test!(try_count_digits_radix_u32, i128, NonZeroI128);
+    add_test!(try_count_digits_radix_u32, isize, NonZeroIsize);
+    add_test!(try_count_digits_radix_u32, u8, NonZeroU8);
+    add_test!(try_count_digits_radix_u32, u16, NonZeroU16);
+    add_test!(try_count_digits_radix_u32, u32, NonZeroU32);
+    add_test!(try_count_digits_radix_u32, u64, NonZeroU64);
+    add_test!(try_count_digits_radix_u32, u128, NonZeroU128);
+    add_test!(try_count_digits_radix_u32, usize, NonZeroUsize);
+
+    add_test!(write_ascii_digits_radix, i8, NonZeroI8);
+    add_test!(write_ascii_digits_radix, i16, NonZeroI16);
+    add_test!(write_ascii_digits_radix, i32, NonZeroI32);
+    add_test!(write_ascii_digits_radix, i64, NonZeroI64);
+    add_test!(write_ascii_digits_radix, i128, NonZeroI128);
+    add_test!(write_ascii_digits_radix, isize, NonZeroIsize);
+    add_test!(write_ascii_digits_radix, u8, NonZeroU8);
+    add_test!(write_ascii_digits_radix, u16, NonZeroU16);
+    add_test!(write_ascii_digits_radix, u32, NonZeroU32);
+    add_test!(write_ascii_digits_radix, u64, NonZeroU64);
+    add_test!(write_ascii_digits_radix, u128, NonZeroU128);
+    add_test!(write_ascii_digits_radix, usize, NonZeroUsize);
+
+    add_test!(zero_policy, i8, NonZeroI8);
+    add_test!(zero_policy, i16, NonZeroI16);
+    add_test!(zero_policy, i32, NonZeroI32);
+    add_test!(zero_policy, i64, NonZeroI64);
+    add_test!(zero_policy, i128, NonZeroI128);
+    add_test!(zero_policy, isize, NonZeroIsize);
+    add_test!(zero_policy, u8, NonZeroU8);
+    add_test!(zero_policy, u16, NonZeroU16);
+    add_test!(zero_policy, u32, NonZeroU32);
+    add_test!(zero_policy, u64, NonZeroU64);
+    add_test!(zero_policy, u128, NonZeroU128);
+    add_test!(zero_policy, usize, NonZeroUsize);
+
+    add_test!(palindromic_rotation_count_radix, i8, NonZeroI8);
+    add_test!(palindromic_rotation_count_radix, i16, NonZeroI16);
+    add_test!(palindromic_rotation_count_radix, i32, NonZeroI32);
+    add_test!(palindromic_rotation_count_radix, i64, NonZeroI64);
+    add_test!(palindromic_rotation_count_radix, i128, NonZeroI128);
+    add_test!(palindromic_rotation_count_radix, isize, NonZeroIsize);
+    add_test!(palindromic_rotation_count_radix, u8, NonZeroU8);
+    add_test!(palindromic_rotation_count_radix, u16, NonZeroU16);
+    add_test!(palindromic_rotation_count_radix, u32, NonZeroU32);
+    add_test!(palindromic_rotation_count_radix, u64, NonZeroU64);
+    add_test!(palindromic_rotation_count_radix, u128, NonZeroU128);
+    add_test!(palindromic_rotation_count_radix, usize, NonZeroUsize);
+
+    add_test!(digits_radix_le, i8, NonZeroI8);
+    add_test!(digits_radix_le, i16, NonZeroI16);
+    add_test!(digits_radix_le, i32, NonZeroI32);
+    add_test!(digits_radix_le, i64, NonZeroI64);
+    add_test!(digits_radix_le, i128, NonZeroI128);
+    add_test!(digits_radix_le, isize, NonZeroIsize);
+    add_test!(digits_radix_le, u8, NonZeroU8);
+    add_test!(digits_radix_le, u16, NonZeroU16);
+    add_test!(digits_radix_le, u32, NonZeroU32);
+    add_test!(digits_radix_le, u64, NonZeroU64);
+    add_test!(digits_radix_le, u128, NonZeroU128);
+    add_test!(digits_radix_le, usize, NonZeroUsize);
+
+    add_test!(digit_iteration_order, i8, NonZeroI8);
+    add_test!(digit_iteration_order, i16, NonZeroI16);
+    add_test!(digit_iteration_order, i32, NonZeroI32);
+    add_test!(digit_iteration_order, i64, NonZeroI64);
+    add_test!(digit_iteration_order, i128, NonZeroI128);
+    add_test!(digit_iteration_order, isize, NonZeroIsize);
+    add_test!(digit_iteration_order, u8, NonZeroU8);
+    add_test!(digit_iteration_order, u16, NonZeroU16);
+    add_test!(digit_iteration_order, u32, NonZeroU32);
+    add_test!(digit_iteration_order, u64, NonZeroU64);
+    add_test!(digit_iteration_order, u128, NonZeroU128);
+    add_test!(digit_iteration_order, usize, NonZeroUsize);
+
+    add_test!(weighted_digits_radix, i8, NonZeroI8);
+    add_test!(weighted_digits_radix, i16, NonZeroI16);
+    add_test!(weighted_digits_radix, i32, NonZeroI32);
+    add_test!(weighted_digits_radix, i64, NonZeroI64);
+    add_test!(weighted_digits_radix, i128, NonZeroI128);
+    add_test!(weighted_digits_radix, isize, NonZeroIsize);
+    add_test!(weighted_digits_radix, u8, NonZeroU8);
+    add_test!(weighted_digits_radix, u16, NonZeroU16);
+    add_test!(weighted_digits_radix, u32, NonZeroU32);
+    add_test!(weighted_digits_radix, u64, NonZeroU64);
+    add_test!(weighted_digits_radix, u128, NonZeroU128);
+    add_test!(weighted_digits_radix, usize, NonZeroUsize);
+
+    add_test!(leading_repeat_count_radix, i8, NonZeroI8);
+    add_test!(leading_repeat_count_radix, i16, NonZeroI16);
+    add_test!(leading_repeat_count_radix, i32, NonZeroI32);
+    add_test!(leading_repeat_count_radix, i64, NonZeroI64);
+    add_test!(leading_repeat_count_radix, i128, NonZeroI128);
+    add_test!(leading_repeat_count_radix, isize, NonZeroIsize);
+    add_test!(leading_repeat_count_radix, u8, NonZeroU8);
+    add_test!(leading_repeat_count_radix, u16, NonZeroU16);
+    add_test!(leading_repeat_count_radix, u32, NonZeroU32);
+    add_test!(leading_repeat_count_radix, u64, NonZeroU64);
+    add_test!(leading_repeat_count_radix, u128, NonZeroU128);
+    add_test!(leading_repeat_count_radix, usize, NonZeroUsize);
+
+    add_test!(max_digits_radix, i8, NonZeroI8);
+    add_test!(max_digits_radix, i16, NonZeroI16);
+    add_test!(max_digits_radix, i32, NonZeroI32);
+    add_test!(max_digits_radix, i64, NonZeroI64);
+    add_test!(max_digits_radix, i128, NonZeroI128);
+    add_test!(max_digits_radix, isize, NonZeroIsize);
+    add_test!(max_digits_radix, u8, NonZeroU8);
+    add_test!(max_digits_radix, u16, NonZeroU16);
+    add_test!(max_digits_radix, u32, NonZeroU32);
+    add_test!(max_digits_radix, u64, NonZeroU64);
+    add_test!(max_digits_radix, u128, NonZeroU128);
+    add_test!(max_digits_radix, usize, NonZeroUsize);
+
+    add_test!(count_digits_of_sum, i8, NonZeroI8);
+    add_test!(count_digits_of_sum, i16, NonZeroI16);
+    add_test!(count_digits_of_sum, i32, NonZeroI32);
+    add_test!(count_digits_of_sum, i64, NonZeroI64);
+    add_test!(count_digits_of_sum, i128, NonZeroI128);
+    add_test!(count_digits_of_sum, isize, NonZeroIsize);
+    add_test!(count_digits_of_sum, u8, NonZeroU8);
+    add_test!(count_digits_of_sum, u16, NonZeroU16);
+    add_test!(count_digits_of_sum, u32, NonZeroU32);
+    add_test!(count_digits_of_sum, u64, NonZeroU64);
+    add_test!(count_digits_of_sum, u128, NonZeroU128);
+    add_test!(count_digits_of_sum, usize, NonZeroUsize);
+
+    add_test!(count_digits_fixed_point, i8, NonZeroI8);
+    add_test!(count_digits_fixed_point, i16, NonZeroI16);
+    add_test!(count_digits_fixed_point, i32, NonZeroI32);
+    add_test!(count_digits_fixed_point, i64, NonZeroI64);
+    add_test!(count_digits_fixed_point, i128, NonZeroI128);
+    add_test!(count_digits_fixed_point, isize, NonZeroIsize);
+    add_test!(count_digits_fixed_point, u8, NonZeroU8);
+    add_test!(count_digits_fixed_point, u16, NonZeroU16);
+    add_test!(count_digits_fixed_point, u32, NonZeroU32);
+    add_test!(count_digits_fixed_point, u64, NonZeroU64);
+    add_test!(count_digits_fixed_point, u128, NonZeroU128);
+    add_test!(count_digits_fixed_point, usize, NonZeroUsize);
 }